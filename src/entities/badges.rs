@@ -12,8 +12,10 @@ pub struct Model {
     pub name: String,
     pub short_name: String,
     pub description: String,
+    pub valid_months: u32,
     pub apx_id: u32,
     pub vdr_id: u32,
+    pub retired_at: Option<DateTime>,
     pub created_at: DateTime,
     pub updated_at: DateTime,
 }
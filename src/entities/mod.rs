@@ -3,6 +3,7 @@
 pub mod prelude;
 
 pub mod badges;
+pub mod bank_accounts;
 pub mod bds;
 pub mod belongs;
 pub mod cryptos;
@@ -12,8 +13,12 @@ pub mod match_statuses;
 pub mod matches;
 pub mod payments;
 pub mod payouts;
+pub mod point_adjustments;
 pub mod points;
 pub mod pools;
 pub mod usr_badges;
 pub mod usrs;
+pub mod withdrawal_statuses;
+pub mod withdrawals;
+pub mod work_corrections;
 pub mod works;
@@ -14,6 +14,7 @@ pub struct Model {
     pub fee: u32,
     pub net: u32,
     pub note: String,
+    pub reversal_of: Option<u32>,
     pub apx_id: u32,
     pub vdr_id: u32,
     pub created_at: DateTime,
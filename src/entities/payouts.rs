@@ -12,11 +12,14 @@ pub struct Model {
     pub flush_id: u32,
     pub usr_id: u32,
     pub points: u32,
-    #[sea_orm(column_type = "Decimal(Some((5, 5)))")]
+    #[sea_orm(column_type = "Decimal(Some((6, 5)))")]
     pub share: Decimal,
     pub amount: u32,
+    pub withheld: u32,
+    pub net: u32,
     pub apx_id: u32,
     pub vdr_id: u32,
+    pub exported_at: Option<DateTime>,
     pub created_at: DateTime,
     pub updated_at: DateTime,
 }
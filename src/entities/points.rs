@@ -9,11 +9,14 @@ pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
     pub badge_id: u32,
+    pub usr_badge_id: u32,
     pub corp_id: u32,
     pub from: u32,
     pub to: u32,
     pub point: u32,
     pub extra: u32,
+    pub kind: u8,
+    pub delta: i32,
     pub apx_id: u32,
     pub vdr_id: u32,
     pub created_at: DateTime,
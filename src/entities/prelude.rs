@@ -1,6 +1,7 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.19
 
 pub use super::badges::Entity as Badges;
+pub use super::bank_accounts::Entity as BankAccounts;
 pub use super::bds::Entity as Bds;
 pub use super::belongs::Entity as Belongs;
 pub use super::cryptos::Entity as Cryptos;
@@ -10,8 +11,12 @@ pub use super::match_statuses::Entity as MatchStatuses;
 pub use super::matches::Entity as Matches;
 pub use super::payments::Entity as Payments;
 pub use super::payouts::Entity as Payouts;
+pub use super::point_adjustments::Entity as PointAdjustments;
 pub use super::points::Entity as Points;
 pub use super::pools::Entity as Pools;
 pub use super::usr_badges::Entity as UsrBadges;
 pub use super::usrs::Entity as Usrs;
+pub use super::withdrawal_statuses::Entity as WithdrawalStatuses;
+pub use super::withdrawals::Entity as Withdrawals;
+pub use super::work_corrections::Entity as WorkCorrections;
 pub use super::works::Entity as Works;
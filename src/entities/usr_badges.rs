@@ -9,6 +9,7 @@ pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
     pub badge_id: u32,
+    pub job_id: u32,
     pub corp_id: u32,
     pub from: u32,
     pub to: u32,
@@ -17,6 +18,19 @@ pub struct Model {
     pub r#type: u8,
     pub apx_id: u32,
     pub vdr_id: u32,
+    pub expires_at: Option<DateTime>,
+    pub revoked_at: Option<DateTime>,
+    pub revoke_reason: u8,
+    pub revoke_note: String,
+    pub revoked_by: u32,
+    pub revoked_staff_id: u32,
+    pub revoked_points: u32,
+    pub held_at: Option<DateTime>,
+    pub held_by: u32,
+    pub held_staff_id: u32,
+    pub held_points: u32,
+    pub flush_id: u32,
+    pub flushed_at: Option<DateTime>,
     pub created_at: DateTime,
     pub updated_at: DateTime,
 }
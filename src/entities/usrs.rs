@@ -29,6 +29,13 @@ pub struct Model {
     pub max_works: u32,
     #[sea_orm(column_type = "Decimal(Some((5, 5)))")]
     pub flush_fee_rate: Decimal,
+    pub withholding_threshold: u32,
+    #[sea_orm(column_type = "Decimal(Some((5, 5)))")]
+    pub withholding_rate: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((5, 5)))")]
+    pub withholding_reduced_rate: Decimal,
+    pub withholding_reduced_limit: u32,
+    pub point_expire_months: u32,
     pub is_staff: i8,
     pub bgn_at: DateTime,
     pub end_at: DateTime,
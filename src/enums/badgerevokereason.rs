@@ -0,0 +1,28 @@
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BadgeRevokeReason {
+    /// 誤った授与の取り消し
+    Mistake = 1,
+    /// 不正行為の発覚による取り消し
+    Misconduct,
+    /// その他（note に詳細を記載する）
+    Other,
+}
+
+impl BadgeRevokeReason {
+    pub fn as_str(&self) -> &str {
+        match self {
+            BadgeRevokeReason::Mistake => "mistake",
+            BadgeRevokeReason::Misconduct => "misconduct",
+            BadgeRevokeReason::Other => "other",
+        }
+    }
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            1 => Some(BadgeRevokeReason::Mistake),
+            2 => Some(BadgeRevokeReason::Misconduct),
+            3 => Some(BadgeRevokeReason::Other),
+            _ => None,
+        }
+    }
+}
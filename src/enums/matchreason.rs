@@ -0,0 +1,28 @@
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchReason {
+    Manual = 0,
+    HighBadge,
+    Random,
+    Explore,
+}
+
+impl MatchReason {
+    pub fn as_str(&self) -> &str {
+        match self {
+            MatchReason::Manual => "manual",
+            MatchReason::HighBadge => "high_badge",
+            MatchReason::Random => "random",
+            MatchReason::Explore => "explore",
+        }
+    }
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            0 => Some(MatchReason::Manual),
+            1 => Some(MatchReason::HighBadge),
+            2 => Some(MatchReason::Random),
+            3 => Some(MatchReason::Explore),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchStatus {
+    Approach = 1,
+    InterviewSet,
+    InterviewDone,
+    Hired,
+    Declined,
+}
+
+impl MatchStatus {
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            1 => Some(MatchStatus::Approach),
+            2 => Some(MatchStatus::InterviewSet),
+            3 => Some(MatchStatus::InterviewDone),
+            4 => Some(MatchStatus::Hired),
+            5 => Some(MatchStatus::Declined),
+            _ => None,
+        }
+    }
+    /// 採用成功・辞退はそれ以上遷移しない終端ステータス
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, MatchStatus::Hired | MatchStatus::Declined)
+    }
+    /// 許可された遷移のみ true を返す
+    /// 1 → 2 → 3 → 4 の順にのみ進み、終端以外からは辞退（5）へ遷移できる
+    pub fn can_transition_to(&self, next: MatchStatus) -> bool {
+        matches!(
+            (self, next),
+            (MatchStatus::Approach, MatchStatus::InterviewSet)
+                | (MatchStatus::InterviewSet, MatchStatus::InterviewDone)
+                | (MatchStatus::InterviewDone, MatchStatus::Hired)
+                | (MatchStatus::Approach, MatchStatus::Declined)
+                | (MatchStatus::InterviewSet, MatchStatus::Declined)
+                | (MatchStatus::InterviewDone, MatchStatus::Declined)
+        )
+    }
+}
@@ -1,3 +1,12 @@
 pub mod mode;
 pub mod usrtype;
+pub mod matchstatus;
+pub mod matchreason;
+pub mod paymenttype;
+pub mod withdrawalstatus;
+pub mod poolmovekind;
+pub mod pointadjustreason;
+pub mod pointadjuststatus;
+pub mod pointkind;
+pub mod badgerevokereason;
 pub use mode::Mode;
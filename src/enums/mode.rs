@@ -4,6 +4,9 @@ use std::str::FromStr;
 pub enum Mode {
     RT,
     AM,
+    FS,
+    RC,
+    PE,
 }
 
 impl Mode {
@@ -11,21 +14,30 @@ impl Mode {
         match self {
             Mode::RT => "rt",
             Mode::AM => "am",
+            Mode::FS => "fs",
+            Mode::RC => "rc",
+            Mode::PE => "pe",
         }
     }
     fn as_help(&self) -> &str {
         match self {
             Mode::RT => "Run as REST API server.",
             Mode::AM => "Run auto migration for db.",
+            Mode::FS => "Run scheduled flush of cash pools for VDRs.",
+            Mode::RC => "Run reconciliation of denormalized counters (use --fix to repair).",
+            Mode::PE => "Run point expiry for VDRs with point_expire_months.",
         }
     }
     fn all() -> &'static [Mode] {
-        &[Mode::RT, Mode::AM]
+        &[Mode::RT, Mode::AM, Mode::FS, Mode::RC, Mode::PE]
     }
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "rt" => Some(Mode::RT),
             "am" => Some(Mode::AM),
+            "fs" => Some(Mode::FS),
+            "rc" => Some(Mode::RC),
+            "pe" => Some(Mode::PE),
             _ => None,
         }
     }
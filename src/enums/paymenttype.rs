@@ -0,0 +1,16 @@
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentType {
+    InterviewFee = 1,
+    PlacementFee,
+}
+
+impl PaymentType {
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            1 => Some(PaymentType::InterviewFee),
+            2 => Some(PaymentType::PlacementFee),
+            _ => None,
+        }
+    }
+}
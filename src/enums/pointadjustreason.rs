@@ -0,0 +1,32 @@
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointAdjustReason {
+    /// 誤ったバッジ付与の取り消し
+    MistakenBadge = 1,
+    /// 不正の発覚による没収
+    Fraud,
+    /// 集計誤りなどの訂正
+    Correction,
+    /// その他（note に詳細を記載する）
+    Other,
+}
+
+impl PointAdjustReason {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PointAdjustReason::MistakenBadge => "mistaken_badge",
+            PointAdjustReason::Fraud => "fraud",
+            PointAdjustReason::Correction => "correction",
+            PointAdjustReason::Other => "other",
+        }
+    }
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            1 => Some(PointAdjustReason::MistakenBadge),
+            2 => Some(PointAdjustReason::Fraud),
+            3 => Some(PointAdjustReason::Correction),
+            4 => Some(PointAdjustReason::Other),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointAdjustStatus {
+    /// 承認待ち（保有ポイントには未反映）
+    Pending = 1,
+    /// 承認済み（保有ポイントに反映済み）
+    Approved,
+    /// 却下
+    Rejected,
+}
+
+impl PointAdjustStatus {
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            1 => Some(PointAdjustStatus::Pending),
+            2 => Some(PointAdjustStatus::Approved),
+            3 => Some(PointAdjustStatus::Rejected),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointKind {
+    /// バッジ授与による付与（delta = point + extra）
+    Award = 1,
+    /// 失効（delta は失効したポイント数の負の値）
+    Expiry,
+    /// バッジ授与の取り消し（delta は戻したポイント数の負の値）
+    Revocation,
+}
+
+impl PointKind {
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            1 => Some(PointKind::Award),
+            2 => Some(PointKind::Expiry),
+            3 => Some(PointKind::Revocation),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PoolMoveKind {
+    /// 入金（payments）
+    Payment = 1,
+    /// 返金（reversal_of を持つ payments）
+    Refund,
+    /// 分配（flushes）
+    Flush,
+}
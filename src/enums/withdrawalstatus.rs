@@ -0,0 +1,34 @@
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WithdrawalStatus {
+    Requested = 1,
+    Approved,
+    Rejected,
+    Paid,
+}
+
+impl WithdrawalStatus {
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            1 => Some(WithdrawalStatus::Requested),
+            2 => Some(WithdrawalStatus::Approved),
+            3 => Some(WithdrawalStatus::Rejected),
+            4 => Some(WithdrawalStatus::Paid),
+            _ => None,
+        }
+    }
+    /// 承認済み・支払済みは、出金可能残高から差し引かれる確定済みステータス
+    pub fn is_committed(&self) -> bool {
+        matches!(self, WithdrawalStatus::Approved | WithdrawalStatus::Paid)
+    }
+    /// 許可された遷移のみ true を返す
+    /// 申請（1）からは承認（2）・却下（3）へ、承認（2）からは支払済（4）へのみ遷移できる
+    pub fn can_transition_to(&self, next: WithdrawalStatus) -> bool {
+        matches!(
+            (self, next),
+            (WithdrawalStatus::Requested, WithdrawalStatus::Approved)
+                | (WithdrawalStatus::Requested, WithdrawalStatus::Rejected)
+                | (WithdrawalStatus::Approved, WithdrawalStatus::Paid)
+        )
+    }
+}
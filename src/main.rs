@@ -1,6 +1,9 @@
 use bsdr::config;
 use bsdr::enums::Mode;
 use bsdr::mode::am;
+use bsdr::mode::fs;
+use bsdr::mode::pe;
+use bsdr::mode::rc;
 use bsdr::mode::rt;
 use std::env;
 
@@ -40,5 +43,14 @@ async fn main() {
         Mode::AM => {
             am::main_of_am(mode_args).await;
         }
+        Mode::FS => {
+            fs::main_of_fs(mode_args).await;
+        }
+        Mode::RC => {
+            rc::main_of_rc(mode_args).await;
+        }
+        Mode::PE => {
+            pe::main_of_pe(mode_args).await;
+        }
     }
 }
@@ -55,7 +55,7 @@ enum MatchStatus {
     From,
     /// 求人のアプローチを受けた個人の UsrID
     To,
-    /// 1:アプローチ, 2:面談設定, 3:面談実行, 4:採用成功
+    /// 1:アプローチ, 2:面談設定, 3:面談実行, 4:採用成功, 5:辞退
     Status,
     /// 個人によって「仮予定」とした場合 true、確定したら false
     IsTmp,
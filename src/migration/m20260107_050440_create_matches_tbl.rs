@@ -53,7 +53,7 @@ enum Match {
     From,
     /// 求人のアプローチを受けた個人の UsrID
     To,
-    /// 1:アプローチ, 2:面談設定, 3:面談実行, 4:採用成功
+    /// 1:アプローチ, 2:面談設定, 3:面談実行, 4:採用成功, 5:辞退
     Status,
     /// バッジ数による優先度スコア記録
     PriorityScore,
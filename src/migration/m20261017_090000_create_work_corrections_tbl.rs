@@ -0,0 +1,77 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 法人による就業実績（タイムカード）の修正履歴
+        manager.create_table(
+            Table::create()
+                .table(WorkCorrection::Table)
+                .if_not_exists()
+                .col(pk_auto(WorkCorrection::Id))
+                .col(unsigned(WorkCorrection::WorkID).not_null().default(0))
+                .col(unsigned(WorkCorrection::From).not_null().default(0))
+                .col(unsigned(WorkCorrection::To).not_null().default(0))
+                .col(unsigned(WorkCorrection::UsrID).not_null().default(0))
+                .col(unsigned(WorkCorrection::StaffID).not_null().default(0))
+                .col(ColumnDef::new(WorkCorrection::BefRealWorkBgnAt).date_time().null())
+                .col(ColumnDef::new(WorkCorrection::BefRealWorkEndAt).date_time().null())
+                .col(ColumnDef::new(WorkCorrection::AftRealWorkBgnAt).date_time().null())
+                .col(ColumnDef::new(WorkCorrection::AftRealWorkEndAt).date_time().null())
+                .col(string_len(WorkCorrection::Reason, 400).not_null().default(""))
+                .col(unsigned(WorkCorrection::ApxID).not_null())
+                .col(unsigned(WorkCorrection::VdrID).not_null())
+                .col(ColumnDef::new(WorkCorrection::CreatedAt).date_time().not_null().default(Expr::current_timestamp()))
+                .col(ColumnDef::new(WorkCorrection::UpdatedAt).date_time().not_null().default(Expr::current_timestamp()))
+                .to_owned()
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("workcorrection_apxid_vdrid_workid_idx")
+                .table(WorkCorrection::Table)
+                .col(WorkCorrection::ApxID)
+                .col(WorkCorrection::VdrID)
+                .col(WorkCorrection::WorkID)
+                .to_owned()
+        ).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(WorkCorrection::Table).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WorkCorrection {
+    #[sea_orm(iden = "work_corrections")]
+    Table,
+    Id,
+    /// 修正対象の WorkID
+    WorkID,
+    /// 求人を発行した法人の UsrID
+    From,
+    /// 就業した個人の UsrID
+    To,
+    /// 修正を行った UsrID（法人 または VDR）
+    UsrID,
+    /// 修正を行ったスタッフの UsrID（スタッフ token でない場合は 0）
+    StaffID,
+    /// 修正前の就業開始日時実績
+    BefRealWorkBgnAt,
+    /// 修正前の就業終了日時実績
+    BefRealWorkEndAt,
+    /// 修正後の就業開始日時実績
+    AftRealWorkBgnAt,
+    /// 修正後の就業終了日時実績
+    AftRealWorkEndAt,
+    /// 修正理由
+    Reason,
+    ApxID,
+    VdrID,
+    CreatedAt,
+    UpdatedAt,
+}
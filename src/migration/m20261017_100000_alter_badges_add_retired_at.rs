@@ -0,0 +1,49 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 廃止日時（廃止後も usr_badges からの参照のため行は残す）
+        manager.alter_table(
+            Table::alter()
+                .table(Badge::Table)
+                .add_column_if_not_exists(ColumnDef::new(Badge::RetiredAt).date_time().null())
+                .to_owned()
+        ).await?;
+
+        // 法人内での short_name の一意性
+        manager.create_index(
+            Index::create()
+                .name("badge_apxid_vdrid_corpid_shortname_uq")
+                .table(Badge::Table)
+                .col(Badge::ApxID)
+                .col(Badge::VdrID)
+                .col(Badge::CorpID)
+                .col(Badge::ShortName)
+                .unique()
+                .to_owned()
+        ).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_index(Index::drop().name("badge_apxid_vdrid_corpid_shortname_uq").table(Badge::Table).to_owned()).await?;
+        manager.alter_table(Table::alter().table(Badge::Table).drop_column(Badge::RetiredAt).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Badge {
+    #[sea_orm(iden = "badges")]
+    Table,
+    /// Badgeを作った法人の UsrID
+    CorpID,
+    /// バッジを fe で表示する時の短い名前（法人内で一意）
+    ShortName,
+    ApxID,
+    VdrID,
+    /// 廃止日時（NULL は有効）
+    RetiredAt,
+}
@@ -0,0 +1,42 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 求人に紐づく授与（jobs.max_badges の判定に用いる）
+        manager.alter_table(
+            Table::alter()
+                .table(UsrBadge::Table)
+                .add_column_if_not_exists(ColumnDef::new(UsrBadge::JobID).unsigned().not_null().default(0))
+                .to_owned()
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("usr_badge_apxid_vdrid_jobid_idx")
+                .table(UsrBadge::Table)
+                .col(UsrBadge::ApxID)
+                .col(UsrBadge::VdrID)
+                .col(UsrBadge::JobID)
+                .to_owned()
+        ).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_index(Index::drop().name("usr_badge_apxid_vdrid_jobid_idx").table(UsrBadge::Table).to_owned()).await?;
+        manager.alter_table(Table::alter().table(UsrBadge::Table).drop_column(UsrBadge::JobID).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UsrBadge {
+    #[sea_orm(iden = "usr_badges")]
+    Table,
+    /// 授与の対象となった求人の JobID（0: 求人に紐づかない授与）
+    JobID,
+    ApxID,
+    VdrID,
+}
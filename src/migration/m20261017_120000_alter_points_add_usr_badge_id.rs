@@ -0,0 +1,42 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ポイントの発生源となったバッジ授与
+        manager.alter_table(
+            Table::alter()
+                .table(Point::Table)
+                .add_column_if_not_exists(ColumnDef::new(Point::UsrBadgeID).unsigned().not_null().default(0))
+                .to_owned()
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("point_apxid_vdrid_usrbadgeid_idx")
+                .table(Point::Table)
+                .col(Point::ApxID)
+                .col(Point::VdrID)
+                .col(Point::UsrBadgeID)
+                .to_owned()
+        ).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_index(Index::drop().name("point_apxid_vdrid_usrbadgeid_idx").table(Point::Table).to_owned()).await?;
+        manager.alter_table(Table::alter().table(Point::Table).drop_column(Point::UsrBadgeID).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Point {
+    #[sea_orm(iden = "points")]
+    Table,
+    /// ポイントの発生源となった UsrBadgeID（0: バッジ授与以外）
+    UsrBadgeID,
+    ApxID,
+    VdrID,
+}
@@ -0,0 +1,34 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 分配対象が 1 人の場合に取り分割合 1.00000 を保存できるよう精度を広げる
+        manager.alter_table(
+            Table::alter()
+                .table(Payout::Table)
+                .modify_column(ColumnDef::new(Payout::Share).decimal_len(6, 5).not_null().default(0.0))
+                .to_owned()
+        ).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.alter_table(
+            Table::alter()
+                .table(Payout::Table)
+                .modify_column(ColumnDef::new(Payout::Share).decimal_len(5, 5).not_null().default(0.0))
+                .to_owned()
+        ).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Payout {
+    #[sea_orm(iden = "payouts")]
+    Table,
+    /// 分配総額に対する自分の取り分割合（Payout.Points / Flush.Points）
+    Share,
+}
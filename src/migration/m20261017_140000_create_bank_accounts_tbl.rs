@@ -0,0 +1,75 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 個人の振込先口座（口座番号と口座名義は AES-256-GCM で暗号化して保存する）
+        manager.create_table(
+            Table::create()
+                .table(BankAccount::Table)
+                .if_not_exists()
+                .col(pk_auto(BankAccount::Id))
+                .col(unsigned(BankAccount::UsrID).not_null().default(0))
+                .col(string_len(BankAccount::BankCode, 4).not_null().default(""))
+                .col(string_len(BankAccount::BankName, 30).not_null().default(""))
+                .col(string_len(BankAccount::BranchCode, 3).not_null().default(""))
+                .col(string_len(BankAccount::BranchName, 30).not_null().default(""))
+                .col(tiny_unsigned(BankAccount::AccountType).not_null().default(1))
+                .col(string_len(BankAccount::AccountNumber, 255).not_null().default(""))
+                .col(string_len(BankAccount::AccountLast4, 4).not_null().default(""))
+                .col(string_len(BankAccount::Holder, 255).not_null().default(""))
+                .col(unsigned(BankAccount::ApxID).not_null())
+                .col(unsigned(BankAccount::VdrID).not_null())
+                .col(ColumnDef::new(BankAccount::CreatedAt).date_time().not_null().default(Expr::current_timestamp()))
+                .col(ColumnDef::new(BankAccount::UpdatedAt).date_time().not_null().default(Expr::current_timestamp()))
+                .to_owned()
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("bankaccount_apxid_vdrid_usrid_uq")
+                .table(BankAccount::Table)
+                .col(BankAccount::ApxID)
+                .col(BankAccount::VdrID)
+                .col(BankAccount::UsrID)
+                .unique()
+                .to_owned()
+        ).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(BankAccount::Table).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum BankAccount {
+    #[sea_orm(iden = "bank_accounts")]
+    Table,
+    Id,
+    /// 口座を所有する個人の UsrID（1 人 1 口座）
+    UsrID,
+    /// 金融機関コード（4 桁）
+    BankCode,
+    /// 金融機関名（カナ）
+    BankName,
+    /// 支店コード（3 桁）
+    BranchCode,
+    /// 支店名（カナ）
+    BranchName,
+    /// 預金種目（1:普通, 2:当座, 4:貯蓄）
+    AccountType,
+    /// 口座番号（暗号化済み）
+    AccountNumber,
+    /// 口座番号の下 4 桁（マスク表示用）
+    AccountLast4,
+    /// 口座名義（カナ、暗号化済み）
+    Holder,
+    ApxID,
+    VdrID,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,116 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 個人による出金申請
+        manager.create_table(
+            Table::create()
+                .table(Withdrawal::Table)
+                .if_not_exists()
+                .col(pk_auto(Withdrawal::Id))
+                .col(unsigned(Withdrawal::UsrID).not_null().default(0))
+                .col(unsigned(Withdrawal::Amount).not_null().default(0))
+                .col(tiny_unsigned(Withdrawal::Status).not_null().default(1))
+                .col(string_len(Withdrawal::Note, 255).not_null().default(""))
+                .col(ColumnDef::new(Withdrawal::ReviewedAt).date_time().null())
+                .col(ColumnDef::new(Withdrawal::PaidAt).date_time().null())
+                .col(unsigned(Withdrawal::ApxID).not_null())
+                .col(unsigned(Withdrawal::VdrID).not_null())
+                .col(ColumnDef::new(Withdrawal::CreatedAt).date_time().not_null().default(Expr::current_timestamp()))
+                .col(ColumnDef::new(Withdrawal::UpdatedAt).date_time().not_null().default(Expr::current_timestamp()))
+                .to_owned()
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("withdrawal_apxid_vdrid_usrid_idx")
+                .table(Withdrawal::Table)
+                .col(Withdrawal::ApxID)
+                .col(Withdrawal::VdrID)
+                .col(Withdrawal::UsrID)
+                .to_owned()
+        ).await?;
+
+        // 出金申請のステータス遷移履歴（監査用の台帳。追記のみで更新・削除しない）
+        manager.create_table(
+            Table::create()
+                .table(WithdrawalStatus::Table)
+                .if_not_exists()
+                .col(pk_auto(WithdrawalStatus::Id))
+                .col(unsigned(WithdrawalStatus::WithdrawalID).not_null().default(0))
+                .col(unsigned(WithdrawalStatus::UsrID).not_null().default(0))
+                .col(unsigned(WithdrawalStatus::Amount).not_null().default(0))
+                .col(tiny_unsigned(WithdrawalStatus::Status).not_null().default(0))
+                .col(unsigned(WithdrawalStatus::ActorID).not_null().default(0))
+                .col(string_len(WithdrawalStatus::Note, 255).not_null().default(""))
+                .col(unsigned(WithdrawalStatus::ApxID).not_null())
+                .col(unsigned(WithdrawalStatus::VdrID).not_null())
+                .col(ColumnDef::new(WithdrawalStatus::CreatedAt).date_time().not_null().default(Expr::current_timestamp()))
+                .col(ColumnDef::new(WithdrawalStatus::UpdatedAt).date_time().not_null().default(Expr::current_timestamp()))
+                .to_owned()
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("withdrawalstatus_apxid_vdrid_withdrawalid_idx")
+                .table(WithdrawalStatus::Table)
+                .col(WithdrawalStatus::ApxID)
+                .col(WithdrawalStatus::VdrID)
+                .col(WithdrawalStatus::WithdrawalID)
+                .to_owned()
+        ).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(WithdrawalStatus::Table).to_owned()).await?;
+        manager.drop_table(Table::drop().table(Withdrawal::Table).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Withdrawal {
+    #[sea_orm(iden = "withdrawals")]
+    Table,
+    Id,
+    /// 申請した個人の UsrID
+    UsrID,
+    /// 出金額
+    Amount,
+    /// 1: 申請, 2: 承認, 3: 却下, 4: 支払済
+    Status,
+    /// 最後の審査・支払時のメモ
+    Note,
+    /// 承認・却下された日時
+    ReviewedAt,
+    /// 支払済となった日時
+    PaidAt,
+    ApxID,
+    VdrID,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum WithdrawalStatus {
+    #[sea_orm(iden = "withdrawal_statuses")]
+    Table,
+    Id,
+    WithdrawalID,
+    /// 申請した個人の UsrID
+    UsrID,
+    /// 遷移時点の出金額
+    Amount,
+    /// 遷移後のステータス
+    Status,
+    /// 遷移を行った UsrID（個人 または VDR）
+    ActorID,
+    Note,
+    ApxID,
+    VdrID,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,76 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // VDR ごとの源泉徴収ルール（税率が共に 0 の場合は源泉徴収しない）
+        manager.alter_table(
+            Table::alter()
+                .table(Usr::Table)
+                .add_column_if_not_exists(ColumnDef::new(Usr::WithholdingThreshold).unsigned().not_null().default(0))
+                .add_column_if_not_exists(ColumnDef::new(Usr::WithholdingRate).decimal_len(5, 5).not_null().default(0.0))
+                .add_column_if_not_exists(ColumnDef::new(Usr::WithholdingReducedRate).decimal_len(5, 5).not_null().default(0.0))
+                .add_column_if_not_exists(ColumnDef::new(Usr::WithholdingReducedLimit).unsigned().not_null().default(0))
+                .to_owned()
+        ).await?;
+
+        // payouts.amount は源泉徴収前の総額とし、源泉徴収額と差引支払額を保存する
+        manager.alter_table(
+            Table::alter()
+                .table(Payout::Table)
+                .add_column_if_not_exists(ColumnDef::new(Payout::Withheld).unsigned().not_null().default(0))
+                .add_column_if_not_exists(ColumnDef::new(Payout::Net).unsigned().not_null().default(0))
+                .to_owned()
+        ).await?;
+
+        // 既存の payouts は源泉徴収なしとして差引支払額を埋める
+        manager.get_connection().execute_unprepared("UPDATE `payouts` SET `net` = `amount`").await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.alter_table(
+            Table::alter()
+                .table(Payout::Table)
+                .drop_column(Payout::Withheld)
+                .drop_column(Payout::Net)
+                .to_owned()
+        ).await?;
+        manager.alter_table(
+            Table::alter()
+                .table(Usr::Table)
+                .drop_column(Usr::WithholdingThreshold)
+                .drop_column(Usr::WithholdingRate)
+                .drop_column(Usr::WithholdingReducedRate)
+                .drop_column(Usr::WithholdingReducedLimit)
+                .to_owned()
+        ).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Usr {
+    #[sea_orm(iden = "usrs")]
+    Table,
+    /// 源泉徴収の対象となる 1 回の支払金額の下限（これ未満の支払は源泉徴収しない。0 は全ての支払が対象）
+    WithholdingThreshold,
+    /// 源泉徴収税率（軽減税率の区分を超える部分に適用）
+    WithholdingRate,
+    /// 軽減税率（1 回の支払金額のうち WithholdingReducedLimit までの部分に適用）
+    WithholdingReducedRate,
+    /// 軽減税率を適用する区分の上限額（0 は軽減税率の区分なし）
+    WithholdingReducedLimit,
+}
+
+#[derive(DeriveIden)]
+enum Payout {
+    #[sea_orm(iden = "payouts")]
+    Table,
+    /// 源泉徴収額
+    Withheld,
+    /// 差引支払額（Amount - Withheld）
+    Net,
+}
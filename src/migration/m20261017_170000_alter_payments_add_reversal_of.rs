@@ -0,0 +1,40 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 返金（取消）の入金は、取り消した元の入金を参照する
+        manager.alter_table(
+            Table::alter()
+                .table(Payment::Table)
+                .add_column_if_not_exists(ColumnDef::new(Payment::ReversalOf).unsigned().null())
+                .to_owned()
+        ).await?;
+
+        // 1 件の入金を取り消せるのは 1 度のみ（NULL は重複可）
+        manager.create_index(
+            Index::create()
+                .name("payment_reversalof_uq")
+                .table(Payment::Table)
+                .col(Payment::ReversalOf)
+                .unique()
+                .to_owned()
+        ).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_index(Index::drop().name("payment_reversalof_uq").table(Payment::Table).to_owned()).await?;
+        manager.alter_table(Table::alter().table(Payment::Table).drop_column(Payment::ReversalOf).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Payment {
+    #[sea_orm(iden = "payments")]
+    Table,
+    /// 取り消した元の入金 ID（NULL は通常の入金。値がある場合は返金であり、金額はプールから差し引かれた額を表す）
+    ReversalOf,
+}
@@ -0,0 +1,70 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 個人の保有ポイントに対する手動調整の台帳（監査用。追記のみで更新・削除しない）
+        manager.create_table(
+            Table::create()
+                .table(PointAdjustment::Table)
+                .if_not_exists()
+                .col(pk_auto(PointAdjustment::Id))
+                .col(unsigned(PointAdjustment::UsrID).not_null().default(0))
+                .col(integer(PointAdjustment::Delta).not_null().default(0))
+                .col(unsigned(PointAdjustment::BefPoints).not_null().default(0))
+                .col(unsigned(PointAdjustment::AftPoints).not_null().default(0))
+                .col(tiny_unsigned(PointAdjustment::Reason).not_null().default(0))
+                .col(string_len(PointAdjustment::Note, 255).not_null().default(""))
+                .col(unsigned(PointAdjustment::ActorID).not_null().default(0))
+                .col(unsigned(PointAdjustment::StaffID).not_null().default(0))
+                .col(unsigned(PointAdjustment::ApxID).not_null())
+                .col(unsigned(PointAdjustment::VdrID).not_null())
+                .col(ColumnDef::new(PointAdjustment::CreatedAt).date_time().not_null().default(Expr::current_timestamp()))
+                .col(ColumnDef::new(PointAdjustment::UpdatedAt).date_time().not_null().default(Expr::current_timestamp()))
+                .to_owned()
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("pointadjustment_apxid_vdrid_usrid_idx")
+                .table(PointAdjustment::Table)
+                .col(PointAdjustment::ApxID)
+                .col(PointAdjustment::VdrID)
+                .col(PointAdjustment::UsrID)
+                .to_owned()
+        ).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(PointAdjustment::Table).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PointAdjustment {
+    #[sea_orm(iden = "point_adjustments")]
+    Table,
+    Id,
+    /// 調整対象の個人の UsrID
+    UsrID,
+    /// 調整量（符号付き。負の値は減算）
+    Delta,
+    /// 調整前の保有ポイント
+    BefPoints,
+    /// 調整後の保有ポイント
+    AftPoints,
+    /// 1: 誤ったバッジ付与, 2: 不正, 3: 訂正, 4: その他
+    Reason,
+    Note,
+    /// 調整を行った UsrID（VDR または スタッフ）
+    ActorID,
+    /// 調整を行ったスタッフの UsrID（スタッフ token でない場合は 0）
+    StaffID,
+    ApxID,
+    VdrID,
+    CreatedAt,
+    UpdatedAt,
+}
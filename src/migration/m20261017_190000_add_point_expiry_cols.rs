@@ -0,0 +1,60 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // VDR ごとのポイント失効ポリシー（0 は失効しない）
+        manager.alter_table(
+            Table::alter()
+                .table(Usr::Table)
+                .add_column_if_not_exists(ColumnDef::new(Usr::PointExpireMonths).unsigned().not_null().default(0))
+                .to_owned()
+        ).await?;
+
+        // ポイント履歴に失効（保有ポイントを減らす行）を記録できるようにする
+        manager.alter_table(
+            Table::alter()
+                .table(Point::Table)
+                .add_column_if_not_exists(ColumnDef::new(Point::Kind).tiny_unsigned().not_null().default(1))
+                .add_column_if_not_exists(ColumnDef::new(Point::Expired).unsigned().not_null().default(0))
+                .to_owned()
+        ).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.alter_table(
+            Table::alter()
+                .table(Point::Table)
+                .drop_column(Point::Kind)
+                .drop_column(Point::Expired)
+                .to_owned()
+        ).await?;
+        manager.alter_table(
+            Table::alter()
+                .table(Usr::Table)
+                .drop_column(Usr::PointExpireMonths)
+                .to_owned()
+        ).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Usr {
+    #[sea_orm(iden = "usrs")]
+    Table,
+    /// 付与から分配されないまま経過すると失効するまでの月数（0 は失効しない）
+    PointExpireMonths,
+}
+
+#[derive(DeriveIden)]
+enum Point {
+    #[sea_orm(iden = "points")]
+    Table,
+    /// 1: 授与, 2: 失効
+    Kind,
+    /// 失効したポイント数（失効の行のみ。保有ポイントから減算される）
+    Expired,
+}
@@ -0,0 +1,102 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 資格型のバッジの有効期間（0 は無期限）
+        manager.alter_table(
+            Table::alter()
+                .table(Badge::Table)
+                .add_column_if_not_exists(ColumnDef::new(Badge::ValidMonths).unsigned().not_null().default(0))
+                .to_owned()
+        ).await?;
+
+        // 授与の有効期限と取り消しの記録（行は削除せず監査用に保持する）
+        manager.alter_table(
+            Table::alter()
+                .table(UsrBadge::Table)
+                .add_column_if_not_exists(ColumnDef::new(UsrBadge::ExpiresAt).date_time().null())
+                .add_column_if_not_exists(ColumnDef::new(UsrBadge::RevokedAt).date_time().null())
+                .add_column_if_not_exists(ColumnDef::new(UsrBadge::RevokeReason).tiny_unsigned().not_null().default(0))
+                .add_column_if_not_exists(ColumnDef::new(UsrBadge::RevokeNote).string_len(255).not_null().default(""))
+                .add_column_if_not_exists(ColumnDef::new(UsrBadge::RevokedBy).unsigned().not_null().default(0))
+                .add_column_if_not_exists(ColumnDef::new(UsrBadge::RevokedStaffID).unsigned().not_null().default(0))
+                .add_column_if_not_exists(ColumnDef::new(UsrBadge::RevokedPoints).unsigned().not_null().default(0))
+                .to_owned()
+        ).await?;
+
+        // ポイント履歴に取り消し（授与で付与したポイントを戻す行）を記録できるようにする
+        manager.alter_table(
+            Table::alter()
+                .table(Point::Table)
+                .add_column_if_not_exists(ColumnDef::new(Point::Revoked).unsigned().not_null().default(0))
+                .to_owned()
+        ).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.alter_table(
+            Table::alter()
+                .table(Point::Table)
+                .drop_column(Point::Revoked)
+                .to_owned()
+        ).await?;
+        manager.alter_table(
+            Table::alter()
+                .table(UsrBadge::Table)
+                .drop_column(UsrBadge::ExpiresAt)
+                .drop_column(UsrBadge::RevokedAt)
+                .drop_column(UsrBadge::RevokeReason)
+                .drop_column(UsrBadge::RevokeNote)
+                .drop_column(UsrBadge::RevokedBy)
+                .drop_column(UsrBadge::RevokedStaffID)
+                .drop_column(UsrBadge::RevokedPoints)
+                .to_owned()
+        ).await?;
+        manager.alter_table(
+            Table::alter()
+                .table(Badge::Table)
+                .drop_column(Badge::ValidMonths)
+                .to_owned()
+        ).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Badge {
+    #[sea_orm(iden = "badges")]
+    Table,
+    /// 授与から失効するまでの月数（0 は無期限）
+    ValidMonths,
+}
+
+#[derive(DeriveIden)]
+enum UsrBadge {
+    #[sea_orm(iden = "usr_badges")]
+    Table,
+    /// 有効期限（NULL は無期限）
+    ExpiresAt,
+    /// 取り消し日時（NULL は有効）
+    RevokedAt,
+    /// 取り消しの理由（0: 未取り消し, 1: 誤授与, 2: 不正行為, 3: その他）
+    RevokeReason,
+    /// 取り消しの補足
+    RevokeNote,
+    /// 取り消しを行った VDR または USR の ID
+    RevokedBy,
+    /// 取り消しを行ったスタッフの ID（スタッフ以外は 0）
+    RevokedStaffID,
+    /// 取り消しにより戻したポイント数（分配済みの場合は 0）
+    RevokedPoints,
+}
+
+#[derive(DeriveIden)]
+enum Point {
+    #[sea_orm(iden = "points")]
+    Table,
+    /// 取り消したポイント数（取り消しの行のみ。保有ポイントから減算される）
+    Revoked,
+}
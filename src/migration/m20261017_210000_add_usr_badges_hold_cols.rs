@@ -0,0 +1,46 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 共謀の疑いがある授与を分配（flush）の対象から保留する
+        manager.alter_table(
+            Table::alter()
+                .table(UsrBadge::Table)
+                .add_column_if_not_exists(ColumnDef::new(UsrBadge::HeldAt).date_time().null())
+                .add_column_if_not_exists(ColumnDef::new(UsrBadge::HeldBy).unsigned().not_null().default(0))
+                .add_column_if_not_exists(ColumnDef::new(UsrBadge::HeldStaffID).unsigned().not_null().default(0))
+                .add_column_if_not_exists(ColumnDef::new(UsrBadge::HeldPoints).unsigned().not_null().default(0))
+                .to_owned()
+        ).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.alter_table(
+            Table::alter()
+                .table(UsrBadge::Table)
+                .drop_column(UsrBadge::HeldAt)
+                .drop_column(UsrBadge::HeldBy)
+                .drop_column(UsrBadge::HeldStaffID)
+                .drop_column(UsrBadge::HeldPoints)
+                .to_owned()
+        ).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UsrBadge {
+    #[sea_orm(iden = "usr_badges")]
+    Table,
+    /// 保留日時（NULL は保留されていない。解除されると NULL に戻る）
+    HeldAt,
+    /// 保留を行った VDR または USR の ID
+    HeldBy,
+    /// 保留を行ったスタッフの ID（スタッフ以外は 0）
+    HeldStaffID,
+    /// 分配の対象から除外するポイント数（保留時点で未分配だった point + extra）
+    HeldPoints,
+}
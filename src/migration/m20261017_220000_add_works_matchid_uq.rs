@@ -0,0 +1,30 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 1 件のアプローチから作成される就業情報は 1 件のみ
+        manager.create_index(
+            Index::create()
+                .name("work_matchid_uq")
+                .table(Work::Table)
+                .col(Work::MatchID)
+                .unique()
+                .to_owned()
+        ).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_index(Index::drop().name("work_matchid_uq").table(Work::Table).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Work {
+    #[sea_orm(iden = "works")]
+    Table,
+    MatchID,
+}
@@ -0,0 +1,34 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 全銀フォーマットで振込ファイルへ出力した payout は、出金可能残高から除外する
+        manager.alter_table(
+            Table::alter()
+                .table(Payout::Table)
+                .add_column_if_not_exists(ColumnDef::new(Payout::ExportedAt).date_time().null())
+                .to_owned()
+        ).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.alter_table(
+            Table::alter()
+                .table(Payout::Table)
+                .drop_column(Payout::ExportedAt)
+                .to_owned()
+        ).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Payout {
+    #[sea_orm(iden = "payouts")]
+    Table,
+    /// 振込ファイルへ出力した日時（NULL は未出力）
+    ExportedAt,
+}
@@ -0,0 +1,56 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ポイント調整は申請者とは別の承認者が承認した時点で保有ポイントへ反映する
+        manager.alter_table(
+            Table::alter()
+                .table(PointAdjustment::Table)
+                .add_column_if_not_exists(ColumnDef::new(PointAdjustment::Status).tiny_unsigned().not_null().default(1))
+                .add_column_if_not_exists(ColumnDef::new(PointAdjustment::ReviewerID).unsigned().not_null().default(0))
+                .add_column_if_not_exists(ColumnDef::new(PointAdjustment::ReviewerStaffID).unsigned().not_null().default(0))
+                .add_column_if_not_exists(ColumnDef::new(PointAdjustment::ReviewedAt).date_time().null())
+                .to_owned()
+        ).await?;
+
+        // 既存の調整は作成時に反映済みのため、承認済みとする
+        manager.exec_stmt(
+            Query::update()
+                .table(PointAdjustment::Table)
+                .value(PointAdjustment::Status, 2)
+                .value(PointAdjustment::ReviewedAt, Expr::col(PointAdjustment::CreatedAt))
+                .to_owned()
+        ).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.alter_table(
+            Table::alter()
+                .table(PointAdjustment::Table)
+                .drop_column(PointAdjustment::Status)
+                .drop_column(PointAdjustment::ReviewerID)
+                .drop_column(PointAdjustment::ReviewerStaffID)
+                .drop_column(PointAdjustment::ReviewedAt)
+                .to_owned()
+        ).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PointAdjustment {
+    #[sea_orm(iden = "point_adjustments")]
+    Table,
+    /// 1: 承認待ち, 2: 承認済み, 3: 却下
+    Status,
+    /// 承認・却下を行った UsrID（VDR または スタッフ）
+    ReviewerID,
+    /// 承認・却下を行ったスタッフの UsrID（スタッフ token でない場合は 0）
+    ReviewerStaffID,
+    /// 承認・却下の日時
+    ReviewedAt,
+    CreatedAt,
+}
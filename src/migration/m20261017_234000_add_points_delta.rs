@@ -0,0 +1,87 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 保有ポイントへの増減は符号付きの delta に統一し、集計は delta の合計のみで行う
+        manager.alter_table(
+            Table::alter()
+                .table(Point::Table)
+                .add_column_if_not_exists(ColumnDef::new(Point::Delta).integer().not_null().default(0))
+                .to_owned()
+        ).await?;
+
+        // 既存の行は種別ごとに delta を埋める（授与は point + extra、失効・取り消しは減算したポイント数の負の値）
+        manager.exec_stmt(
+            Query::update()
+                .table(Point::Table)
+                .value(Point::Delta, Expr::col(Point::Base).add(Expr::col(Point::Extra)))
+                .and_where(Expr::col(Point::Kind).eq(1))
+                .to_owned()
+        ).await?;
+        // 失効・取り消しの列は unsigned のため、符号付きへ変換してから負の値とする
+        manager.get_connection().execute_unprepared("UPDATE `points` SET `delta` = -CAST(`expired` AS SIGNED) WHERE `kind` = 2").await?;
+        manager.get_connection().execute_unprepared("UPDATE `points` SET `delta` = -CAST(`revoked` AS SIGNED) WHERE `kind` = 3").await?;
+
+        // delta に移した失効・取り消しのポイント数の列は削除する
+        manager.alter_table(
+            Table::alter()
+                .table(Point::Table)
+                .drop_column(Point::Expired)
+                .drop_column(Point::Revoked)
+                .to_owned()
+        ).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.alter_table(
+            Table::alter()
+                .table(Point::Table)
+                .add_column_if_not_exists(ColumnDef::new(Point::Expired).unsigned().not_null().default(0))
+                .add_column_if_not_exists(ColumnDef::new(Point::Revoked).unsigned().not_null().default(0))
+                .to_owned()
+        ).await?;
+        manager.exec_stmt(
+            Query::update()
+                .table(Point::Table)
+                .value(Point::Expired, Expr::value(0).sub(Expr::col(Point::Delta)))
+                .and_where(Expr::col(Point::Kind).eq(2))
+                .to_owned()
+        ).await?;
+        manager.exec_stmt(
+            Query::update()
+                .table(Point::Table)
+                .value(Point::Revoked, Expr::value(0).sub(Expr::col(Point::Delta)))
+                .and_where(Expr::col(Point::Kind).eq(3))
+                .to_owned()
+        ).await?;
+        manager.alter_table(
+            Table::alter()
+                .table(Point::Table)
+                .drop_column(Point::Delta)
+                .to_owned()
+        ).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Point {
+    #[sea_orm(iden = "points")]
+    Table,
+    /// 基本ポイント（point 列）
+    #[sea_orm(iden = "point")]
+    Base,
+    Extra,
+    /// 1: 授与, 2: 失効, 3: 取り消し
+    Kind,
+    /// 受領者（to）の保有ポイントに対する増減（符号付き）
+    /// 授与は point + extra、失効・取り消しは減算したポイント数の負の値
+    Delta,
+    /// 失効したポイント数（delta へ移行済み）
+    Expired,
+    /// 取り消したポイント数（delta へ移行済み）
+    Revoked,
+}
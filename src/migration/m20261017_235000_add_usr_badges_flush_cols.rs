@@ -0,0 +1,49 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 授与ごとに、そのポイントが分配（flush）済みかを記録する
+        manager.alter_table(
+            Table::alter()
+                .table(UsrBadge::Table)
+                .add_column_if_not_exists(ColumnDef::new(UsrBadge::FlushID).unsigned().not_null().default(0))
+                .add_column_if_not_exists(ColumnDef::new(UsrBadge::FlushedAt).date_time().null())
+                .to_owned()
+        ).await?;
+
+        // 既存の授与は、受領者への最後の分配より前のもの（保留中を除く）を分配済みとする
+        manager.get_connection().execute_unprepared(
+            "UPDATE `usr_badges` ub \
+             JOIN (SELECT `usr_id`, `apx_id`, `vdr_id`, MAX(`flush_id`) AS `flush_id`, MAX(`created_at`) AS `flushed_at` \
+                   FROM `payouts` GROUP BY `usr_id`, `apx_id`, `vdr_id`) p \
+               ON p.`usr_id` = ub.`to` AND p.`apx_id` = ub.`apx_id` AND p.`vdr_id` = ub.`vdr_id` \
+             SET ub.`flush_id` = p.`flush_id`, ub.`flushed_at` = p.`flushed_at` \
+             WHERE ub.`flush_id` = 0 AND ub.`held_at` IS NULL AND ub.`created_at` <= p.`flushed_at`"
+        ).await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.alter_table(
+            Table::alter()
+                .table(UsrBadge::Table)
+                .drop_column(UsrBadge::FlushID)
+                .drop_column(UsrBadge::FlushedAt)
+                .to_owned()
+        ).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UsrBadge {
+    #[sea_orm(iden = "usr_badges")]
+    Table,
+    /// 授与のポイントを分配した分配ID（0 は未分配）
+    FlushID,
+    /// 分配日時（NULL は未分配）
+    FlushedAt,
+}
@@ -20,6 +20,24 @@ impl MigratorTrait for Migrator {
             Box::new(m20260107_050440_create_pools_tbl::Migration),
             Box::new(m20260107_050440_create_flushes_tbl::Migration),
             Box::new(m20260107_050440_create_payouts_tbl::Migration),
+            Box::new(m20261017_090000_create_work_corrections_tbl::Migration),
+            Box::new(m20261017_100000_alter_badges_add_retired_at::Migration),
+            Box::new(m20261017_110000_alter_usr_badges_add_job_id::Migration),
+            Box::new(m20261017_120000_alter_points_add_usr_badge_id::Migration),
+            Box::new(m20261017_130000_alter_payouts_widen_share::Migration),
+            Box::new(m20261017_140000_create_bank_accounts_tbl::Migration),
+            Box::new(m20261017_150000_create_withdrawals_tbl::Migration),
+            Box::new(m20261017_160000_add_withholding_cols::Migration),
+            Box::new(m20261017_170000_alter_payments_add_reversal_of::Migration),
+            Box::new(m20261017_180000_create_point_adjustments_tbl::Migration),
+            Box::new(m20261017_190000_add_point_expiry_cols::Migration),
+            Box::new(m20261017_200000_add_badge_revoke_expiry_cols::Migration),
+            Box::new(m20261017_210000_add_usr_badges_hold_cols::Migration),
+            Box::new(m20261017_220000_add_works_matchid_uq::Migration),
+            Box::new(m20261017_230000_add_payouts_exported_at::Migration),
+            Box::new(m20261017_233000_add_point_adjustments_review_cols::Migration),
+            Box::new(m20261017_234000_add_points_delta::Migration),
+            Box::new(m20261017_235000_add_usr_badges_flush_cols::Migration),
         ]
     }
 }
@@ -39,3 +57,21 @@ mod m20260107_050440_create_payments_tbl;
 mod m20260107_050440_create_pools_tbl;
 mod m20260107_050440_create_flushes_tbl;
 mod m20260107_050440_create_payouts_tbl;
+mod m20261017_090000_create_work_corrections_tbl;
+mod m20261017_100000_alter_badges_add_retired_at;
+mod m20261017_110000_alter_usr_badges_add_job_id;
+mod m20261017_120000_alter_points_add_usr_badge_id;
+mod m20261017_130000_alter_payouts_widen_share;
+mod m20261017_140000_create_bank_accounts_tbl;
+mod m20261017_150000_create_withdrawals_tbl;
+mod m20261017_160000_add_withholding_cols;
+mod m20261017_170000_alter_payments_add_reversal_of;
+mod m20261017_180000_create_point_adjustments_tbl;
+mod m20261017_190000_add_point_expiry_cols;
+mod m20261017_200000_add_badge_revoke_expiry_cols;
+mod m20261017_210000_add_usr_badges_hold_cols;
+mod m20261017_220000_add_works_matchid_uq;
+mod m20261017_230000_add_payouts_exported_at;
+mod m20261017_233000_add_point_adjustments_review_cols;
+mod m20261017_234000_add_points_delta;
+mod m20261017_235000_add_usr_badges_flush_cols;
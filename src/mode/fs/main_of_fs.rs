@@ -0,0 +1,63 @@
+use crate::utils::db::get_db;
+use crate::utils::init::{CommonFlgs, HasCommonFlgs, init};
+use crate::mode::fs::scheduler::run_scheduled_flush;
+use clap::Parser;
+use serde::Serialize;
+use std::iter::{Chain, Cloned, Once};
+use std::slice::Iter;
+use std::time::Duration;
+
+#[derive(Debug, Parser, Serialize)]
+#[command(override_usage = "bsdr fs [OPTIONS]")]
+pub struct FSFlgs {
+    #[command(flatten)]
+    pub common: CommonFlgs,
+    #[arg(short = 'i', long = "interval", default_value_t = 3600, help = "Interval seconds between runs.")]
+    pub interval: u64,
+    #[arg(long = "once", default_value_t = false, help = "Run only once and exit (for cron).")]
+    pub once: bool,
+}
+
+impl HasCommonFlgs for FSFlgs {
+    fn common_flgs(&self) -> &CommonFlgs {
+        &self.common
+    }
+}
+
+pub async fn main_of_fs(args: Chain<Once<String>, Cloned<Iter<'_, String>>>) {
+    // ==============================
+    // 初期化
+    // ==============================
+    let (flgs, env) = init::<FSFlgs>(args).expect("Failed to init fs mode.");
+
+    // ==============================
+    // フラグの出力
+    // ==============================
+    let flgs_json = serde_json::to_string(&flgs).expect("Failed to serialize flgs to json.");
+    log::debug!("FS-FLAGS: {}", flgs_json);
+
+    // ==============================
+    // DB接続
+    // ==============================
+    let db_result = get_db(&env, &flgs.common.log_level).await;
+    let db = match db_result {
+        Ok(db) => { log::debug!("DB created successfully."); db }
+        Err(e) => { eprintln!("Failed to create DB: {}", e); std::process::exit(1); }
+    };
+    let rw_conn = db.get_rw().expect("Failed to get RW connection for scheduled flush.");
+
+    // ==============================
+    // 定期分配の実行
+    // ==============================
+    loop {
+        log::info!("Running scheduled flush...");
+        match run_scheduled_flush(rw_conn).await {
+            Ok(()) => log::info!("Scheduled flush completed."),
+            Err(e) => log::error!("Scheduled flush failed: {}", e),
+        }
+        if flgs.once {
+            break;
+        }
+        tokio::time::sleep(Duration::from_secs(flgs.interval.max(1))).await;
+    }
+}
@@ -0,0 +1,3 @@
+pub mod main_of_fs;
+pub mod scheduler;
+pub use main_of_fs::main_of_fs;
@@ -0,0 +1,131 @@
+use anyhow::Context;
+use axum::http::StatusCode;
+use chrono::Duration;
+use sea_orm::{ColumnTrait, ConnectionTrait, DatabaseConnection, DbBackend, EntityTrait, QueryFilter, QueryOrder, Statement, TransactionTrait};
+use crate::entities::{flushes, usrs};
+use crate::mode::rt::rtres::errs_res::ApiError;
+use crate::mode::rt::rtutils::flush_for_rt::execute_flush_for_rt;
+
+/// 定期分配の多重実行を防ぐための MySQL アドバイザリロック名
+const LOCK_NAME: &str = "bsdr_fs_scheduled_flush";
+
+/// VDR ごとの実行結果
+enum FlushOutcome {
+    Flushed { flush_id: i32, total: u32, fee: u32, distributable: u32, points: u32, payouts: usize },
+    NotDue { due_at: chrono::NaiveDateTime },
+    Skipped { reason: String },
+    Failed { reason: String },
+}
+
+/// 定期分配を 1 回実行する
+/// 複数のデプロイから同時に起動されても 1 インスタンスのみが実行するよう、GET_LOCK によるアドバイザリロックを取得する
+/// GET_LOCK はコネクション単位のロックであるため、トランザクションを開始して 1 本のコネクションを占有した上で取得・解放する
+pub async fn run_scheduled_flush(conn: &DatabaseConnection) -> anyhow::Result<()> {
+    // --------------------------------
+    // 1. アドバイザリロックの取得
+    // --------------------------------
+    let lock_conn = conn.begin().await.context("Failed to begin lock connection")?;
+    let got = lock_conn
+        .query_one(Statement::from_sql_and_values(DbBackend::MySql, "SELECT GET_LOCK(?, 0) AS got", [LOCK_NAME.into()]))
+        .await
+        .context("Failed to get advisory lock")?
+        .map(|r| r.try_get::<Option<i64>>("", "got"))
+        .transpose()
+        .context("Failed to read advisory lock result")?
+        .flatten();
+    if got != Some(1) {
+        log::info!("<Scheduler> Advisory lock '{}' is held by another instance. Skipped.", LOCK_NAME);
+        lock_conn.rollback().await.context("Failed to close lock connection")?;
+        return Ok(());
+    }
+    log::debug!("<Scheduler> Advisory lock '{}' acquired.", LOCK_NAME);
+    // --------------------------------
+    // 2. 分配の実行（結果に関わらずロックは解放する）
+    // --------------------------------
+    let res = flush_due_vdrs(conn).await;
+    // --------------------------------
+    // 3. アドバイザリロックの解放
+    // --------------------------------
+    lock_conn
+        .execute(Statement::from_sql_and_values(DbBackend::MySql, "SELECT RELEASE_LOCK(?)", [LOCK_NAME.into()]))
+        .await
+        .context("Failed to release advisory lock")?;
+    lock_conn.commit().await.context("Failed to close lock connection")?;
+    log::debug!("<Scheduler> Advisory lock '{}' released.", LOCK_NAME);
+    res
+}
+
+/// flush_days が設定された VDR のうち、前回の分配（未分配の場合は VDR の作成日時）から flush_days 日以上経過したものを分配する
+async fn flush_due_vdrs(conn: &DatabaseConnection) -> anyhow::Result<()> {
+    let now = chrono::Local::now().naive_local();
+    let vdrs = usrs::Entity::find()
+        .filter(usrs::Column::ApxId.is_not_null())
+        .filter(usrs::Column::VdrId.is_null())
+        .filter(usrs::Column::FlushDays.gt(0))
+        .order_by_asc(usrs::Column::Id)
+        .all(conn)
+        .await
+        .context("Failed to fetch VDRs")?;
+    log::info!("<Scheduler> {} VDR(s) with flush_days found.", vdrs.len());
+    let mut flushed = 0;
+    for vdr in vdrs {
+        let apx_id = vdr.apx_id.unwrap_or(0);
+        let vdr_id = vdr.id as u32;
+        let outcome = flush_vdr_if_due(conn, &vdr, now).await;
+        match outcome {
+            FlushOutcome::Flushed { flush_id, total, fee, distributable, points, payouts } => {
+                flushed += 1;
+                log::info!("<Scheduler> apx: {}, vdr: {}, result: flushed, flush_id: {}, total: {}, fee: {}, distributable: {}, points: {}, payouts: {}", apx_id, vdr_id, flush_id, total, fee, distributable, points, payouts);
+            }
+            FlushOutcome::NotDue { due_at } => {
+                log::info!("<Scheduler> apx: {}, vdr: {}, result: not due, due_at: {}", apx_id, vdr_id, due_at.format("%Y-%m-%dT%H:%M:%S"));
+            }
+            FlushOutcome::Skipped { reason } => {
+                log::info!("<Scheduler> apx: {}, vdr: {}, result: skipped, reason: {}", apx_id, vdr_id, reason);
+            }
+            FlushOutcome::Failed { reason } => {
+                log::error!("<Scheduler> apx: {}, vdr: {}, result: failed, reason: {}", apx_id, vdr_id, reason);
+            }
+        }
+    }
+    log::info!("<Scheduler> {} VDR(s) flushed.", flushed);
+    Ok(())
+}
+
+/// VDR の分配サイクルを判定し、期限を迎えていれば分配する
+/// プールが空、または分配対象のポイントがない場合（409）はスキップとして扱う
+async fn flush_vdr_if_due(conn: &DatabaseConnection, vdr: &usrs::Model, now: chrono::NaiveDateTime) -> FlushOutcome {
+    let apx_id = vdr.apx_id.unwrap_or(0);
+    let vdr_id = vdr.id as u32;
+    let last = flushes::Entity::find()
+        .filter(flushes::Column::ApxId.eq(apx_id))
+        .filter(flushes::Column::VdrId.eq(vdr_id))
+        .order_by_desc(flushes::Column::CreatedAt)
+        .one(conn)
+        .await;
+    let last = match last {
+        Ok(last) => last,
+        Err(e) => return FlushOutcome::Failed { reason: format!("Fetch last flush error: {}", e) },
+    };
+    let base = last.map(|f| f.created_at).unwrap_or(vdr.created_at);
+    let due_at = base + Duration::days(vdr.flush_days as i64);
+    if now < due_at {
+        return FlushOutcome::NotDue { due_at };
+    }
+    match execute_flush_for_rt(conn, apx_id, vdr_id).await {
+        Ok((flush, plan)) => FlushOutcome::Flushed {
+            flush_id: flush.id,
+            total: plan.total,
+            fee: plan.fee,
+            distributable: plan.distributable,
+            points: plan.points,
+            payouts: plan.lines.len(),
+        },
+        Err(e) if e.status == StatusCode::CONFLICT.as_u16() => FlushOutcome::Skipped { reason: api_error_message(&e) },
+        Err(e) => FlushOutcome::Failed { reason: api_error_message(&e) },
+    }
+}
+
+fn api_error_message(e: &ApiError) -> String {
+    e.errors.iter().map(|d| d.message.as_str()).collect::<Vec<_>>().join(", ")
+}
@@ -1,2 +1,5 @@
 pub mod am;
+pub mod fs;
+pub mod pe;
+pub mod rc;
 pub mod rt;
@@ -0,0 +1,38 @@
+use anyhow::Context;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder};
+use crate::entities::usrs;
+use crate::mode::rt::rtutils::point_expiry_for_rt::expire_vdr_points_for_rt;
+
+/// ポイント失効を 1 回実行する
+/// point_expire_months が設定された VDR ごとに、失効日時を迎えたポイントを失効させる
+/// 個人ごとに行ロックした上で再計算するため、複数のインスタンスから同時に実行されても二重に失効することはない
+pub async fn run_point_expiry(conn: &DatabaseConnection) -> anyhow::Result<()> {
+    let now = chrono::Local::now().naive_local();
+    let vdrs = usrs::Entity::find()
+        .filter(usrs::Column::ApxId.is_not_null())
+        .filter(usrs::Column::VdrId.is_null())
+        .filter(usrs::Column::PointExpireMonths.gt(0))
+        .order_by_asc(usrs::Column::Id)
+        .all(conn)
+        .await
+        .context("Failed to fetch VDRs")?;
+    log::info!("<Expirer> {} VDR(s) with point_expire_months found.", vdrs.len());
+    let mut failed = 0;
+    for vdr in vdrs {
+        let apx_id = vdr.apx_id.unwrap_or(0);
+        let vdr_id = vdr.id as u32;
+        match expire_vdr_points_for_rt(conn, &vdr, now).await {
+            Ok((usrs, expired)) => {
+                log::info!("<Expirer> apx: {}, vdr: {}, months: {}, result: done, usrs: {}, expired: {}", apx_id, vdr_id, vdr.point_expire_months, usrs, expired);
+            }
+            Err(e) => {
+                failed += 1;
+                log::error!("<Expirer> apx: {}, vdr: {}, result: failed, reason: {}", apx_id, vdr_id, e);
+            }
+        }
+    }
+    if failed > 0 {
+        anyhow::bail!("{} VDR(s) failed.", failed);
+    }
+    Ok(())
+}
@@ -0,0 +1,3 @@
+pub mod main_of_pe;
+pub mod expirer;
+pub use main_of_pe::main_of_pe;
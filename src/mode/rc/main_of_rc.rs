@@ -0,0 +1,64 @@
+use crate::utils::db::get_db;
+use crate::utils::init::{CommonFlgs, HasCommonFlgs, init};
+use crate::mode::rc::reconciler::run_reconcile;
+use clap::Parser;
+use serde::Serialize;
+use std::iter::{Chain, Cloned, Once};
+use std::slice::Iter;
+
+#[derive(Debug, Parser, Serialize)]
+#[command(override_usage = "bsdr rc [OPTIONS]")]
+pub struct RCFlgs {
+    #[command(flatten)]
+    pub common: CommonFlgs,
+    #[arg(long = "vdr", help = "Reconcile only the given VDR ID (all VDRs if omitted).")]
+    pub vdr: Option<u32>,
+    #[arg(long = "fix", default_value_t = false, help = "Repair discrepancies with recomputed values (per VDR in a transaction).")]
+    pub fix: bool,
+}
+
+impl HasCommonFlgs for RCFlgs {
+    fn common_flgs(&self) -> &CommonFlgs {
+        &self.common
+    }
+}
+
+pub async fn main_of_rc(args: Chain<Once<String>, Cloned<Iter<'_, String>>>) {
+    // ==============================
+    // 初期化
+    // ==============================
+    let (flgs, env) = init::<RCFlgs>(args).expect("Failed to init rc mode.");
+
+    // ==============================
+    // フラグの出力
+    // ==============================
+    let flgs_json = serde_json::to_string(&flgs).expect("Failed to serialize flgs to json.");
+    log::debug!("RC-FLAGS: {}", flgs_json);
+
+    // ==============================
+    // DB接続
+    // ==============================
+    let db_result = get_db(&env, &flgs.common.log_level).await;
+    let db = match db_result {
+        Ok(db) => { log::debug!("DB created successfully."); db }
+        Err(e) => { eprintln!("Failed to create DB: {}", e); std::process::exit(1); }
+    };
+    let rw_conn = db.get_rw().expect("Failed to get RW connection for reconciliation.");
+
+    // ==============================
+    // 整合性チェック（と修復）の実行
+    // 未修復の不一致、または失敗したテナントが残った場合は終了コード 1 で終了する
+    // ==============================
+    log::info!("Running reconciliation...");
+    match run_reconcile(rw_conn, flgs.vdr, flgs.fix).await {
+        Ok(summary) if summary.has_unresolved() => {
+            log::warn!("Reconciliation completed with unresolved discrepancies.");
+            std::process::exit(1);
+        }
+        Ok(_) => log::info!("Reconciliation completed."),
+        Err(e) => {
+            log::error!("Reconciliation failed: {:#}", e);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod main_of_rc;
+pub mod reconciler;
+pub use main_of_rc::main_of_rc;
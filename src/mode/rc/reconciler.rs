@@ -0,0 +1,306 @@
+use std::collections::HashMap;
+use anyhow::Context;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use sea_orm::{ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait};
+use sea_orm::sea_query::Expr;
+use crate::entities::{jobs, payouts, point_adjustments, points, pools, usr_badges, usrs, works};
+use crate::enums::pointadjuststatus::PointAdjustStatus;
+use crate::mode::rt::rtutils::pool_ledger_for_rt::load_pool_ledger_for_rt;
+
+
+/// 保存されている非正規化カウンタと、元テーブルから再計算した値の不一致
+#[derive(Debug, Clone)]
+pub struct Discrepancy {
+    /// usrs / pools / jobs
+    pub table: &'static str,
+    pub id: u32,
+    pub column: &'static str,
+    pub stored: i64,
+    pub computed: i64,
+}
+
+impl Discrepancy {
+    /// 再計算した値がカラム（unsigned）に収まる場合のみ修復できる
+    pub fn is_fixable(&self) -> bool {
+        (0..=u32::MAX as i64).contains(&self.computed)
+    }
+}
+
+/// 全体の実行結果
+#[derive(Debug, Default)]
+pub struct ReconcileSummary {
+    pub tenants: usize,
+    pub discrepancies: usize,
+    pub fixed: usize,
+    pub failed: usize,
+}
+
+impl ReconcileSummary {
+    /// 修復されずに残った不一致、または失敗したテナントがあるか
+    pub fn has_unresolved(&self) -> bool {
+        self.discrepancies > self.fixed || self.failed > 0
+    }
+}
+
+/// VDR（テナント）ごとに非正規化カウンタを再計算し、不一致を報告する
+/// fix が true の場合は、テナントごとに 1 トランザクションで関連する行をロックした上で再計算し、修復する
+pub async fn run_reconcile(conn: &DatabaseConnection, vdr_id: Option<u32>, fix: bool) -> anyhow::Result<ReconcileSummary> {
+    let mut query = usrs::Entity::find()
+        .filter(usrs::Column::ApxId.is_not_null())
+        .filter(usrs::Column::VdrId.is_null());
+    if let Some(vdr_id) = vdr_id {
+        query = query.filter(usrs::Column::Id.eq(vdr_id));
+    }
+    let vdrs = query
+        .order_by_asc(usrs::Column::Id)
+        .all(conn)
+        .await
+        .context("Failed to fetch VDRs")?;
+    log::info!("<Reconciler> {} VDR(s) to reconcile. fix: {}", vdrs.len(), fix);
+    let mut summary = ReconcileSummary::default();
+    for vdr in vdrs {
+        let apx_id = vdr.apx_id.unwrap_or(0);
+        let vdr_id = vdr.id as u32;
+        summary.tenants += 1;
+        let res = if fix {
+            reconcile_and_fix_vdr(conn, apx_id, vdr_id).await
+        } else {
+            collect_discrepancies(conn, apx_id, vdr_id, false).await.map(|d| (d, 0))
+        };
+        match res {
+            Ok((discrepancies, fixed)) => {
+                for d in discrepancies.iter() {
+                    let state = match (fix, d.is_fixable()) {
+                        (false, _) => "found",
+                        (true, true) => "fixed",
+                        (true, false) => "unfixable",
+                    };
+                    log::warn!("<Reconciler> apx: {}, vdr: {}, {}.{} (id: {}): stored {}, computed {}, result: {}", apx_id, vdr_id, d.table, d.column, d.id, d.stored, d.computed, state);
+                }
+                log::info!("<Reconciler> apx: {}, vdr: {}, discrepancies: {}, fixed: {}", apx_id, vdr_id, discrepancies.len(), fixed);
+                summary.discrepancies += discrepancies.len();
+                summary.fixed += fixed;
+            }
+            Err(e) => {
+                summary.failed += 1;
+                log::error!("<Reconciler> apx: {}, vdr: {}, result: failed, reason: {:#}", apx_id, vdr_id, e);
+            }
+        }
+    }
+    log::info!("<Reconciler> tenants: {}, discrepancies: {}, fixed: {}, failed: {}", summary.tenants, summary.discrepancies, summary.fixed, summary.failed);
+    Ok(summary)
+}
+
+/// 1 テナント分の再計算と修復を 1 トランザクションで行う
+/// 修復中に分配・授与などが割り込まないよう、pools → jobs → usrs の順（既存の処理と同じ順）で行ロックしてから再計算する
+async fn reconcile_and_fix_vdr(conn: &DatabaseConnection, apx_id: u32, vdr_id: u32) -> anyhow::Result<(Vec<Discrepancy>, usize)> {
+    let tx = conn.begin().await.context("Failed to begin transaction")?;
+    let discrepancies = collect_discrepancies(&tx, apx_id, vdr_id, true).await?;
+    let mut fixed = 0;
+    for d in discrepancies.iter().filter(|d| d.is_fixable()) {
+        apply_fix(&tx, d).await?;
+        fixed += 1;
+    }
+    tx.commit().await.context("Failed to commit fixes")?;
+    Ok((discrepancies, fixed))
+}
+
+/// 不一致 1 件を再計算した値で上書きする
+async fn apply_fix<C: ConnectionTrait>(tx: &C, d: &Discrepancy) -> anyhow::Result<()> {
+    let value = Expr::value(d.computed as u32);
+    match d.table {
+        "usrs" => {
+            let col = match d.column {
+                "points" => usrs::Column::Points,
+                "sum_p" => usrs::Column::SumP,
+                "sum_c" => usrs::Column::SumC,
+                "badged" => usrs::Column::Badged,
+                "total_badged" => usrs::Column::TotalBadged,
+                "total_badges" => usrs::Column::TotalBadges,
+                c => anyhow::bail!("Unknown usrs column: {}", c),
+            };
+            usrs::Entity::update_many().col_expr(col, value).filter(usrs::Column::Id.eq(d.id)).exec(tx).await
+                .with_context(|| format!("Failed to fix usrs.{} (id: {})", d.column, d.id))?;
+        }
+        "pools" => {
+            let col = match d.column {
+                "remain" => pools::Column::Remain,
+                "total_in" => pools::Column::TotalIn,
+                "total_out" => pools::Column::TotalOut,
+                c => anyhow::bail!("Unknown pools column: {}", c),
+            };
+            pools::Entity::update_many().col_expr(col, value).filter(pools::Column::Id.eq(d.id)).exec(tx).await
+                .with_context(|| format!("Failed to fix pools.{} (id: {})", d.column, d.id))?;
+        }
+        "jobs" => {
+            jobs::Entity::update_many().col_expr(jobs::Column::Filled, value).filter(jobs::Column::Id.eq(d.id)).exec(tx).await
+                .with_context(|| format!("Failed to fix jobs.filled (id: {})", d.id))?;
+        }
+        t => anyhow::bail!("Unknown table: {}", t),
+    }
+    Ok(())
+}
+
+/// 1 テナント分の非正規化カウンタを元テーブルから再計算し、保存されている値と比較する
+/// - usrs.points: points（delta, to）- payouts.points + point_adjustments.delta（承認済みのみ）
+/// - usrs.sum_p / sum_c: payouts.points / payouts.amount の累計
+/// - usrs.badged / total_badged: 取り消されていない usr_badges の受領数（to） / 授与数（from）。有効期限を過ぎた授与も数える
+/// - usrs.total_badges（VDR）: VDR 内の取り消されていない usr_badges の総数
+/// - pools.remain / total_in / total_out: payments（返金を含む）と flushes から再計算
+/// - jobs.filled: 求人に紐づく works の数
+async fn collect_discrepancies<C: ConnectionTrait>(conn: &C, apx_id: u32, vdr_id: u32, lock: bool) -> anyhow::Result<Vec<Discrepancy>> {
+    // --------------------------------
+    // 1. 保存されている値の取得（lock が true の場合は行ロック）
+    // --------------------------------
+    let mut pool_query = pools::Entity::find()
+        .filter(pools::Column::ApxId.eq(apx_id))
+        .filter(pools::Column::VdrId.eq(vdr_id));
+    let mut jobs_query = jobs::Entity::find()
+        .filter(jobs::Column::ApxId.eq(apx_id))
+        .filter(jobs::Column::VdrId.eq(vdr_id))
+        .order_by_asc(jobs::Column::Id);
+    let mut usrs_query = usrs::Entity::find()
+        .filter(usrs::Column::ApxId.eq(apx_id))
+        .filter(Condition::any().add(usrs::Column::VdrId.eq(vdr_id)).add(usrs::Column::Id.eq(vdr_id)))
+        .order_by_asc(usrs::Column::Id);
+    if lock {
+        pool_query = pool_query.lock_exclusive();
+        jobs_query = jobs_query.lock_exclusive();
+        usrs_query = usrs_query.lock_exclusive();
+    }
+    let pool = pool_query.one(conn).await.context("Failed to fetch pool")?;
+    let jobs = jobs_query.all(conn).await.context("Failed to fetch jobs")?;
+    let usrs = usrs_query.all(conn).await.context("Failed to fetch usrs")?;
+    // --------------------------------
+    // 2. 元テーブルからの再計算
+    // --------------------------------
+    let awarded: Vec<(u32, Option<Decimal>)> = points::Entity::find()
+        .select_only()
+        .column(points::Column::To)
+        .column_as(points::Column::Delta.sum(), "delta")
+        .filter(points::Column::ApxId.eq(apx_id))
+        .filter(points::Column::VdrId.eq(vdr_id))
+        .group_by(points::Column::To)
+        .into_tuple()
+        .all(conn)
+        .await
+        .context("Failed to sum points")?;
+    let paid: Vec<(u32, Option<Decimal>, Option<Decimal>)> = payouts::Entity::find()
+        .select_only()
+        .column(payouts::Column::UsrId)
+        .column_as(payouts::Column::Points.sum(), "points")
+        .column_as(payouts::Column::Amount.sum(), "amount")
+        .filter(payouts::Column::ApxId.eq(apx_id))
+        .filter(payouts::Column::VdrId.eq(vdr_id))
+        .group_by(payouts::Column::UsrId)
+        .into_tuple()
+        .all(conn)
+        .await
+        .context("Failed to sum payouts")?;
+    let adjusted: Vec<(u32, Option<Decimal>)> = point_adjustments::Entity::find()
+        .select_only()
+        .column(point_adjustments::Column::UsrId)
+        .column_as(point_adjustments::Column::Delta.sum(), "delta")
+        .filter(point_adjustments::Column::ApxId.eq(apx_id))
+        .filter(point_adjustments::Column::VdrId.eq(vdr_id))
+        .filter(point_adjustments::Column::Status.eq(PointAdjustStatus::Approved as u8))
+        .group_by(point_adjustments::Column::UsrId)
+        .into_tuple()
+        .all(conn)
+        .await
+        .context("Failed to sum point_adjustments")?;
+    let received: Vec<(u32, i64)> = usr_badges::Entity::find()
+        .select_only()
+        .column(usr_badges::Column::To)
+        .column_as(usr_badges::Column::Id.count(), "cnt")
+        .filter(usr_badges::Column::ApxId.eq(apx_id))
+        .filter(usr_badges::Column::VdrId.eq(vdr_id))
+        .filter(usr_badges::Column::RevokedAt.is_null())
+        .group_by(usr_badges::Column::To)
+        .into_tuple()
+        .all(conn)
+        .await
+        .context("Failed to count received usr_badges")?;
+    let given: Vec<(u32, i64)> = usr_badges::Entity::find()
+        .select_only()
+        .column(usr_badges::Column::From)
+        .column_as(usr_badges::Column::Id.count(), "cnt")
+        .filter(usr_badges::Column::ApxId.eq(apx_id))
+        .filter(usr_badges::Column::VdrId.eq(vdr_id))
+        .filter(usr_badges::Column::RevokedAt.is_null())
+        .group_by(usr_badges::Column::From)
+        .into_tuple()
+        .all(conn)
+        .await
+        .context("Failed to count given usr_badges")?;
+    let hired: Vec<(u32, i64)> = works::Entity::find()
+        .select_only()
+        .column(works::Column::JobId)
+        .column_as(works::Column::Id.count(), "cnt")
+        .filter(works::Column::ApxId.eq(apx_id))
+        .filter(works::Column::VdrId.eq(vdr_id))
+        .group_by(works::Column::JobId)
+        .into_tuple()
+        .all(conn)
+        .await
+        .context("Failed to count works")?;
+    let mut points_of: HashMap<u32, i64> = HashMap::new();
+    let mut sum_p_of: HashMap<u32, i64> = HashMap::new();
+    let mut sum_c_of: HashMap<u32, i64> = HashMap::new();
+    for (to, delta) in awarded {
+        *points_of.entry(to).or_default() += to_i64(delta);
+    }
+    for (usr_id, p, c) in paid {
+        *points_of.entry(usr_id).or_default() -= to_i64(p);
+        sum_p_of.insert(usr_id, to_i64(p));
+        sum_c_of.insert(usr_id, to_i64(c));
+    }
+    for (usr_id, delta) in adjusted {
+        *points_of.entry(usr_id).or_default() += to_i64(delta);
+    }
+    let badged_of: HashMap<u32, i64> = received.into_iter().collect();
+    let total_badged_of: HashMap<u32, i64> = given.into_iter().collect();
+    let filled_of: HashMap<u32, i64> = hired.into_iter().collect();
+    // --------------------------------
+    // 3. 比較
+    // --------------------------------
+    let mut res = Vec::new();
+    let mut check = |table: &'static str, id: u32, column: &'static str, stored: i64, computed: i64| {
+        if stored != computed {
+            res.push(Discrepancy { table, id, column, stored, computed });
+        }
+    };
+    for u in usrs.iter() {
+        let id = u.id as u32;
+        if id == vdr_id {
+            let total_badges = badged_of.values().sum();
+            check("usrs", id, "total_badges", u.total_badges as i64, total_badges);
+            continue;
+        }
+        check("usrs", id, "points", u.points as i64, points_of.get(&id).copied().unwrap_or(0));
+        check("usrs", id, "sum_p", u.sum_p as i64, sum_p_of.get(&id).copied().unwrap_or(0));
+        check("usrs", id, "sum_c", u.sum_c as i64, sum_c_of.get(&id).copied().unwrap_or(0));
+        check("usrs", id, "badged", u.badged as i64, badged_of.get(&id).copied().unwrap_or(0));
+        check("usrs", id, "total_badged", u.total_badged as i64, total_badged_of.get(&id).copied().unwrap_or(0));
+    }
+    for j in jobs.iter() {
+        let id = j.id as u32;
+        check("jobs", id, "filled", j.filled as i64, filled_of.get(&id).copied().unwrap_or(0));
+    }
+    match pool {
+        Some(pool) => {
+            let (_, computed) = load_pool_ledger_for_rt(conn, apx_id, vdr_id).await.map_err(|e| anyhow::anyhow!("Failed to load pool ledger: {}", e))?;
+            let id = pool.id as u32;
+            check("pools", id, "remain", pool.remain as i64, computed.remain);
+            check("pools", id, "total_in", pool.total_in as i64, computed.total_in);
+            check("pools", id, "total_out", pool.total_out as i64, computed.total_out);
+        }
+        None => log::warn!("<Reconciler> apx: {}, vdr: {}, pool not found. Skipped pool counters.", apx_id, vdr_id),
+    }
+    Ok(res)
+}
+
+fn to_i64(v: Option<Decimal>) -> i64 {
+    v.and_then(|d| d.to_i64()).unwrap_or(0)
+}
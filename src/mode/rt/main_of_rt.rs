@@ -4,6 +4,8 @@ use crate::utils::env::get_env_or;
 use crate::utils::init::{CommonFlgs, HasCommonFlgs, init};
 use crate::utils::s3client;
 use crate::mode::rt::req_map;
+use crate::mode::rt::rtutils::config_for_rt::RtConfig;
+use rust_decimal::Decimal;
 
 use clap::Parser;
 use serde::Serialize;
@@ -51,6 +53,8 @@ pub async fn main_of_rt(args: Chain<Once<String>, Cloned<Iter<'_, String>>>) {
     let cors_on_rt = get_env_or("CORS_ON_RT", false);
     let rt_skey = get_env_or("RT_SKEY", DEFAULT_SKEY.to_string());
     let rt_crypto_key = get_env_or("RT_CRYPTO_KEY", DEFAULT_CRYPTO_KEY.to_string());
+    let rt_clock_tolerance_min = get_env_or("RT_CLOCK_TOLERANCE_MIN", 30);
+    let rt_payment_fee_rate = get_env_or("RT_PAYMENT_FEE_RATE", Decimal::ZERO);
     let s3_use_local = get_env_or("S3_USE_LOCAL", false);
     let s3_local_dir = get_env_or("S3_LOCAL_DIR", "dummy".to_string());
     let s3_down_dir = get_env_or("S3_DOWN_DIR", "dummy".to_string());
@@ -63,6 +67,8 @@ pub async fn main_of_rt(args: Chain<Once<String>, Cloned<Iter<'_, String>>>) {
     log::debug!("CORS_ON_RT: {}", cors_on_rt);
     log::debug!("RT_SKEY: {}", rt_skey);
     log::debug!("RT_CRYPTO_KEY: {}", rt_crypto_key);
+    log::debug!("RT_CLOCK_TOLERANCE_MIN: {}", rt_clock_tolerance_min);
+    log::debug!("RT_PAYMENT_FEE_RATE: {}", rt_payment_fee_rate);
     log::debug!("S3_USE_LOCAL: {}", s3_use_local);
     log::debug!("S3_LOCAL_DIR: {}", s3_local_dir);
     log::debug!("S3_DOWN_DIR: {}", s3_down_dir);
@@ -76,10 +82,10 @@ pub async fn main_of_rt(args: Chain<Once<String>, Cloned<Iter<'_, String>>>) {
     // s3clientの初期化
     // ==============================
     let s3c = s3client::S3Client::new(&s3_access_key, &s3_secret_access_key, &s3_region, &s3_bucket, &s3_local_dir, &s3_down_dir, s3_use_local).await;
-    match s3c {
-        Ok(_) => { log::debug!("S3Client created successfully."); }
+    let s3c = match s3c {
+        Ok(s3c) => { log::debug!("S3Client created successfully."); s3c }
         Err(e) => { eprintln!("Failed to create s3client: {}", e); std::process::exit(1); }
-    }
+    };
 
     // ==============================
     // DB接続
@@ -93,7 +99,11 @@ pub async fn main_of_rt(args: Chain<Once<String>, Cloned<Iter<'_, String>>>) {
     // ==============================
     // Axum リクエストマッピングと起動
     // ==============================
-    let router = req_map::map_request(cors_on_rt, db, &rt_skey, &rt_crypto_key);
+    let rt_config = RtConfig {
+        clock_tolerance_min: rt_clock_tolerance_min,
+        payment_fee_rate: rt_payment_fee_rate,
+    };
+    let router = req_map::map_request(cors_on_rt, db, s3c, &rt_skey, &rt_crypto_key, rt_config);
     log::debug!("Starting RT server on port {}...", rt_port);
     let listener = tokio::net::TcpListener::bind(format!("0.0.0.0:{rt_port}")).await.expect("Failed to bind listener.");
     axum::serve(listener, router).await.expect("Failed to serve.");
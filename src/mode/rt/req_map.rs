@@ -1,6 +1,7 @@
 use axum::{Router, Extension};
 use crate::utils::jwt::JwtConfig;
-use crate::{config::VERSION, utils::cors::cors_layer, utils::db::DbPools};
+use crate::mode::rt::rtutils::config_for_rt::RtConfig;
+use crate::{config::VERSION, utils::cors::cors_layer, utils::db::DbPools, utils::s3client::S3Client};
 use std::sync::Arc;
 use utoipa::{OpenApi};
 use utoipa_swagger_ui::SwaggerUi;
@@ -10,6 +11,21 @@ use utoipa::openapi::security::{SecurityScheme, HttpBuilder, HttpAuthScheme};
 use crate::mode::rt::rthandler::usrs_handler::*;
 use crate::mode::rt::rthandler::bds_handler::*;
 use crate::mode::rt::rthandler::cryptos_handler::*;
+use crate::mode::rt::rthandler::jobs_handler::*;
+use crate::mode::rt::rthandler::matches_handler::*;
+use crate::mode::rt::rthandler::works_handler::*;
+use crate::mode::rt::rthandler::belongs_handler::*;
+use crate::mode::rt::rthandler::badges_handler::*;
+use crate::mode::rt::rthandler::usr_badges_handler::*;
+use crate::mode::rt::rthandler::points_handler::*;
+use crate::mode::rt::rthandler::payments_handler::*;
+use crate::mode::rt::rthandler::flushes_handler::*;
+use crate::mode::rt::rthandler::bank_accounts_handler::*;
+use crate::mode::rt::rthandler::withdrawals_handler::*;
+use crate::mode::rt::rthandler::journals_handler::*;
+use crate::mode::rt::rthandler::pools_handler::*;
+use crate::mode::rt::rthandler::point_adjustments_handler::*;
+use crate::mode::rt::rthandler::collusion_handler::*;
 
 // ==============================
 // セキュリティアドオン作成
@@ -61,12 +77,79 @@ fn app_routes() -> OpenApiRouter { OpenApiRouter::new()
     .routes(routes!(decrypt_handler))
     .routes(routes!(create_vdr_token_handler))
     .routes(routes!(get_vdr_token_handler))
+    .routes(routes!(search_jobs))
+    .routes(routes!(get_job))
+    .routes(routes!(create_job))
+    .routes(routes!(update_job))
+    .routes(routes!(delete_job))
+    .routes(routes!(search_matches))
+    .routes(routes!(get_match))
+    .routes(routes!(create_match))
+    .routes(routes!(accept_match))
+    .routes(routes!(decline_match))
+    .routes(routes!(confirm_match))
+    .routes(routes!(update_match_status))
+    .routes(routes!(search_match_candidates))
+    .routes(routes!(search_works))
+    .routes(routes!(get_work))
+    .routes(routes!(clock_in_work))
+    .routes(routes!(clock_out_work))
+    .routes(routes!(correct_work))
+    .routes(routes!(search_belongs))
+    .routes(routes!(get_belong))
+    .routes(routes!(create_belong))
+    .routes(routes!(close_belong))
+    .routes(routes!(search_badges))
+    .routes(routes!(get_badge))
+    .routes(routes!(update_badge))
+    .routes(routes!(create_badge))
+    .routes(routes!(retire_badge))
+    .routes(routes!(search_usr_badges))
+    .routes(routes!(get_usr_badge))
+    .routes(routes!(award_usr_badge))
+    .routes(routes!(revoke_usr_badge))
+    .routes(routes!(search_points))
+    .routes(routes!(get_point))
+    .routes(routes!(get_expiring_points))
+    .routes(routes!(search_payments))
+    .routes(routes!(get_payment))
+    .routes(routes!(create_payment))
+    .routes(routes!(refund_payment))
+    .routes(routes!(search_flushes))
+    .routes(routes!(get_flush))
+    .routes(routes!(create_flush))
+    .routes(routes!(preview_flush))
+    .routes(routes!(search_payouts))
+    .routes(routes!(summarize_withholding))
+    .routes(routes!(export_flush_zengin))
+    .routes(routes!(search_bank_accounts))
+    .routes(routes!(get_bank_account))
+    .routes(routes!(create_bank_account))
+    .routes(routes!(update_bank_account))
+    .routes(routes!(search_withdrawals))
+    .routes(routes!(get_withdrawal))
+    .routes(routes!(create_withdrawal))
+    .routes(routes!(approve_withdrawal))
+    .routes(routes!(reject_withdrawal))
+    .routes(routes!(pay_withdrawal))
+    .routes(routes!(export_journal))
+    .routes(routes!(export_journal_csv))
+    .routes(routes!(search_pool_ledger))
+    .routes(routes!(check_pool))
+    .routes(routes!(search_point_adjustments))
+    .routes(routes!(get_point_adjustment))
+    .routes(routes!(create_point_adjustment))
+    .routes(routes!(approve_point_adjustment))
+    .routes(routes!(reject_point_adjustment))
+    .routes(routes!(get_collusion_report))
+    .routes(routes!(hold_collusion_awards))
+    .routes(routes!(release_collusion_hold))
 }
 
 // ==============================
 // リクエストマッピング
 // ==============================
-pub fn map_request(cors: bool, db: DbPools, rt_skey: &str, rt_crypto_key: &str) -> Router {
+pub fn map_request(cors: bool, db: DbPools, s3c: S3Client, rt_skey: &str, rt_crypto_key: &str, rt_config: RtConfig) -> Router {
     log::debug!("Mapping requests.");
     let (router, api) = OpenApiRouter::with_openapi(ApiDoc::openapi())
         .nest("/v1", app_routes())
@@ -75,6 +158,8 @@ pub fn map_request(cors: bool, db: DbPools, rt_skey: &str, rt_crypto_key: &str)
         .merge(router)
         .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", api))
         .layer(Extension(Arc::new(db)))
+        .layer(Extension(Arc::new(s3c)))
+        .layer(Extension(Arc::new(rt_config)))
         .layer(Extension(Arc::new(JwtConfig {
             skey: rt_skey.to_string(),
             crypto_key: rt_crypto_key.to_string(),
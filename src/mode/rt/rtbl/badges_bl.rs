@@ -0,0 +1,279 @@
+use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, ColumnTrait, QuerySelect, QueryOrder, Select, ActiveModelTrait, IntoActiveModel, Set, PaginatorTrait};
+use crate::entities::badges;
+use crate::utils::jwt::{JwtUsr, JwtIDs, JwtRole};
+use crate::mode::rt::rtreq::badges_req::{SearchBadgesReq, CreateBadgeReq, UpdateBadgeReq};
+use crate::mode::rt::rtres::badges_res::{SearchBadgesRes, SearchBadgesResItem, GetBadgeRes, CreateBadgeRes, UpdateBadgeRes, RetireBadgeRes};
+use crate::mode::rt::rtres::errs_res::ApiError;
+use crate::mode::rt::rtutils::usrs_for_rt::find_usr_by_type_for_rt;
+use axum::http::StatusCode;
+use crate::mode::rt::rterr::rterr;
+use chrono::Local;
+use crate::enums::usrtype::UsrType;
+
+// ============================================================
+// Private Helper for Search and Get
+// ============================================================
+/// 権限に基づいた共通のクエリベースを作成する
+/// バッジ定義は VDR 内の全 USR（法人・個人）が閲覧できる
+async fn find_badges_base(
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+) -> Result<Select<badges::Entity>, ApiError> {
+    let query = badges::Entity::find();
+    match ju.role() {
+        JwtRole::BD => {
+            log::debug!("<BadgeBl> find_badges_base: BD role. No filtering.");
+            Ok(query)
+        }
+        JwtRole::APX => {
+            log::debug!("<BadgeBl> find_badges_base: APX role. Filter apx_id: {}", ids.apx_id);
+            Ok(query.filter(badges::Column::ApxId.eq(ids.apx_id)))
+        }
+        JwtRole::VDR => {
+            log::debug!("<BadgeBl> find_badges_base: VDR role. Filter apx_id: {}, vdr_id: {}", ids.apx_id, ids.vdr_id);
+            Ok(query
+                .filter(badges::Column::ApxId.eq(ids.apx_id))
+                .filter(badges::Column::VdrId.eq(ids.vdr_id)))
+        }
+        JwtRole::USR => {
+            log::debug!("<BadgeBl> find_badges_base: USR role. Filter apx_id: {}, vdr_id: {}", ids.apx_id, ids.vdr_id);
+            Ok(query
+                .filter(badges::Column::ApxId.eq(ids.apx_id))
+                .filter(badges::Column::VdrId.eq(ids.vdr_id)))
+        }
+    }
+}
+
+/// 操作者が法人 USR であることを確認する（個人 USR は 403）
+async fn ensure_corp(
+    conn: &DatabaseConnection,
+    ids: &JwtIDs,
+) -> Result<(), ApiError> {
+    find_usr_by_type_for_rt(conn, ids.apx_id, ids.vdr_id, ids.usr_id, UsrType::Corp).await.map_err(|e| {
+        if e.status == StatusCode::NOT_FOUND.as_u16() {
+            ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Only corporate USR can manage badges.")
+        } else {
+            e
+        }
+    })?;
+    Ok(())
+}
+
+/// 法人内で short_name が重複していないことを確認する（廃止済みのバッジも対象）
+async fn ensure_unique_short_name(
+    conn: &DatabaseConnection,
+    ids: &JwtIDs,
+    short_name: &str,
+    exclude_id: Option<i32>,
+) -> Result<(), ApiError> {
+    let mut query = badges::Entity::find()
+        .filter(badges::Column::ApxId.eq(ids.apx_id))
+        .filter(badges::Column::VdrId.eq(ids.vdr_id))
+        .filter(badges::Column::CorpId.eq(ids.usr_id))
+        .filter(badges::Column::ShortName.eq(short_name));
+    if let Some(id) = exclude_id {
+        query = query.filter(badges::Column::Id.ne(id));
+    }
+    let count = query
+        .count(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Count badges error: {}", e)))?;
+    if count > 0 {
+        log::debug!("<BadgeBl> ensure_unique_short_name: Duplicate short_name: {}", short_name);
+        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "short_name already exists."));
+    }
+    Ok(())
+}
+
+/// 自身（法人）が発行したバッジを取得する
+async fn find_own_badge(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    badge_id: u32,
+) -> Result<badges::Model, ApiError> {
+    find_badges_base(ju, ids).await?
+        .filter(badges::Column::Id.eq(badge_id))
+        .filter(badges::Column::CorpId.eq(ids.usr_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch badge error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Badge not found."))
+}
+
+// ============================================================
+// Search
+// ============================================================
+pub async fn search_badges(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: SearchBadgesReq,
+) -> Result<SearchBadgesRes, ApiError> {
+    // --------------------------------
+    // 1. クエリの基本形を取得
+    // --------------------------------
+    log::debug!("<BadgeBl> search_badges: Constructing base query.");
+    let mut query = find_badges_base(ju, ids).await?;
+    // --------------------------------
+    // 2. 検索条件
+    // --------------------------------
+    if !req.name.is_empty() {
+        log::debug!("<BadgeBl> search_badges: Filter by name: {}", req.name);
+        query = query.filter(badges::Column::Name.contains(&req.name));
+    }
+    if let Some(corp_id) = req.corp_id {
+        log::debug!("<BadgeBl> search_badges: Filter by corp_id: {}", corp_id);
+        query = query.filter(badges::Column::CorpId.eq(corp_id));
+    }
+    if !req.include_retired {
+        log::debug!("<BadgeBl> search_badges: Exclude retired badges.");
+        query = query.filter(badges::Column::RetiredAt.is_null());
+    }
+    // --------------------------------
+    // 3. データの取得
+    // --------------------------------
+    log::debug!("<BadgeBl> search_badges: Fetching records. limit: {}, offset: {}", req.limit, req.offset);
+    let models = query
+        .order_by_desc(badges::Column::Id)
+        .offset(req.offset as u64)
+        .limit(req.limit as u64)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Search query error: {}", e)))?;
+    log::debug!("<BadgeBl> search_badges: Found {} records.", models.len());
+    // --------------------------------
+    // 4. 最終レスポンス
+    // --------------------------------
+    let badges = models.into_iter().map(SearchBadgesResItem::from).collect();
+    Ok(SearchBadgesRes { badges })
+}
+
+// ============================================================
+// Get
+// ============================================================
+pub async fn get_badge(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    badge_id: u32,
+) -> Result<GetBadgeRes, ApiError> {
+    log::debug!("<BadgeBl> get_badge: Fetching badge: {}", badge_id);
+    let model = find_badges_base(ju, ids).await?
+        .filter(badges::Column::Id.eq(badge_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch badge error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Badge not found."))?;
+    Ok(GetBadgeRes::from(model))
+}
+
+// ============================================================
+// Create
+// ============================================================
+pub async fn create_badge(
+    conn: &DatabaseConnection,
+    ids: &JwtIDs,
+    req: CreateBadgeReq,
+) -> Result<CreateBadgeRes, ApiError> {
+    // --------------------------------
+    // 1. 法人 USR であることの確認
+    // --------------------------------
+    ensure_corp(conn, ids).await?;
+    // --------------------------------
+    // 2. short_name の重複確認
+    // --------------------------------
+    ensure_unique_short_name(conn, ids, &req.short_name, None).await?;
+    // --------------------------------
+    // 3. 保存
+    // --------------------------------
+    log::debug!("<BadgeBl> create_badge: Inserting badge. corp: {}, short_name: {}", ids.usr_id, req.short_name);
+    let active = badges::ActiveModel {
+        corp_id: Set(ids.usr_id),
+        name: Set(req.name),
+        short_name: Set(req.short_name),
+        description: Set(req.description),
+        valid_months: Set(req.valid_months.unwrap_or(0)),
+        apx_id: Set(ids.apx_id),
+        vdr_id: Set(ids.vdr_id),
+        retired_at: Set(None),
+        ..Default::default()
+    };
+    let res = active.insert(conn).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Insert badge error: {}", e)))?;
+    log::debug!("<BadgeBl> create_badge: Success. ID: {}", res.id);
+    Ok(CreateBadgeRes { id: res.id as u32 })
+}
+
+// ============================================================
+// Update
+// ============================================================
+pub async fn update_badge(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    badge_id: u32,
+    req: UpdateBadgeReq,
+) -> Result<UpdateBadgeRes, ApiError> {
+    // --------------------------------
+    // 1. 対象の取得と状態確認
+    // --------------------------------
+    ensure_corp(conn, ids).await?;
+    log::debug!("<BadgeBl> update_badge: Fetching target badge: {}", badge_id);
+    let model = find_own_badge(conn, ju, ids, badge_id).await?;
+    if model.retired_at.is_some() {
+        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Badge is retired."));
+    }
+    // --------------------------------
+    // 2. short_name の重複確認
+    // --------------------------------
+    if let Some(short_name) = &req.short_name && *short_name != model.short_name {
+        ensure_unique_short_name(conn, ids, short_name, Some(model.id)).await?;
+    }
+    // --------------------------------
+    // 3. 各フィールドの更新
+    // --------------------------------
+    let mut active: badges::ActiveModel = model.into_active_model();
+    if let Some(v) = req.name { active.name = Set(v); }
+    if let Some(v) = req.short_name { active.short_name = Set(v); }
+    if let Some(v) = req.description { active.description = Set(v); }
+    if let Some(v) = req.valid_months { active.valid_months = Set(v); }
+    // --------------------------------
+    // 4. 保存
+    // --------------------------------
+    log::debug!("<BadgeBl> update_badge: Saving changes to DB.");
+    active.update(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update badge error: {}", e)))?;
+    Ok(UpdateBadgeRes { id: badge_id })
+}
+
+// ============================================================
+// Retire
+// ============================================================
+pub async fn retire_badge(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    badge_id: u32,
+) -> Result<RetireBadgeRes, ApiError> {
+    // --------------------------------
+    // 1. 対象の取得と状態確認
+    // --------------------------------
+    ensure_corp(conn, ids).await?;
+    log::debug!("<BadgeBl> retire_badge: Fetching target badge: {}", badge_id);
+    let model = find_own_badge(conn, ju, ids, badge_id).await?;
+    if model.retired_at.is_some() {
+        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Badge is already retired."));
+    }
+    // --------------------------------
+    // 2. 保存（行は削除せず、授与履歴からの参照を維持する）
+    // --------------------------------
+    let now = Local::now().naive_local();
+    log::debug!("<BadgeBl> retire_badge: Retiring at: {}", now);
+    let mut active: badges::ActiveModel = model.into_active_model();
+    active.retired_at = Set(Some(now));
+    active.update(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update badge error: {}", e)))?;
+    Ok(RetireBadgeRes { id: badge_id })
+}
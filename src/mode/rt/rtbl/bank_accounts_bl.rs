@@ -0,0 +1,244 @@
+use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, ColumnTrait, QuerySelect, QueryOrder, Select, ActiveModelTrait, IntoActiveModel, Set};
+use crate::entities::bank_accounts;
+use crate::utils::crypto::encrypt;
+use crate::utils::jwt::{JwtUsr, JwtIDs, JwtRole};
+use crate::utils::zengin::to_zengin_kana;
+use crate::mode::rt::rtreq::bank_accounts_req::{SearchBankAccountsReq, CreateBankAccountReq, UpdateBankAccountReq};
+use crate::mode::rt::rtres::bank_accounts_res::{SearchBankAccountsRes, SearchBankAccountsResItem, GetBankAccountRes, CreateBankAccountRes, UpdateBankAccountRes};
+use crate::mode::rt::rtres::errs_res::ApiError;
+use crate::mode::rt::rtutils::usrs_for_rt::find_usr_by_type_for_rt;
+use axum::http::StatusCode;
+use crate::mode::rt::rterr::rterr;
+use crate::enums::usrtype::UsrType;
+
+// ============================================================
+// Private Helper for Search and Get
+// ============================================================
+/// 権限に基づいた共通のクエリベースを作成する
+/// USR は自身の口座のみ参照できる
+async fn find_bank_accounts_base(
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+) -> Result<Select<bank_accounts::Entity>, ApiError> {
+    let query = bank_accounts::Entity::find();
+    match ju.role() {
+        JwtRole::BD => {
+            log::debug!("<BankAccountBl> find_bank_accounts_base: BD role. No filtering.");
+            Ok(query)
+        }
+        JwtRole::APX => {
+            log::debug!("<BankAccountBl> find_bank_accounts_base: APX role. Filter apx_id: {}", ids.apx_id);
+            Ok(query.filter(bank_accounts::Column::ApxId.eq(ids.apx_id)))
+        }
+        JwtRole::VDR => {
+            log::debug!("<BankAccountBl> find_bank_accounts_base: VDR role. Filter apx_id: {}, vdr_id: {}", ids.apx_id, ids.vdr_id);
+            Ok(query
+                .filter(bank_accounts::Column::ApxId.eq(ids.apx_id))
+                .filter(bank_accounts::Column::VdrId.eq(ids.vdr_id)))
+        }
+        JwtRole::USR => {
+            log::debug!("<BankAccountBl> find_bank_accounts_base: USR role. Filter apx_id: {}, vdr_id: {}, usr_id: {}", ids.apx_id, ids.vdr_id, ids.usr_id);
+            Ok(query
+                .filter(bank_accounts::Column::ApxId.eq(ids.apx_id))
+                .filter(bank_accounts::Column::VdrId.eq(ids.vdr_id))
+                .filter(bank_accounts::Column::UsrId.eq(ids.usr_id)))
+        }
+    }
+}
+
+/// 名称を全銀協規定の半角カナへ変換し、変換後の桁数を検証する
+fn normalize_kana(field: &str, s: &str, max: usize) -> Result<String, ApiError> {
+    let kana = to_zengin_kana(s.trim())
+        .map_err(|e| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, format!("Invalid {}: {}", field, e)))?;
+    if kana.chars().count() > max {
+        return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, format!("{} is too long in half-width kana (max {}).", field, max)));
+    }
+    Ok(kana)
+}
+
+/// 預金種目を検証する（1:普通, 2:当座, 4:貯蓄）
+fn validate_account_type(t: u8) -> Result<u8, ApiError> {
+    match t {
+        1 | 2 | 4 => Ok(t),
+        _ => Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, format!("Invalid account_type: {}", t))),
+    }
+}
+
+fn encrypt_field(field: &str, s: &str, crypto_key: &str) -> Result<String, ApiError> {
+    encrypt(s, crypto_key)
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_UNEXPECTED, format!("Failed to encrypt {}: {}", field, e)))
+}
+
+fn last4(account_number: &str) -> String {
+    let n = account_number.len();
+    account_number[n.saturating_sub(4)..].to_string()
+}
+
+// ============================================================
+// Search
+// ============================================================
+pub async fn search_bank_accounts(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: SearchBankAccountsReq,
+) -> Result<SearchBankAccountsRes, ApiError> {
+    // --------------------------------
+    // 1. クエリの基本形を取得
+    // --------------------------------
+    log::debug!("<BankAccountBl> search_bank_accounts: Constructing base query.");
+    let mut query = find_bank_accounts_base(ju, ids).await?;
+    // --------------------------------
+    // 2. 検索条件（完全一致）
+    // --------------------------------
+    if let Some(usr_id) = req.usr_id {
+        log::debug!("<BankAccountBl> search_bank_accounts: Filter by usr_id: {}", usr_id);
+        query = query.filter(bank_accounts::Column::UsrId.eq(usr_id));
+    }
+    // --------------------------------
+    // 3. データの取得
+    // --------------------------------
+    log::debug!("<BankAccountBl> search_bank_accounts: Fetching records. limit: {}, offset: {}", req.limit, req.offset);
+    let models = query
+        .order_by_desc(bank_accounts::Column::Id)
+        .offset(req.offset as u64)
+        .limit(req.limit as u64)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Search query error: {}", e)))?;
+    log::debug!("<BankAccountBl> search_bank_accounts: Found {} records.", models.len());
+    // --------------------------------
+    // 4. 最終レスポンス
+    // --------------------------------
+    let bank_accounts = models.into_iter().map(SearchBankAccountsResItem::from).collect();
+    Ok(SearchBankAccountsRes { bank_accounts })
+}
+
+// ============================================================
+// Get
+// ============================================================
+pub async fn get_bank_account(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    bank_account_id: u32,
+) -> Result<GetBankAccountRes, ApiError> {
+    log::debug!("<BankAccountBl> get_bank_account: Fetching bank account: {}", bank_account_id);
+    let model = find_bank_accounts_base(ju, ids).await?
+        .filter(bank_accounts::Column::Id.eq(bank_account_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch bank account error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Bank account not found."))?;
+    Ok(GetBankAccountRes::from(model))
+}
+
+// ============================================================
+// Create
+// ============================================================
+pub async fn create_bank_account(
+    conn: &DatabaseConnection,
+    ids: &JwtIDs,
+    crypto_key: &str,
+    req: CreateBankAccountReq,
+) -> Result<CreateBankAccountRes, ApiError> {
+    // --------------------------------
+    // 1. 登録者が個人であることの確認
+    // --------------------------------
+    log::debug!("<BankAccountBl> create_bank_account: Checking owner: {}", ids.usr_id);
+    find_usr_by_type_for_rt(conn, ids.apx_id, ids.vdr_id, ids.usr_id, UsrType::Indi).await.map_err(|e| {
+        if e.status == StatusCode::NOT_FOUND.as_u16() {
+            ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Only personal USR can register bank accounts.")
+        } else {
+            e
+        }
+    })?;
+    // --------------------------------
+    // 2. 登録済みでないことの確認（1 人 1 口座）
+    // --------------------------------
+    let exists = bank_accounts::Entity::find()
+        .filter(bank_accounts::Column::ApxId.eq(ids.apx_id))
+        .filter(bank_accounts::Column::VdrId.eq(ids.vdr_id))
+        .filter(bank_accounts::Column::UsrId.eq(ids.usr_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch bank account error: {}", e)))?;
+    if exists.is_some() {
+        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Bank account already registered."));
+    }
+    // --------------------------------
+    // 3. 名称の半角カナ変換と口座番号・口座名義の暗号化
+    // --------------------------------
+    let bank_name = normalize_kana("bank_name", &req.bank_name, 15)?;
+    let branch_name = normalize_kana("branch_name", &req.branch_name, 15)?;
+    let holder = normalize_kana("holder", &req.holder, 30)?;
+    let account_type = validate_account_type(req.account_type)?;
+    let active = bank_accounts::ActiveModel {
+        usr_id: Set(ids.usr_id),
+        bank_code: Set(req.bank_code),
+        bank_name: Set(bank_name),
+        branch_code: Set(req.branch_code),
+        branch_name: Set(branch_name),
+        account_type: Set(account_type),
+        account_number: Set(encrypt_field("account_number", &req.account_number, crypto_key)?),
+        account_last4: Set(last4(&req.account_number)),
+        holder: Set(encrypt_field("holder", &holder, crypto_key)?),
+        apx_id: Set(ids.apx_id),
+        vdr_id: Set(ids.vdr_id),
+        ..Default::default()
+    };
+    // --------------------------------
+    // 4. 保存
+    // --------------------------------
+    let res = active.insert(conn).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Insert bank account error: {}", e)))?;
+    log::debug!("<BankAccountBl> create_bank_account: Success. ID: {}", res.id);
+    Ok(CreateBankAccountRes { id: res.id as u32 })
+}
+
+// ============================================================
+// Update
+// ============================================================
+pub async fn update_bank_account(
+    conn: &DatabaseConnection,
+    ids: &JwtIDs,
+    crypto_key: &str,
+    bank_account_id: u32,
+    req: UpdateBankAccountReq,
+) -> Result<UpdateBankAccountRes, ApiError> {
+    // --------------------------------
+    // 1. 自分の口座であることの確認
+    // --------------------------------
+    log::debug!("<BankAccountBl> update_bank_account: Fetching bank account: {}", bank_account_id);
+    let model = bank_accounts::Entity::find()
+        .filter(bank_accounts::Column::Id.eq(bank_account_id))
+        .filter(bank_accounts::Column::ApxId.eq(ids.apx_id))
+        .filter(bank_accounts::Column::VdrId.eq(ids.vdr_id))
+        .filter(bank_accounts::Column::UsrId.eq(ids.usr_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch bank account error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Bank account not found."))?;
+    // --------------------------------
+    // 2. 各フィールドの更新
+    // --------------------------------
+    let mut active = model.into_active_model();
+    if let Some(v) = req.bank_code { active.bank_code = Set(v); }
+    if let Some(v) = req.bank_name { active.bank_name = Set(normalize_kana("bank_name", &v, 15)?); }
+    if let Some(v) = req.branch_code { active.branch_code = Set(v); }
+    if let Some(v) = req.branch_name { active.branch_name = Set(normalize_kana("branch_name", &v, 15)?); }
+    if let Some(v) = req.account_type { active.account_type = Set(validate_account_type(v)?); }
+    if let Some(v) = req.account_number {
+        active.account_number = Set(encrypt_field("account_number", &v, crypto_key)?);
+        active.account_last4 = Set(last4(&v));
+    }
+    if let Some(v) = req.holder {
+        let holder = normalize_kana("holder", &v, 30)?;
+        active.holder = Set(encrypt_field("holder", &holder, crypto_key)?);
+    }
+    // --------------------------------
+    // 3. 保存
+    // --------------------------------
+    let res = active.update(conn).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update bank account error: {}", e)))?;
+    log::debug!("<BankAccountBl> update_bank_account: Success. ID: {}", res.id);
+    Ok(UpdateBankAccountRes { id: res.id as u32 })
+}
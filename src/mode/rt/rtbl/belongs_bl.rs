@@ -0,0 +1,237 @@
+use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, ColumnTrait, QuerySelect, QueryOrder, Select, ActiveModelTrait, IntoActiveModel, Set, Condition, PaginatorTrait};
+use crate::entities::belongs;
+use crate::utils::jwt::{JwtUsr, JwtIDs, JwtRole};
+use crate::mode::rt::rtreq::belongs_req::{SearchBelongsReq, CreateBelongReq, CloseBelongReq};
+use crate::mode::rt::rtres::belongs_res::{SearchBelongsRes, SearchBelongsResItem, GetBelongRes, CreateBelongRes, CloseBelongRes};
+use crate::mode::rt::rtres::errs_res::ApiError;
+use crate::mode::rt::rtutils::usrs_for_rt::find_usr_by_type_for_rt;
+use crate::mode::rt::rtutils::belongs_for_rt::belong_active_at;
+use axum::http::StatusCode;
+use crate::mode::rt::rterr::rterr;
+use chrono::{Local, NaiveDateTime};
+use crate::enums::usrtype::UsrType;
+use crate::utils::db::str_to_naive_datetime;
+
+// ============================================================
+// Private Helper for Search and Get
+// ============================================================
+/// 権限に基づいた共通のクエリベースを作成する
+/// USR は自身が法人（corp_id）または個人（usr_id）である所属のみ参照できる
+async fn find_belongs_base(
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+) -> Result<Select<belongs::Entity>, ApiError> {
+    let query = belongs::Entity::find();
+    match ju.role() {
+        JwtRole::BD => {
+            log::debug!("<BelongBl> find_belongs_base: BD role. No filtering.");
+            Ok(query)
+        }
+        JwtRole::APX => {
+            log::debug!("<BelongBl> find_belongs_base: APX role. Filter apx_id: {}", ids.apx_id);
+            Ok(query.filter(belongs::Column::ApxId.eq(ids.apx_id)))
+        }
+        JwtRole::VDR => {
+            log::debug!("<BelongBl> find_belongs_base: VDR role. Filter apx_id: {}, vdr_id: {}", ids.apx_id, ids.vdr_id);
+            Ok(query
+                .filter(belongs::Column::ApxId.eq(ids.apx_id))
+                .filter(belongs::Column::VdrId.eq(ids.vdr_id)))
+        }
+        JwtRole::USR => {
+            log::debug!("<BelongBl> find_belongs_base: USR role. Filter apx_id: {}, vdr_id: {}, corp/usr: {}", ids.apx_id, ids.vdr_id, ids.usr_id);
+            Ok(query
+                .filter(belongs::Column::ApxId.eq(ids.apx_id))
+                .filter(belongs::Column::VdrId.eq(ids.vdr_id))
+                .filter(Condition::any().add(belongs::Column::CorpId.eq(ids.usr_id)).add(belongs::Column::UsrId.eq(ids.usr_id))))
+        }
+    }
+}
+
+/// 任意の日時文字列を NaiveDateTime に変換する（未指定の場合は現在日時）
+fn parse_or_now(v: &Option<String>, key: &str) -> Result<NaiveDateTime, ApiError> {
+    match v {
+        Some(s) => str_to_naive_datetime(s).map_err(|e| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, format!("Invalid {}: {}", key, e))),
+        None => Ok(Local::now().naive_local()),
+    }
+}
+
+// ============================================================
+// Search
+// ============================================================
+pub async fn search_belongs(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: SearchBelongsReq,
+) -> Result<SearchBelongsRes, ApiError> {
+    // --------------------------------
+    // 1. クエリの基本形を取得
+    // --------------------------------
+    log::debug!("<BelongBl> search_belongs: Constructing base query.");
+    let mut query = find_belongs_base(ju, ids).await?;
+    // --------------------------------
+    // 2. 検索条件（完全一致）
+    // --------------------------------
+    if let Some(corp_id) = req.corp_id {
+        log::debug!("<BelongBl> search_belongs: Filter by corp_id: {}", corp_id);
+        query = query.filter(belongs::Column::CorpId.eq(corp_id));
+    }
+    if let Some(usr_id) = req.usr_id {
+        log::debug!("<BelongBl> search_belongs: Filter by usr_id: {}", usr_id);
+        query = query.filter(belongs::Column::UsrId.eq(usr_id));
+    }
+    // --------------------------------
+    // 3. 指定日時に有効な所属のみに絞り込み
+    // --------------------------------
+    if req.at.is_some() {
+        let at = parse_or_now(&req.at, "at")?;
+        log::debug!("<BelongBl> search_belongs: Filter by active at: {}", at);
+        query = query.filter(belong_active_at(at));
+    }
+    // --------------------------------
+    // 4. データの取得
+    // --------------------------------
+    log::debug!("<BelongBl> search_belongs: Fetching records. limit: {}, offset: {}", req.limit, req.offset);
+    let models = query
+        .order_by_desc(belongs::Column::Id)
+        .offset(req.offset as u64)
+        .limit(req.limit as u64)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Search query error: {}", e)))?;
+    log::debug!("<BelongBl> search_belongs: Found {} records.", models.len());
+    // --------------------------------
+    // 5. 最終レスポンス
+    // --------------------------------
+    let belongs = models.into_iter().map(SearchBelongsResItem::from).collect();
+    Ok(SearchBelongsRes { belongs })
+}
+
+// ============================================================
+// Get
+// ============================================================
+pub async fn get_belong(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    belong_id: u32,
+) -> Result<GetBelongRes, ApiError> {
+    log::debug!("<BelongBl> get_belong: Fetching belong: {}", belong_id);
+    let model = find_belongs_base(ju, ids).await?
+        .filter(belongs::Column::Id.eq(belong_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch belong error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Belong not found."))?;
+    Ok(GetBelongRes::from(model))
+}
+
+// ============================================================
+// Create
+// ============================================================
+pub async fn create_belong(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: CreateBelongReq,
+) -> Result<CreateBelongRes, ApiError> {
+    // --------------------------------
+    // 1. ロールに基づく所属先法人の決定
+    // --------------------------------
+    log::debug!("<BelongBl> create_belong: Role-based validation for {:?}.", ju.role());
+    let corp_id = match ju.role() {
+        JwtRole::VDR => req.corp_id.ok_or_else(|| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "VDR requires corp_id."))?,
+        JwtRole::USR => {
+            if req.corp_id.is_some() {
+                return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "USR cannot set corp_id."));
+            }
+            ids.usr_id
+        }
+        _ => {
+            return Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Only VDR or corporate USR can create belongs."));
+        }
+    };
+    // --------------------------------
+    // 2. 法人・個人の存在確認
+    // --------------------------------
+    find_usr_by_type_for_rt(conn, ids.apx_id, ids.vdr_id, corp_id, UsrType::Corp).await.map_err(|e| {
+        if ju.role() == JwtRole::USR && e.status == StatusCode::NOT_FOUND.as_u16() {
+            ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Only corporate USR can create belongs.")
+        } else {
+            e
+        }
+    })?;
+    find_usr_by_type_for_rt(conn, ids.apx_id, ids.vdr_id, req.usr_id, UsrType::Indi).await?;
+    // --------------------------------
+    // 3. 所属期間の重複確認（開始日時以降も有効な所属があれば不可）
+    // --------------------------------
+    let open_at = parse_or_now(&req.open_at, "open_at")?;
+    let overlaps = belongs::Entity::find()
+        .filter(belongs::Column::ApxId.eq(ids.apx_id))
+        .filter(belongs::Column::VdrId.eq(ids.vdr_id))
+        .filter(belongs::Column::CorpId.eq(corp_id))
+        .filter(belongs::Column::UsrId.eq(req.usr_id))
+        .filter(Condition::any().add(belongs::Column::CloseAt.is_null()).add(belongs::Column::CloseAt.gt(open_at)))
+        .count(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Count belongs error: {}", e)))?;
+    if overlaps > 0 {
+        log::debug!("<BelongBl> create_belong: Overlapping belong exists. corp: {}, usr: {}", corp_id, req.usr_id);
+        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Belong already exists."));
+    }
+    // --------------------------------
+    // 4. 保存
+    // --------------------------------
+    log::debug!("<BelongBl> create_belong: Inserting belong. corp: {}, usr: {}, open_at: {}", corp_id, req.usr_id, open_at);
+    let active = belongs::ActiveModel {
+        corp_id: Set(corp_id),
+        usr_id: Set(req.usr_id),
+        open_at: Set(Some(open_at)),
+        close_at: Set(None),
+        apx_id: Set(ids.apx_id),
+        vdr_id: Set(ids.vdr_id),
+        ..Default::default()
+    };
+    let res = active.insert(conn).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Insert belong error: {}", e)))?;
+    log::debug!("<BelongBl> create_belong: Success. ID: {}", res.id);
+    Ok(CreateBelongRes { id: res.id as u32 })
+}
+
+// ============================================================
+// Close
+// ============================================================
+pub async fn close_belong(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    belong_id: u32,
+    req: CloseBelongReq,
+) -> Result<CloseBelongRes, ApiError> {
+    // --------------------------------
+    // 1. 対象の取得と状態確認
+    // --------------------------------
+    log::debug!("<BelongBl> close_belong: Fetching target belong: {}", belong_id);
+    let model = find_belongs_base(ju, ids).await?
+        .filter(belongs::Column::Id.eq(belong_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch belong error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Belong not found."))?;
+    if model.close_at.is_some() {
+        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Belong is already closed."));
+    }
+    let close_at = parse_or_now(&req.close_at, "close_at")?;
+    if model.open_at.is_some_and(|v| close_at < v) {
+        return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "close_at must be after open_at."));
+    }
+    // --------------------------------
+    // 2. 保存
+    // --------------------------------
+    log::debug!("<BelongBl> close_belong: Closing at: {}", close_at);
+    let mut active: belongs::ActiveModel = model.into_active_model();
+    active.close_at = Set(Some(close_at));
+    active.update(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update belong error: {}", e)))?;
+    Ok(CloseBelongRes { id: belong_id })
+}
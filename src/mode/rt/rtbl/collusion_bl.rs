@@ -0,0 +1,285 @@
+use std::collections::HashMap;
+use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, ColumnTrait, QuerySelect, QueryOrder, ActiveModelTrait, Set, TransactionTrait};
+use crate::entities::{points, usrs, usr_badges};
+use crate::utils::jwt::{JwtUsr, JwtIDs, JwtRole};
+use crate::utils::collusion::{AwardEdge, CollusionReport, CollusionRule, detect_collusion};
+use crate::utils::db::datetime_to_str;
+use crate::mode::rt::rtreq::collusion_req::CollusionReportReq;
+use crate::mode::rt::rtres::collusion_res::{CollusionReportRes, HoldCollusionAwardsRes, ReleaseCollusionHoldRes};
+use crate::mode::rt::rtres::errs_res::ApiError;
+use axum::http::StatusCode;
+use crate::mode::rt::rterr::rterr;
+use chrono::{Duration, Local, NaiveDateTime};
+use crate::enums::usrtype::UsrType;
+use crate::enums::pointkind::PointKind;
+
+const DEFAULT_DAYS: u32 = 30;
+const DEFAULT_MIN_RECIPROCAL: u32 = 1;
+const DEFAULT_MIN_CLIQUE: u32 = 3;
+const DEFAULT_BURST_HOURS: u32 = 24;
+const DEFAULT_BURST_COUNT: u32 = 10;
+
+// ============================================================
+// Private Helper
+// ============================================================
+/// 操作者を確認する（VDR または スタッフ token のみ）
+/// 戻り値はスタッフの ID（スタッフ以外は 0）
+fn ensure_operator(ju: &JwtUsr) -> Result<u32, ApiError> {
+    let staff_id = ju.staff_id.unwrap_or(0);
+    if ju.role() == JwtRole::USR && staff_id == 0 {
+        return Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Only VDR or staff can operate collusion detection."));
+    }
+    Ok(staff_id)
+}
+
+/// 分析対象の個人間の授与（取り消されていないもの）を取得する
+async fn load_award_edges(
+    conn: &DatabaseConnection,
+    ids: &JwtIDs,
+    since: NaiveDateTime,
+) -> Result<Vec<AwardEdge>, ApiError> {
+    let models = usr_badges::Entity::find()
+        .filter(usr_badges::Column::ApxId.eq(ids.apx_id))
+        .filter(usr_badges::Column::VdrId.eq(ids.vdr_id))
+        .filter(usr_badges::Column::Type.eq(UsrType::Indi as u8))
+        .filter(usr_badges::Column::RevokedAt.is_null())
+        .filter(usr_badges::Column::CreatedAt.gte(since))
+        .order_by_asc(usr_badges::Column::Id)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch usr_badges error: {}", e)))?;
+    let usr_badge_ids: Vec<u32> = models.iter().map(|m| m.id as u32).collect();
+    let points_of: HashMap<u32, u32> = if usr_badge_ids.is_empty() {
+        HashMap::new()
+    } else {
+        points::Entity::find()
+            .filter(points::Column::ApxId.eq(ids.apx_id))
+            .filter(points::Column::VdrId.eq(ids.vdr_id))
+            .filter(points::Column::Kind.eq(PointKind::Award as u8))
+            .filter(points::Column::UsrBadgeId.is_in(usr_badge_ids))
+            .all(conn)
+            .await
+            .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch points error: {}", e)))?
+            .into_iter()
+            .map(|p| (p.usr_badge_id, p.point + p.extra))
+            .collect()
+    };
+    Ok(models
+        .into_iter()
+        .map(|m| AwardEdge {
+            usr_badge_id: m.id as u32,
+            from: m.from,
+            to: m.to,
+            points: points_of.get(&(m.id as u32)).copied().unwrap_or(0),
+            at: m.created_at,
+        })
+        .collect())
+}
+
+/// 条件に従って分析対象を取得し、共謀の疑いを検出する
+/// 戻り値は分析対象の期間の開始日時、分析対象の授与数、検出結果
+async fn run_detection(
+    conn: &DatabaseConnection,
+    ids: &JwtIDs,
+    req: &CollusionReportReq,
+) -> Result<(NaiveDateTime, usize, CollusionReport), ApiError> {
+    let rule = CollusionRule {
+        min_reciprocal: req.min_reciprocal.unwrap_or(DEFAULT_MIN_RECIPROCAL),
+        min_clique: req.min_clique.unwrap_or(DEFAULT_MIN_CLIQUE) as usize,
+        burst_hours: req.burst_hours.unwrap_or(DEFAULT_BURST_HOURS),
+        burst_count: req.burst_count.unwrap_or(DEFAULT_BURST_COUNT),
+    };
+    let since = Local::now().naive_local() - Duration::days(req.days.unwrap_or(DEFAULT_DAYS) as i64);
+    log::debug!("<CollusionBl> run_detection: vdr: {}, since: {}, rule: {:?}", ids.vdr_id, since, rule);
+    let edges = load_award_edges(conn, ids, since).await?;
+    let report = detect_collusion(&edges, &rule);
+    log::debug!("<CollusionBl> run_detection: awards: {}, pairs: {}, cliques: {}, bursts: {}, flagged: {}", edges.len(), report.pairs.len(), report.cliques.len(), report.bursts.len(), report.flagged.len());
+    Ok((since, edges.len(), report))
+}
+
+/// 授与を分配の対象から保留する
+/// 授与と受領者の行をロックするため、分配（flush）・取り消しとは直列化される
+/// 取り消し済み・保留中・分配済み（flush_id が設定済み）の授与は保留せず false を返す
+async fn hold_award(
+    conn: &DatabaseConnection,
+    ids: &JwtIDs,
+    staff_id: u32,
+    usr_badge_id: u32,
+) -> Result<bool, ApiError> {
+    let apx_id = ids.apx_id;
+    let vdr_id = ids.vdr_id;
+    let actor_id = ids.usr_id;
+    let held = conn.transaction::<_, bool, ApiError>(|tx| {
+        Box::pin(async move {
+            let model = usr_badges::Entity::find_by_id(usr_badge_id as i32)
+                .filter(usr_badges::Column::ApxId.eq(apx_id))
+                .filter(usr_badges::Column::VdrId.eq(vdr_id))
+                .lock_exclusive()
+                .one(tx)
+                .await
+                .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock usr_badge error: {}", e)))?
+                .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "UsrBadge not found."))?;
+            if model.revoked_at.is_some() || model.held_at.is_some() {
+                return Ok(false);
+            }
+            usrs::Entity::find_by_id(model.to as i32)
+                .lock_exclusive()
+                .one(tx)
+                .await
+                .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock usr error: {}", e)))?
+                .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "USR not found."))?;
+            let award = points::Entity::find()
+                .filter(points::Column::UsrBadgeId.eq(usr_badge_id))
+                .filter(points::Column::Kind.eq(PointKind::Award as u8))
+                .one(tx)
+                .await
+                .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch point error: {}", e)))?;
+            let Some(award) = award else {
+                return Ok(false);
+            };
+            let held_points = award.point + award.extra;
+            if model.flush_id != 0 || held_points == 0 {
+                log::debug!("<CollusionBl> hold_award: Skipped. usr_badge: {}, flush_id: {}, points: {}", usr_badge_id, model.flush_id, held_points);
+                return Ok(false);
+            }
+            let mut active: usr_badges::ActiveModel = model.into();
+            active.held_at = Set(Some(Local::now().naive_local()));
+            active.held_by = Set(actor_id);
+            active.held_staff_id = Set(staff_id);
+            active.held_points = Set(held_points);
+            active.update(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update usr_badge error: {}", e)))?;
+            log::debug!("<CollusionBl> hold_award: Held. usr_badge: {}, points: {}", usr_badge_id, held_points);
+            Ok(true)
+        })
+    }).await?;
+    Ok(held)
+}
+
+// ============================================================
+// Report
+// ============================================================
+pub async fn get_collusion_report(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: CollusionReportReq,
+) -> Result<CollusionReportRes, ApiError> {
+    // --------------------------------
+    // 1. 操作者の確認
+    // --------------------------------
+    ensure_operator(ju)?;
+    // --------------------------------
+    // 2. 分析対象の取得と検出
+    // --------------------------------
+    let (since, awards, report) = run_detection(conn, ids, &req).await?;
+    // --------------------------------
+    // 3. 最終レスポンス
+    // --------------------------------
+    Ok(CollusionReportRes {
+        since: datetime_to_str(since),
+        awards: awards as u32,
+        pairs: report.pairs.into_iter().map(Into::into).collect(),
+        cliques: report.cliques.into_iter().map(Into::into).collect(),
+        bursts: report.bursts.into_iter().map(Into::into).collect(),
+        usrs: report.usrs.into_iter().map(Into::into).collect(),
+        flagged: report.flagged,
+    })
+}
+
+// ============================================================
+// Hold
+// ============================================================
+pub async fn hold_collusion_awards(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: CollusionReportReq,
+) -> Result<HoldCollusionAwardsRes, ApiError> {
+    // --------------------------------
+    // 1. 操作者の確認
+    // --------------------------------
+    let staff_id = ensure_operator(ju)?;
+    // --------------------------------
+    // 2. 分析対象の取得と検出（レポートと同じ条件）
+    // --------------------------------
+    let (since, _, report) = run_detection(conn, ids, &req).await?;
+    // --------------------------------
+    // 3. 疑わしい授与の保留（授与ごとにトランザクション）
+    // --------------------------------
+    let mut held = Vec::new();
+    for usr_badge_id in report.flagged.iter().copied() {
+        if hold_award(conn, ids, staff_id, usr_badge_id).await? {
+            held.push(usr_badge_id);
+        }
+    }
+    log::debug!("<CollusionBl> hold_collusion_awards: Held {} of {} flagged awards.", held.len(), report.flagged.len());
+    // --------------------------------
+    // 4. 最終レスポンス
+    // --------------------------------
+    Ok(HoldCollusionAwardsRes {
+        since: datetime_to_str(since),
+        flagged: report.flagged,
+        held,
+    })
+}
+
+// ============================================================
+// Release
+// ============================================================
+pub async fn release_collusion_hold(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    usr_badge_id: u32,
+) -> Result<ReleaseCollusionHoldRes, ApiError> {
+    // --------------------------------
+    // 1. 操作者の確認
+    // --------------------------------
+    ensure_operator(ju)?;
+    // --------------------------------
+    // 2. 保留の解除（トランザクション）
+    //    授与と受領者の行をロックするため、分配（flush）・取り消しとは直列化される
+    // --------------------------------
+    log::debug!("<CollusionBl> release_collusion_hold: Locking usr_badge: {}", usr_badge_id);
+    let apx_id = ids.apx_id;
+    let vdr_id = ids.vdr_id;
+    let self_id = (ju.role() == JwtRole::USR).then_some(ids.usr_id);
+    let held_points = conn.transaction::<_, u32, ApiError>(|tx| {
+        Box::pin(async move {
+            let model = usr_badges::Entity::find_by_id(usr_badge_id as i32)
+                .filter(usr_badges::Column::ApxId.eq(apx_id))
+                .filter(usr_badges::Column::VdrId.eq(vdr_id))
+                .lock_exclusive()
+                .one(tx)
+                .await
+                .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock usr_badge error: {}", e)))?
+                .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "UsrBadge not found."))?;
+            if self_id.is_some_and(|id| model.to == id || model.from == id) {
+                return Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Cannot release your own award."));
+            }
+            if model.held_at.is_none() {
+                return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "UsrBadge is not held."));
+            }
+            // 保留中の授与は分配の対象から除かれているため、flush_id は設定されていないはず
+            if model.flush_id != 0 {
+                return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "UsrBadge is already flushed."));
+            }
+            usrs::Entity::find_by_id(model.to as i32)
+                .lock_exclusive()
+                .one(tx)
+                .await
+                .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock usr error: {}", e)))?
+                .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "USR not found."))?;
+            // 保留を行った ID と held_points は記録として残す
+            let held_points = model.held_points;
+            let mut active: usr_badges::ActiveModel = model.into();
+            active.held_at = Set(None);
+            active.update(tx)
+                .await
+                .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update usr_badge error: {}", e)))?;
+            Ok(held_points)
+        })
+    }).await?;
+    log::debug!("<CollusionBl> release_collusion_hold: Released. usr_badge: {}, points: {}", usr_badge_id, held_points);
+    Ok(ReleaseCollusionHoldRes { id: usr_badge_id, held_points })
+}
@@ -0,0 +1,330 @@
+use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, ColumnTrait, QuerySelect, QueryOrder, Select};
+use crate::entities::{flushes, payouts, usrs};
+use crate::utils::jwt::{JwtUsr, JwtIDs, JwtRole};
+use crate::mode::rt::rtreq::flushes_req::{SearchFlushesReq, PreviewFlushReq, SearchPayoutsReq, SummarizeWithholdingReq, ExportFlushZenginReq};
+use crate::mode::rt::rtres::flushes_res::{SearchFlushesRes, SearchFlushesResItem, GetFlushRes, CreateFlushRes, PreviewFlushRes, SearchPayoutsRes, SearchPayoutsResItem, SummarizeWithholdingRes, SummarizeWithholdingResItem, ExportFlushZenginRes};
+use crate::mode::rt::rtres::errs_res::ApiError;
+use crate::mode::rt::rtutils::flush_for_rt::{execute_flush_for_rt, plan_flush_for_rt};
+use crate::mode::rt::rtutils::zengin_for_rt::export_flush_zengin_for_rt;
+use crate::utils::s3client::S3Client;
+use crate::utils::zengin::{ZenginAccount, ZenginHeader};
+use axum::http::StatusCode;
+use crate::mode::rt::rterr::rterr;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use std::collections::HashMap;
+
+// ============================================================
+// Private Helper for Search and Get
+// ============================================================
+/// 権限に基づいた共通のクエリベースを作成する
+async fn find_flushes_base(
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+) -> Result<Select<flushes::Entity>, ApiError> {
+    let query = flushes::Entity::find();
+    match ju.role() {
+        JwtRole::BD => {
+            log::debug!("<FlushBl> find_flushes_base: BD role. No filtering.");
+            Ok(query)
+        }
+        JwtRole::APX => {
+            log::debug!("<FlushBl> find_flushes_base: APX role. Filter apx_id: {}", ids.apx_id);
+            Ok(query.filter(flushes::Column::ApxId.eq(ids.apx_id)))
+        }
+        JwtRole::VDR => {
+            log::debug!("<FlushBl> find_flushes_base: VDR role. Filter apx_id: {}, vdr_id: {}", ids.apx_id, ids.vdr_id);
+            Ok(query
+                .filter(flushes::Column::ApxId.eq(ids.apx_id))
+                .filter(flushes::Column::VdrId.eq(ids.vdr_id)))
+        }
+        JwtRole::USR => {
+            log::debug!("<FlushBl> find_flushes_base: USR role. Access denied.");
+            Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "USR cannot access flushes."))
+        }
+    }
+}
+
+/// 権限に基づいた共通のクエリベースを作成する
+/// USR は自身への分配のみ参照できる
+async fn find_payouts_base(
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+) -> Result<Select<payouts::Entity>, ApiError> {
+    let query = payouts::Entity::find();
+    match ju.role() {
+        JwtRole::BD => {
+            log::debug!("<FlushBl> find_payouts_base: BD role. No filtering.");
+            Ok(query)
+        }
+        JwtRole::APX => {
+            log::debug!("<FlushBl> find_payouts_base: APX role. Filter apx_id: {}", ids.apx_id);
+            Ok(query.filter(payouts::Column::ApxId.eq(ids.apx_id)))
+        }
+        JwtRole::VDR => {
+            log::debug!("<FlushBl> find_payouts_base: VDR role. Filter apx_id: {}, vdr_id: {}", ids.apx_id, ids.vdr_id);
+            Ok(query
+                .filter(payouts::Column::ApxId.eq(ids.apx_id))
+                .filter(payouts::Column::VdrId.eq(ids.vdr_id)))
+        }
+        JwtRole::USR => {
+            log::debug!("<FlushBl> find_payouts_base: USR role. Filter apx_id: {}, vdr_id: {}, usr_id: {}", ids.apx_id, ids.vdr_id, ids.usr_id);
+            Ok(query
+                .filter(payouts::Column::ApxId.eq(ids.apx_id))
+                .filter(payouts::Column::VdrId.eq(ids.vdr_id))
+                .filter(payouts::Column::UsrId.eq(ids.usr_id)))
+        }
+    }
+}
+
+// ============================================================
+// Search
+// ============================================================
+pub async fn search_flushes(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: SearchFlushesReq,
+) -> Result<SearchFlushesRes, ApiError> {
+    // --------------------------------
+    // 1. クエリの基本形を取得
+    // --------------------------------
+    log::debug!("<FlushBl> search_flushes: Constructing base query.");
+    let query = find_flushes_base(ju, ids).await?;
+    // --------------------------------
+    // 2. データの取得
+    // --------------------------------
+    log::debug!("<FlushBl> search_flushes: Fetching records. limit: {}, offset: {}", req.limit, req.offset);
+    let models = query
+        .order_by_desc(flushes::Column::Id)
+        .offset(req.offset as u64)
+        .limit(req.limit as u64)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Search query error: {}", e)))?;
+    log::debug!("<FlushBl> search_flushes: Found {} records.", models.len());
+    // --------------------------------
+    // 3. 最終レスポンス
+    // --------------------------------
+    let flushes = models.into_iter().map(SearchFlushesResItem::from).collect();
+    Ok(SearchFlushesRes { flushes })
+}
+
+// ============================================================
+// Get
+// ============================================================
+pub async fn get_flush(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    flush_id: u32,
+) -> Result<GetFlushRes, ApiError> {
+    log::debug!("<FlushBl> get_flush: Fetching flush: {}", flush_id);
+    let model = find_flushes_base(ju, ids).await?
+        .filter(flushes::Column::Id.eq(flush_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch flush error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Flush not found."))?;
+    let payouts = payouts::Entity::find()
+        .filter(payouts::Column::FlushId.eq(flush_id))
+        .order_by_asc(payouts::Column::UsrId)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch payouts error: {}", e)))?;
+    Ok(GetFlushRes::new(model, payouts))
+}
+
+// ============================================================
+// Create
+// ============================================================
+pub async fn create_flush(
+    conn: &DatabaseConnection,
+    ids: &JwtIDs,
+) -> Result<CreateFlushRes, ApiError> {
+    log::debug!("<FlushBl> create_flush: Executing flush. apx_id: {}, vdr_id: {}", ids.apx_id, ids.vdr_id);
+    let (flush, plan) = execute_flush_for_rt(conn, ids.apx_id, ids.vdr_id).await?;
+    log::debug!("<FlushBl> create_flush: Success. ID: {}, payouts: {}", flush.id, plan.lines.len());
+    Ok(CreateFlushRes::new(&flush, &plan))
+}
+
+// ============================================================
+// Preview
+// ============================================================
+pub async fn preview_flush(
+    conn: &DatabaseConnection,
+    ids: &JwtIDs,
+    req: PreviewFlushReq,
+) -> Result<PreviewFlushRes, ApiError> {
+    log::debug!("<FlushBl> preview_flush: Planning flush. apx_id: {}, vdr_id: {}, total: {:?}", ids.apx_id, ids.vdr_id, req.total);
+    let plan = plan_flush_for_rt(conn, ids.apx_id, ids.vdr_id, req.total).await?;
+    log::debug!("<FlushBl> preview_flush: total: {}, fee: {}, distributable: {}, points: {}, remainder: {}", plan.total, plan.fee, plan.distributable, plan.points, plan.remainder);
+    Ok(PreviewFlushRes::from(plan))
+}
+
+// ============================================================
+// Search Payouts
+// ============================================================
+pub async fn search_payouts(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: SearchPayoutsReq,
+) -> Result<SearchPayoutsRes, ApiError> {
+    // --------------------------------
+    // 1. クエリの基本形を取得
+    // --------------------------------
+    log::debug!("<FlushBl> search_payouts: Constructing base query.");
+    let mut query = find_payouts_base(ju, ids).await?;
+    // --------------------------------
+    // 2. 検索条件（完全一致）
+    // --------------------------------
+    if let Some(flush_id) = req.flush_id {
+        log::debug!("<FlushBl> search_payouts: Filter by flush_id: {}", flush_id);
+        query = query.filter(payouts::Column::FlushId.eq(flush_id));
+    }
+    if let Some(usr_id) = req.usr_id {
+        log::debug!("<FlushBl> search_payouts: Filter by usr_id: {}", usr_id);
+        query = query.filter(payouts::Column::UsrId.eq(usr_id));
+    }
+    // --------------------------------
+    // 3. データの取得
+    // --------------------------------
+    log::debug!("<FlushBl> search_payouts: Fetching records. limit: {}, offset: {}", req.limit, req.offset);
+    let models = query
+        .order_by_desc(payouts::Column::Id)
+        .offset(req.offset as u64)
+        .limit(req.limit as u64)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Search query error: {}", e)))?;
+    log::debug!("<FlushBl> search_payouts: Found {} records.", models.len());
+    // --------------------------------
+    // 4. 最終レスポンス
+    // --------------------------------
+    let payouts = models.into_iter().map(SearchPayoutsResItem::from).collect();
+    Ok(SearchPayoutsRes { payouts })
+}
+
+// ============================================================
+// Withholding Summary
+// ============================================================
+/// 個人ごとの集計行（apx_id, vdr_id, usr_id, 件数, 総額, 源泉徴収額, 差引支払額）
+type WithholdingRow = (u32, u32, u32, i64, Option<Decimal>, Option<Decimal>, Option<Decimal>);
+
+pub async fn summarize_withholding(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: SummarizeWithholdingReq,
+) -> Result<SummarizeWithholdingRes, ApiError> {
+    // --------------------------------
+    // 1. クエリの基本形を取得し、対象年で絞り込む
+    // --------------------------------
+    log::debug!("<FlushBl> summarize_withholding: year: {}", req.year);
+    let bgn = NaiveDate::from_ymd_opt(req.year, 1, 1).and_then(|d| d.and_hms_opt(0, 0, 0));
+    let end = NaiveDate::from_ymd_opt(req.year + 1, 1, 1).and_then(|d| d.and_hms_opt(0, 0, 0));
+    let (Some(bgn), Some(end)) = (bgn, end) else {
+        return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, format!("Invalid year: {}", req.year)));
+    };
+    let mut query = find_payouts_base(ju, ids).await?
+        .filter(payouts::Column::CreatedAt.gte(bgn))
+        .filter(payouts::Column::CreatedAt.lt(end));
+    if let Some(usr_id) = req.usr_id {
+        log::debug!("<FlushBl> summarize_withholding: Filter by usr_id: {}", usr_id);
+        query = query.filter(payouts::Column::UsrId.eq(usr_id));
+    }
+    // --------------------------------
+    // 2. 個人ごとの集計
+    // --------------------------------
+    let rows: Vec<WithholdingRow> = query
+        .select_only()
+        .column(payouts::Column::ApxId)
+        .column(payouts::Column::VdrId)
+        .column(payouts::Column::UsrId)
+        .column_as(payouts::Column::Id.count(), "cnt")
+        .column_as(payouts::Column::Amount.sum(), "gross")
+        .column_as(payouts::Column::Withheld.sum(), "withheld")
+        .column_as(payouts::Column::Net.sum(), "net")
+        .group_by(payouts::Column::ApxId)
+        .group_by(payouts::Column::VdrId)
+        .group_by(payouts::Column::UsrId)
+        .order_by_asc(payouts::Column::ApxId)
+        .order_by_asc(payouts::Column::VdrId)
+        .order_by_asc(payouts::Column::UsrId)
+        .into_tuple()
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Summarize payouts error: {}", e)))?;
+    log::debug!("<FlushBl> summarize_withholding: Found {} usrs.", rows.len());
+    // --------------------------------
+    // 3. 個人名の取得
+    // --------------------------------
+    let usr_ids: Vec<u32> = rows.iter().map(|r| r.2).collect();
+    let names: HashMap<u32, String> = usrs::Entity::find()
+        .filter(usrs::Column::Id.is_in(usr_ids))
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch usrs error: {}", e)))?
+        .into_iter()
+        .map(|m| (m.id as u32, m.name))
+        .collect();
+    // --------------------------------
+    // 4. 最終レスポンス
+    // --------------------------------
+    let to_u64 = |v: Option<Decimal>| v.and_then(|d| d.to_u64()).unwrap_or(0);
+    let usrs: Vec<SummarizeWithholdingResItem> = rows
+        .into_iter()
+        .map(|(apx_id, vdr_id, usr_id, cnt, gross, withheld, net)| SummarizeWithholdingResItem {
+            apx_id,
+            vdr_id,
+            usr_id,
+            name: names.get(&usr_id).cloned().unwrap_or_default(),
+            payouts: cnt as u32,
+            gross: to_u64(gross),
+            withheld: to_u64(withheld),
+            net: to_u64(net),
+        })
+        .collect();
+    Ok(SummarizeWithholdingRes {
+        year: req.year,
+        gross: usrs.iter().map(|u| u.gross).sum(),
+        withheld: usrs.iter().map(|u| u.withheld).sum(),
+        net: usrs.iter().map(|u| u.net).sum(),
+        usrs,
+    })
+}
+
+// ============================================================
+// Export Zengin
+// ============================================================
+pub async fn export_flush_zengin(
+    conn: &DatabaseConnection,
+    ids: &JwtIDs,
+    s3c: &S3Client,
+    crypto_key: &str,
+    flush_id: u32,
+    req: ExportFlushZenginReq,
+) -> Result<ExportFlushZenginRes, ApiError> {
+    log::debug!("<FlushBl> export_flush_zengin: Exporting flush: {}", flush_id);
+    let transfer_date = chrono::NaiveDate::parse_from_str(&req.transfer_date, "%Y-%m-%d")
+        .map_err(|e| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, format!("Invalid transfer_date: {}", e)))?;
+    let header = ZenginHeader {
+        consignor_code: req.consignor_code,
+        consignor_name: req.consignor_name,
+        transfer_date,
+        account: ZenginAccount {
+            bank_code: req.bank_code,
+            bank_name: req.bank_name,
+            branch_code: req.branch_code,
+            branch_name: req.branch_name,
+            account_type: req.account_type,
+            account_number: req.account_number,
+            holder: String::new(),
+        },
+    };
+    let key = export_flush_zengin_for_rt(conn, s3c, crypto_key, ids.apx_id, ids.vdr_id, flush_id, &header).await?;
+    log::debug!("<FlushBl> export_flush_zengin: Success. key: {}", key);
+    Ok(ExportFlushZenginRes { key })
+}
@@ -0,0 +1,342 @@
+use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, ColumnTrait, QuerySelect, Select, ActiveModelTrait, IntoActiveModel, Set, ModelTrait, TransactionTrait, Condition, PaginatorTrait};
+use crate::entities::{jobs, matches, match_statuses, works};
+use crate::utils::jwt::{JwtUsr, JwtIDs, JwtRole};
+use crate::mode::rt::rtreq::jobs_req::{SearchJobsReq, CreateJobReq, UpdateJobReq};
+use crate::mode::rt::rtres::jobs_res::{SearchJobsRes, SearchJobsResItem, GetJobRes, CreateJobRes, UpdateJobRes, DeleteJobRes};
+use crate::mode::rt::rtres::errs_res::ApiError;
+use crate::mode::rt::rtutils::usrs_for_rt::find_usr_by_type_for_rt;
+use axum::http::StatusCode;
+use crate::mode::rt::rterr::rterr;
+use chrono::NaiveDateTime;
+use crate::enums::usrtype::UsrType;
+use crate::utils::db::str_to_naive_datetime;
+
+// ============================================================
+// Private Helper for Search and Get
+// ============================================================
+/// 権限に基づいた共通のクエリベースを作成する
+/// 求人は VDR 内の全 USR（法人・個人）が閲覧できる
+async fn find_jobs_base(
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+) -> Result<Select<jobs::Entity>, ApiError> {
+    let query = jobs::Entity::find();
+    match ju.role() {
+        JwtRole::BD => {
+            log::debug!("<JobBl> find_jobs_base: BD role. No filtering.");
+            Ok(query)
+        }
+        JwtRole::APX => {
+            log::debug!("<JobBl> find_jobs_base: APX role. Filter apx_id: {}", ids.apx_id);
+            Ok(query.filter(jobs::Column::ApxId.eq(ids.apx_id)))
+        }
+        JwtRole::VDR => {
+            log::debug!("<JobBl> find_jobs_base: VDR role. Filter apx_id: {}, vdr_id: {}", ids.apx_id, ids.vdr_id);
+            Ok(query
+                .filter(jobs::Column::ApxId.eq(ids.apx_id))
+                .filter(jobs::Column::VdrId.eq(ids.vdr_id)))
+        }
+        JwtRole::USR => {
+            log::debug!("<JobBl> find_jobs_base: USR role. Filter apx_id: {}, vdr_id: {}", ids.apx_id, ids.vdr_id);
+            Ok(query
+                .filter(jobs::Column::ApxId.eq(ids.apx_id))
+                .filter(jobs::Column::VdrId.eq(ids.vdr_id)))
+        }
+    }
+}
+
+/// 更新・削除の対象を、書き込み権限のある求人に限定する
+/// USR は自身が発行した求人（corp_id = 自分）のみを操作できる
+fn restrict_to_writable(
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    query: Select<jobs::Entity>,
+) -> Select<jobs::Entity> {
+    match ju.role() {
+        JwtRole::USR => {
+            log::debug!("<JobBl> restrict_to_writable: USR role. Filter corp_id: {}", ids.usr_id);
+            query.filter(jobs::Column::CorpId.eq(ids.usr_id))
+        }
+        _ => query,
+    }
+}
+
+/// 任意の日時文字列を NaiveDateTime に変換する
+fn parse_opt_datetime(v: &Option<String>, key: &str) -> Result<Option<NaiveDateTime>, ApiError> {
+    match v {
+        Some(s) => str_to_naive_datetime(s)
+            .map(Some)
+            .map_err(|e| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, format!("Invalid {}: {}", key, e))),
+        None => Ok(None),
+    }
+}
+
+/// 開始・終了の前後関係を検証する（どちらかが未設定の場合は検証しない）
+fn check_range(bgn: Option<NaiveDateTime>, end: Option<NaiveDateTime>, label: &str) -> Result<(), ApiError> {
+    if let (Some(b), Some(e)) = (bgn, end) && b > e {
+        return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, format!("{} bgn must be before end.", label)));
+    }
+    Ok(())
+}
+
+// ============================================================
+// Search
+// ============================================================
+pub async fn search_jobs(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: SearchJobsReq,
+) -> Result<SearchJobsRes, ApiError> {
+    // --------------------------------
+    // 1. クエリの基本形を取得
+    // --------------------------------
+    log::debug!("<JobBl> search_jobs: Constructing base query.");
+    let mut query = find_jobs_base(ju, ids).await?;
+    // --------------------------------
+    // 2. 検索条件（LIKE検索 / 完全一致）
+    // --------------------------------
+    if !req.name.is_empty() {
+        log::debug!("<JobBl> search_jobs: Filter by name: {}", req.name);
+        query = query.filter(jobs::Column::Name.contains(&req.name));
+    }
+    if !req.location.is_empty() {
+        log::debug!("<JobBl> search_jobs: Filter by location: {}", req.location);
+        query = query.filter(jobs::Column::Location.contains(&req.location));
+    }
+    if let Some(corp_id) = req.corp_id {
+        log::debug!("<JobBl> search_jobs: Filter by corp_id: {}", corp_id);
+        query = query.filter(jobs::Column::CorpId.eq(corp_id));
+    }
+    // --------------------------------
+    // 3. 募集期間のフィルタリング
+    // --------------------------------
+    log::debug!("<JobBl> search_jobs: Filter by range [{}, {}]", req.bgn_at, req.end_at);
+    let bgn_at = str_to_naive_datetime(&req.bgn_at).map_err(|e| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, format!("Invalid bgn_at: {}", e)))?;
+    let end_at = str_to_naive_datetime(&req.end_at).map_err(|e| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, format!("Invalid end_at: {}", e)))?;
+    // モデルの [OpenAt, CloseAt] が [req.bgn_at, req.end_at] と重なるものを抽出（NULL は無期限として扱う）
+    query = query
+        .filter(Condition::any().add(jobs::Column::OpenAt.is_null()).add(jobs::Column::OpenAt.lte(end_at)))
+        .filter(Condition::any().add(jobs::Column::CloseAt.is_null()).add(jobs::Column::CloseAt.gte(bgn_at)));
+    // --------------------------------
+    // 4. データの取得
+    // --------------------------------
+    log::debug!("<JobBl> search_jobs: Fetching records. limit: {}, offset: {}", req.limit, req.offset);
+    let models = query
+        .offset(req.offset as u64)
+        .limit(req.limit as u64)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Search query error: {}", e)))?;
+    log::debug!("<JobBl> search_jobs: Found {} records.", models.len());
+    // --------------------------------
+    // 5. 最終レスポンス
+    // --------------------------------
+    let jobs = models.into_iter().map(SearchJobsResItem::from).collect();
+    Ok(SearchJobsRes { jobs })
+}
+
+// ============================================================
+// Get
+// ============================================================
+pub async fn get_job(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    job_id: u32,
+) -> Result<GetJobRes, ApiError> {
+    log::debug!("<JobBl> get_job: Fetching job: {}", job_id);
+    let model = find_jobs_base(ju, ids).await?
+        .filter(jobs::Column::Id.eq(job_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch job error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Job not found."))?;
+    Ok(GetJobRes::from(model))
+}
+
+// ============================================================
+// Create
+// ============================================================
+pub async fn create_job(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: CreateJobReq,
+) -> Result<CreateJobRes, ApiError> {
+    // --------------------------------
+    // 1. ロールに基づく発行元法人の決定
+    // --------------------------------
+    log::debug!("<JobBl> create_job: Role-based validation for {:?}.", ju.role());
+    let corp_id = match ju.role() {
+        JwtRole::VDR => {
+            // VDR は配下の法人を指定して代理で発行する
+            let cid = req.corp_id.ok_or_else(|| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "VDR requires corp_id."))?;
+            find_usr_by_type_for_rt(conn, ids.apx_id, ids.vdr_id, cid, UsrType::Corp).await?;
+            cid
+        }
+        JwtRole::USR => {
+            // USR は法人である自分自身の求人のみ発行できる
+            if req.corp_id.is_some() {
+                return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "USR cannot set corp_id."));
+            }
+            find_usr_by_type_for_rt(conn, ids.apx_id, ids.vdr_id, ids.usr_id, UsrType::Corp).await.map_err(|e| {
+                if e.status == StatusCode::NOT_FOUND.as_u16() {
+                    ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Only corporate USR can post jobs.")
+                } else {
+                    e
+                }
+            })?;
+            ids.usr_id
+        }
+        _ => {
+            return Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Only VDR or corporate USR can post jobs."));
+        }
+    };
+    // --------------------------------
+    // 2. 日時変換と前後関係の検証
+    // --------------------------------
+    let work_bgn_at = parse_opt_datetime(&req.work_bgn_at, "work_bgn_at")?;
+    let work_end_at = parse_opt_datetime(&req.work_end_at, "work_end_at")?;
+    let open_at = parse_opt_datetime(&req.open_at, "open_at")?;
+    let close_at = parse_opt_datetime(&req.close_at, "close_at")?;
+    check_range(work_bgn_at, work_end_at, "Work")?;
+    check_range(open_at, close_at, "Open")?;
+    // --------------------------------
+    // 3. 保存
+    // --------------------------------
+    log::debug!("<JobBl> create_job: Inserting job for corp: {}", corp_id);
+    let active = jobs::ActiveModel {
+        corp_id: Set(corp_id),
+        name: Set(req.name),
+        description: Set(req.description),
+        max: Set(req.max),
+        filled: Set(0),
+        hour_price: Set(req.hour_price),
+        requirements: Set(req.requirements),
+        benefits: Set(req.benefits),
+        location: Set(req.location),
+        phone: Set(req.phone),
+        max_badges: Set(req.max_badges),
+        work_bgn_at: Set(work_bgn_at),
+        work_end_at: Set(work_end_at),
+        open_at: Set(open_at),
+        close_at: Set(close_at),
+        apx_id: Set(ids.apx_id),
+        vdr_id: Set(ids.vdr_id),
+        ..Default::default()
+    };
+    let res = active.insert(conn).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Insert job error: {}", e)))?;
+    log::debug!("<JobBl> create_job: Success. ID: {}", res.id);
+    // --------------------------------
+    // 4. 最終レスポンス
+    // --------------------------------
+    Ok(CreateJobRes { id: res.id as u32 })
+}
+
+// ============================================================
+// Update
+// ============================================================
+pub async fn update_job(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    job_id: u32,
+    req: UpdateJobReq,
+) -> Result<UpdateJobRes, ApiError> {
+    // --------------------------------
+    // 1. クエリの基本形を取得して存在確認
+    // --------------------------------
+    log::debug!("<JobBl> update_job: Fetching target job: {}", job_id);
+    let query = find_jobs_base(ju, ids).await?.filter(jobs::Column::Id.eq(job_id));
+    let model = restrict_to_writable(ju, ids, query)
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch job error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Job not found."))?;
+    // --------------------------------
+    // 2. 更新後の値に基づく整合性チェック
+    // --------------------------------
+    if let Some(max) = req.max && max < model.filled {
+        return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, format!("max cannot be less than filled ({}).", model.filled)));
+    }
+    let work_bgn_at = parse_opt_datetime(&req.work_bgn_at, "work_bgn_at")?.or(model.work_bgn_at);
+    let work_end_at = parse_opt_datetime(&req.work_end_at, "work_end_at")?.or(model.work_end_at);
+    let open_at = parse_opt_datetime(&req.open_at, "open_at")?.or(model.open_at);
+    let close_at = parse_opt_datetime(&req.close_at, "close_at")?.or(model.close_at);
+    check_range(work_bgn_at, work_end_at, "Work")?;
+    check_range(open_at, close_at, "Open")?;
+    // --------------------------------
+    // 3. 各フィールドの更新
+    // --------------------------------
+    let mut active: jobs::ActiveModel = model.into_active_model();
+    if let Some(v) = req.name { active.name = Set(v); }
+    if let Some(v) = req.description { active.description = Set(v); }
+    if let Some(v) = req.max { active.max = Set(v); }
+    if let Some(v) = req.hour_price { active.hour_price = Set(v); }
+    if let Some(v) = req.requirements { active.requirements = Set(v); }
+    if let Some(v) = req.benefits { active.benefits = Set(v); }
+    if let Some(v) = req.location { active.location = Set(v); }
+    if let Some(v) = req.phone { active.phone = Set(v); }
+    if let Some(v) = req.max_badges { active.max_badges = Set(v); }
+    if req.work_bgn_at.is_some() { active.work_bgn_at = Set(work_bgn_at); }
+    if req.work_end_at.is_some() { active.work_end_at = Set(work_end_at); }
+    if req.open_at.is_some() { active.open_at = Set(open_at); }
+    if req.close_at.is_some() { active.close_at = Set(close_at); }
+    // --------------------------------
+    // 4. 保存
+    // --------------------------------
+    log::debug!("<JobBl> update_job: Saving changes to DB.");
+    active.update(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update job error: {}", e)))?;
+    log::debug!("<JobBl> update_job: Success.");
+    Ok(UpdateJobRes { id: job_id })
+}
+
+// ============================================================
+// Delete
+// ============================================================
+pub async fn delete_job(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    job_id: u32,
+) -> Result<DeleteJobRes, ApiError> {
+    // --------------------------------
+    // 1. クエリの基本形を取得して存在確認
+    // --------------------------------
+    log::debug!("<JobBl> delete_job: Fetching target job: {}", job_id);
+    let query = find_jobs_base(ju, ids).await?.filter(jobs::Column::Id.eq(job_id));
+    let model = restrict_to_writable(ju, ids, query)
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch job error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Job not found."))?;
+    // --------------------------------
+    // 2. 就労実績がある求人は削除できない
+    // --------------------------------
+    let work_count = works::Entity::find()
+        .filter(works::Column::JobId.eq(job_id))
+        .count(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Count works error: {}", e)))?;
+    if work_count > 0 {
+        log::debug!("<JobBl> delete_job: Job {} has {} works. Refusing.", job_id, work_count);
+        return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "Job with works cannot be deleted."));
+    }
+    // --------------------------------
+    // 3. 削除の実行（関連するアプローチ情報も一括削除）
+    // --------------------------------
+    log::debug!("<JobBl> delete_job: Starting deletion transaction.");
+    conn.transaction::<_, (), ApiError>(|tx| {
+        Box::pin(async move {
+            match_statuses::Entity::delete_many().filter(match_statuses::Column::JobId.eq(job_id)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete match_statuses error: {}", e)))?;
+            matches::Entity::delete_many().filter(matches::Column::JobId.eq(job_id)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete matches error: {}", e)))?;
+            model.delete(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete job error: {}", e)))?;
+            log::debug!("<JobBl> delete_job: Transaction success.");
+            Ok(())
+        })
+    }).await?;
+    Ok(DeleteJobRes { id: job_id })
+}
@@ -0,0 +1,92 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use sea_orm::DatabaseConnection;
+use crate::utils::jwt::{JwtUsr, JwtIDs, JwtRole};
+use crate::utils::journal::{JournalEntry, to_csv};
+use crate::mode::rt::rtreq::journals_req::ExportJournalReq;
+use crate::mode::rt::rtres::journals_res::ExportJournalRes;
+use crate::mode::rt::rtres::errs_res::ApiError;
+use crate::mode::rt::rtutils::journals_for_rt::build_journal_for_rt;
+use crate::mode::rt::rtutils::usrs_for_rt::find_vdr_for_rt;
+use axum::http::StatusCode;
+use crate::mode::rt::rterr::rterr;
+
+// ============================================================
+// Private Helper for Export
+// ============================================================
+/// ロールに基づいて対象の VDR と期間を決定し、仕訳を作成する
+/// 期間は bgn_date 00:00:00 以上、end_date の翌日 00:00:00 未満
+async fn build_journal(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: &ExportJournalReq,
+) -> Result<(u32, Vec<JournalEntry>), ApiError> {
+    // --------------------------------
+    // 1. ロールに基づく対象 VDR の決定
+    // --------------------------------
+    log::debug!("<JournalBl> build_journal: Role-based validation for {:?}.", ju.role());
+    let vdr_id = match ju.role() {
+        JwtRole::APX => req.vdr_id.ok_or_else(|| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "APX requires vdr_id."))?,
+        JwtRole::VDR => {
+            if req.vdr_id.is_some() {
+                return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "VDR cannot set vdr_id."));
+            }
+            ids.vdr_id
+        }
+        _ => {
+            return Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Only APX or VDR can export journals."));
+        }
+    };
+    find_vdr_for_rt(conn, ids.apx_id, vdr_id).await?;
+    // --------------------------------
+    // 2. 期間の決定
+    // --------------------------------
+    let bgn = parse_date(&req.bgn_date, "bgn_date")?;
+    let end = parse_date(&req.end_date, "end_date")?;
+    if end < bgn {
+        return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "end_date must be on or after bgn_date."));
+    }
+    let bgn: NaiveDateTime = bgn.and_time(Default::default());
+    let end: NaiveDateTime = end.succ_opt()
+        .ok_or_else(|| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "Invalid end_date."))?
+        .and_time(Default::default());
+    // --------------------------------
+    // 3. 仕訳の作成（貸借が一致しない場合は 409）
+    // --------------------------------
+    log::debug!("<JournalBl> build_journal: vdr: {}, bgn: {}, end: {}", vdr_id, bgn, end);
+    let entries = build_journal_for_rt(conn, ids.apx_id, vdr_id, bgn, end).await?;
+    Ok((vdr_id, entries))
+}
+
+fn parse_date(s: &str, field: &str) -> Result<NaiveDate, ApiError> {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .map_err(|e| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, format!("Invalid {}: {}", field, e)))
+}
+
+// ============================================================
+// Export (JSON)
+// ============================================================
+pub async fn export_journal(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: ExportJournalReq,
+) -> Result<ExportJournalRes, ApiError> {
+    let (vdr_id, entries) = build_journal(conn, ju, ids, &req).await?;
+    log::debug!("<JournalBl> export_journal: Success. entries: {}", entries.len());
+    Ok(ExportJournalRes::new(vdr_id, req.bgn_date, req.end_date, entries))
+}
+
+// ============================================================
+// Export (CSV)
+// ============================================================
+pub async fn export_journal_csv(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: ExportJournalReq,
+) -> Result<String, ApiError> {
+    let (_, entries) = build_journal(conn, ju, ids, &req).await?;
+    log::debug!("<JournalBl> export_journal_csv: Success. entries: {}", entries.len());
+    Ok(to_csv(&entries))
+}
@@ -0,0 +1,680 @@
+use sea_orm::{ConnectionTrait, DatabaseConnection, DatabaseTransaction, EntityTrait, QueryFilter, ColumnTrait, QuerySelect, QueryOrder, Select, ActiveModelTrait, IntoActiveModel, Set, TransactionTrait, Condition, PaginatorTrait};
+use crate::entities::{jobs, matches, match_statuses, usrs, usr_badges, works};
+use crate::utils::jwt::{JwtUsr, JwtIDs, JwtRole};
+use crate::mode::rt::rtreq::matches_req::{SearchMatchesReq, CreateMatchReq, AcceptMatchReq, UpdateMatchStatusReq, SearchMatchCandidatesReq};
+use crate::mode::rt::rtres::matches_res::{SearchMatchesRes, SearchMatchesResItem, GetMatchRes, CreateMatchRes, AcceptMatchRes, DeclineMatchRes, ConfirmMatchRes, UpdateMatchStatusRes, SearchMatchCandidatesRes, SearchMatchCandidatesResItem};
+use crate::mode::rt::rtres::errs_res::ApiError;
+use crate::mode::rt::rtutils::usrs_for_rt::{find_usr_by_type_for_rt, find_vdr_for_rt};
+use axum::http::StatusCode;
+use crate::mode::rt::rterr::rterr;
+use chrono::Local;
+use crate::enums::usrtype::UsrType;
+use crate::enums::matchstatus::MatchStatus;
+use crate::enums::matchreason::MatchReason;
+use crate::utils::ranking::{self, Candidate, RankingContext};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use std::collections::{HashMap, HashSet};
+
+// ============================================================
+// Private Helper for Search and Get
+// ============================================================
+/// 権限に基づいた共通のクエリベースを作成する
+/// USR は自身が送信元（法人）または送信先（個人）であるアプローチのみ参照できる
+async fn find_matches_base(
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+) -> Result<Select<matches::Entity>, ApiError> {
+    let query = matches::Entity::find();
+    match ju.role() {
+        JwtRole::BD => {
+            log::debug!("<MatchBl> find_matches_base: BD role. No filtering.");
+            Ok(query)
+        }
+        JwtRole::APX => {
+            log::debug!("<MatchBl> find_matches_base: APX role. Filter apx_id: {}", ids.apx_id);
+            Ok(query.filter(matches::Column::ApxId.eq(ids.apx_id)))
+        }
+        JwtRole::VDR => {
+            log::debug!("<MatchBl> find_matches_base: VDR role. Filter apx_id: {}, vdr_id: {}", ids.apx_id, ids.vdr_id);
+            Ok(query
+                .filter(matches::Column::ApxId.eq(ids.apx_id))
+                .filter(matches::Column::VdrId.eq(ids.vdr_id)))
+        }
+        JwtRole::USR => {
+            log::debug!("<MatchBl> find_matches_base: USR role. Filter apx_id: {}, vdr_id: {}, from/to: {}", ids.apx_id, ids.vdr_id, ids.usr_id);
+            Ok(query
+                .filter(matches::Column::ApxId.eq(ids.apx_id))
+                .filter(matches::Column::VdrId.eq(ids.vdr_id))
+                .filter(Condition::any().add(matches::Column::From.eq(ids.usr_id)).add(matches::Column::To.eq(ids.usr_id))))
+        }
+    }
+}
+
+/// アプローチの当事者のうち、どちらの立場で操作するか
+enum MatchSide {
+    /// 求人を発行した法人（from）
+    Corp,
+    /// アプローチを受けた個人（to）
+    Indi,
+}
+
+/// 更新対象のアプローチを取得する
+/// VDR は代理で操作でき、USR は指定された立場の当事者である場合のみ操作できる
+async fn find_match_for_update(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    match_id: u32,
+    side: MatchSide,
+) -> Result<matches::Model, ApiError> {
+    let mut query = find_matches_base(ju, ids).await?.filter(matches::Column::Id.eq(match_id));
+    if ju.role() == JwtRole::USR {
+        query = match side {
+            MatchSide::Corp => query.filter(matches::Column::From.eq(ids.usr_id)),
+            MatchSide::Indi => query.filter(matches::Column::To.eq(ids.usr_id)),
+        };
+    }
+    query
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch match error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Match not found."))
+}
+
+/// アプローチの最新の履歴を取得する
+async fn find_latest_status<C: ConnectionTrait>(
+    conn: &C,
+    match_id: u32,
+) -> Result<Option<match_statuses::Model>, ApiError> {
+    match_statuses::Entity::find()
+        .filter(match_statuses::Column::MatchId.eq(match_id))
+        .order_by_desc(match_statuses::Column::Id)
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch match status error: {}", e)))
+}
+
+/// アプローチ対象の求人を取得する
+/// USR は自身が発行した求人のみ対象とできる
+async fn find_job_for_match(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    job_id: u32,
+) -> Result<jobs::Model, ApiError> {
+    let mut query = jobs::Entity::find()
+        .filter(jobs::Column::Id.eq(job_id))
+        .filter(jobs::Column::ApxId.eq(ids.apx_id))
+        .filter(jobs::Column::VdrId.eq(ids.vdr_id));
+    if ju.role() == JwtRole::USR {
+        query = query.filter(jobs::Column::CorpId.eq(ids.usr_id));
+    }
+    query
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch job error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Job not found."))
+}
+
+/// VDR 内の個人ごとのバッジ保有数を集計する（usr_id を指定した場合はその個人のみ）
+/// 取り消し済み、または有効期限を過ぎたバッジ授与は数えない
+async fn load_badge_counts(
+    conn: &DatabaseConnection,
+    ids: &JwtIDs,
+    usr_id: Option<u32>,
+) -> Result<HashMap<u32, u32>, ApiError> {
+    let now = Local::now().naive_local();
+    let mut query = usr_badges::Entity::find()
+        .select_only()
+        .column(usr_badges::Column::To)
+        .column_as(usr_badges::Column::Id.count(), "cnt")
+        .filter(usr_badges::Column::ApxId.eq(ids.apx_id))
+        .filter(usr_badges::Column::VdrId.eq(ids.vdr_id))
+        .filter(usr_badges::Column::RevokedAt.is_null())
+        .filter(Condition::any()
+            .add(usr_badges::Column::ExpiresAt.is_null())
+            .add(usr_badges::Column::ExpiresAt.gt(now)));
+    if let Some(id) = usr_id {
+        query = query.filter(usr_badges::Column::To.eq(id));
+    }
+    let rows: Vec<(u32, i64)> = query
+        .group_by(usr_badges::Column::To)
+        .into_tuple()
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Count usr_badges error: {}", e)))?;
+    Ok(rows.into_iter().map(|(to, cnt)| (to, cnt as u32)).collect())
+}
+
+/// スコアリングの前提となる値（求人の max_badges と VDR の total_badges）を取得する
+async fn load_ranking_context(
+    conn: &DatabaseConnection,
+    ids: &JwtIDs,
+    job: &jobs::Model,
+) -> Result<RankingContext, ApiError> {
+    let vdr = find_vdr_for_rt(conn, ids.apx_id, ids.vdr_id).await?;
+    Ok(RankingContext { max_badges: job.max_badges, total_badges: vdr.total_badges })
+}
+
+/// 現在のステータスを列挙型に変換する
+fn current_status(model: &matches::Model) -> Result<MatchStatus, ApiError> {
+    MatchStatus::from_u8(model.status).ok_or_else(|| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_UNEXPECTED, format!("Unknown match status: {}", model.status)))
+}
+
+/// 遷移の可否を検証する
+fn check_transition(current: MatchStatus, next: MatchStatus) -> Result<(), ApiError> {
+    if !current.can_transition_to(next) {
+        log::debug!("<MatchBl> check_transition: Illegal transition {:?} -> {:?}", current, next);
+        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, format!("Illegal status transition: {} -> {}.", current as u8, next as u8)));
+    }
+    Ok(())
+}
+
+/// ステータス履歴を1件追記する
+async fn insert_status_history(
+    tx: &DatabaseTransaction,
+    model: &matches::Model,
+    status: MatchStatus,
+    is_tmp: bool,
+) -> Result<(), ApiError> {
+    let history = match_statuses::ActiveModel {
+        job_id: Set(model.job_id),
+        match_id: Set(model.id as u32),
+        from: Set(model.from),
+        to: Set(model.to),
+        status: Set(status as u8),
+        is_tmp: Set(is_tmp as i8),
+        apx_id: Set(model.apx_id),
+        vdr_id: Set(model.vdr_id),
+        ..Default::default()
+    };
+    history.insert(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Insert match status error: {}", e)))?;
+    Ok(())
+}
+
+/// トランザクション内でアプローチを行ロックして再取得し、遷移を検証する
+/// トランザクション開始前に取得した値からステータスが変わっている場合は、同時に別の遷移が行われたとみなし 409 とする
+async fn lock_match_in_tx(
+    tx: &DatabaseTransaction,
+    model: &matches::Model,
+    next: MatchStatus,
+) -> Result<matches::Model, ApiError> {
+    let locked = matches::Entity::find_by_id(model.id)
+        .lock_exclusive()
+        .one(tx)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock match error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Match not found."))?;
+    if locked.status != model.status {
+        log::debug!("<MatchBl> lock_match_in_tx: Status changed concurrently. match: {}, {} -> {}", locked.id, model.status, locked.status);
+        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Match status has been changed."));
+    }
+    let current = current_status(&locked)?;
+    if current != next {
+        check_transition(current, next)?;
+    }
+    Ok(locked)
+}
+
+/// トランザクション内でアプローチのステータスを更新し、履歴を追記する
+async fn update_status_in_tx(
+    tx: &DatabaseTransaction,
+    model: matches::Model,
+    status: MatchStatus,
+    is_tmp: bool,
+) -> Result<(), ApiError> {
+    insert_status_history(tx, &model, status, is_tmp).await?;
+    let mut active: matches::ActiveModel = model.into_active_model();
+    active.status = Set(status as u8);
+    active.update(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update match error: {}", e)))?;
+    Ok(())
+}
+
+/// アプローチのステータスを更新し、同一トランザクションで履歴を追記する
+/// 同じステータスのまま仮予定を確定する場合（面談設定 → 面談設定）は、最新の履歴が仮予定のままであることも再確認する
+async fn apply_status(
+    conn: &DatabaseConnection,
+    model: matches::Model,
+    status: MatchStatus,
+    is_tmp: bool,
+) -> Result<(), ApiError> {
+    conn.transaction::<_, (), ApiError>(|tx| {
+        Box::pin(async move {
+            let locked = lock_match_in_tx(tx, &model, status).await?;
+            if current_status(&locked)? == status {
+                let still_tmp = find_latest_status(tx, locked.id as u32).await?.is_some_and(|h| h.is_tmp != 0);
+                if !still_tmp {
+                    return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Schedule is already confirmed."));
+                }
+            }
+            update_status_in_tx(tx, locked, status, is_tmp).await
+        })
+    }).await?;
+    Ok(())
+}
+
+/// 採用成功（4）への遷移を行い、同一トランザクションで就業情報（works）を作成する
+/// 求人の募集人数（jobs.max）と、個人の同時就業数（VDR の max_works、0 は上限なし）を超える場合は拒否する
+/// 同時実行による重複採用・超過を防ぐため、アプローチ・求人・個人の行をロックした上で検証する
+async fn apply_hire(
+    conn: &DatabaseConnection,
+    model: matches::Model,
+    max_works: u32,
+) -> Result<(), ApiError> {
+    conn.transaction::<_, (), ApiError>(|tx| {
+        Box::pin(async move {
+            // --------------------------------
+            // 1. アプローチをロックし、面談実行（3）のままであることを再確認
+            // --------------------------------
+            let model = lock_match_in_tx(tx, &model, MatchStatus::Hired).await?;
+            // --------------------------------
+            // 2. 求人をロックして募集人数を検証
+            // --------------------------------
+            let job = jobs::Entity::find_by_id(model.job_id as i32)
+                .lock_exclusive()
+                .one(tx)
+                .await
+                .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock job error: {}", e)))?
+                .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Job not found."))?;
+            if job.filled >= job.max {
+                log::debug!("<MatchBl> apply_hire: Job {} is full. filled: {}, max: {}", job.id, job.filled, job.max);
+                return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Job is already filled."));
+            }
+            // --------------------------------
+            // 3. 個人をロックして同時就業数を検証
+            // --------------------------------
+            usrs::Entity::find_by_id(model.to as i32)
+                .lock_exclusive()
+                .one(tx)
+                .await
+                .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock usr error: {}", e)))?
+                .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "USR not found."))?;
+            if max_works > 0 {
+                let active_works = works::Entity::find()
+                    .filter(works::Column::ApxId.eq(model.apx_id))
+                    .filter(works::Column::VdrId.eq(model.vdr_id))
+                    .filter(works::Column::To.eq(model.to))
+                    .filter(works::Column::RealWorkEndAt.is_null())
+                    .count(tx)
+                    .await
+                    .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Count works error: {}", e)))?;
+                if active_works >= max_works as u64 {
+                    log::debug!("<MatchBl> apply_hire: USR {} reached max_works. active: {}, max: {}", model.to, active_works, max_works);
+                    return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "USR has reached max works."));
+                }
+            }
+            // --------------------------------
+            // 4. 就業情報の作成と採用済み人数の更新
+            // --------------------------------
+            let work = works::ActiveModel {
+                job_id: Set(model.job_id),
+                match_id: Set(model.id as u32),
+                from: Set(model.from),
+                to: Set(model.to),
+                work_bgn_at: Set(job.work_bgn_at),
+                work_end_at: Set(job.work_end_at),
+                apx_id: Set(model.apx_id),
+                vdr_id: Set(model.vdr_id),
+                ..Default::default()
+            };
+            let work = work.insert(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Insert work error: {}", e)))?;
+            log::debug!("<MatchBl> apply_hire: Work created. ID: {}", work.id);
+            let filled = job.filled + 1;
+            let mut active_job: jobs::ActiveModel = job.into_active_model();
+            active_job.filled = Set(filled);
+            active_job.update(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update job error: {}", e)))?;
+            // --------------------------------
+            // 5. ステータスの更新と履歴の追記
+            // --------------------------------
+            update_status_in_tx(tx, model, MatchStatus::Hired, false).await
+        })
+    }).await?;
+    Ok(())
+}
+
+// ============================================================
+// Search
+// ============================================================
+pub async fn search_matches(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: SearchMatchesReq,
+) -> Result<SearchMatchesRes, ApiError> {
+    // --------------------------------
+    // 1. クエリの基本形を取得
+    // --------------------------------
+    log::debug!("<MatchBl> search_matches: Constructing base query.");
+    let mut query = find_matches_base(ju, ids).await?;
+    // --------------------------------
+    // 2. 検索条件（完全一致）
+    // --------------------------------
+    if let Some(job_id) = req.job_id {
+        log::debug!("<MatchBl> search_matches: Filter by job_id: {}", job_id);
+        query = query.filter(matches::Column::JobId.eq(job_id));
+    }
+    if let Some(from) = req.from {
+        log::debug!("<MatchBl> search_matches: Filter by from: {}", from);
+        query = query.filter(matches::Column::From.eq(from));
+    }
+    if let Some(to) = req.to {
+        log::debug!("<MatchBl> search_matches: Filter by to: {}", to);
+        query = query.filter(matches::Column::To.eq(to));
+    }
+    if let Some(status) = req.status {
+        log::debug!("<MatchBl> search_matches: Filter by status: {}", status);
+        query = query.filter(matches::Column::Status.eq(status));
+    }
+    // --------------------------------
+    // 3. データの取得
+    // --------------------------------
+    log::debug!("<MatchBl> search_matches: Fetching records. limit: {}, offset: {}", req.limit, req.offset);
+    let models = query
+        .order_by_desc(matches::Column::Id)
+        .offset(req.offset as u64)
+        .limit(req.limit as u64)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Search query error: {}", e)))?;
+    log::debug!("<MatchBl> search_matches: Found {} records.", models.len());
+    // --------------------------------
+    // 4. 最終レスポンス
+    // --------------------------------
+    let matches = models.into_iter().map(SearchMatchesResItem::from).collect();
+    Ok(SearchMatchesRes { matches })
+}
+
+// ============================================================
+// Get
+// ============================================================
+pub async fn get_match(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    match_id: u32,
+) -> Result<GetMatchRes, ApiError> {
+    log::debug!("<MatchBl> get_match: Fetching match: {}", match_id);
+    let model = find_matches_base(ju, ids).await?
+        .filter(matches::Column::Id.eq(match_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch match error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Match not found."))?;
+    log::debug!("<MatchBl> get_match: Fetching histories.");
+    let histories = match_statuses::Entity::find()
+        .filter(match_statuses::Column::MatchId.eq(match_id))
+        .order_by_asc(match_statuses::Column::Id)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch match statuses error: {}", e)))?;
+    Ok(GetMatchRes::new(model, histories))
+}
+
+// ============================================================
+// Create
+// ============================================================
+pub async fn create_match(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: CreateMatchReq,
+) -> Result<CreateMatchRes, ApiError> {
+    // --------------------------------
+    // 1. 求人の取得（USR は自身が発行した求人のみ）
+    // --------------------------------
+    log::debug!("<MatchBl> create_match: Fetching job: {}", req.job_id);
+    let job = find_job_for_match(conn, ju, ids, req.job_id).await?;
+    // --------------------------------
+    // 2. 求人が募集中であることを確認
+    // --------------------------------
+    let now = Local::now().naive_local();
+    let is_opened = job.open_at.is_none_or(|v| v <= now) && job.close_at.is_none_or(|v| now <= v);
+    if !is_opened {
+        return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "Job is not open."));
+    }
+    if job.filled >= job.max {
+        return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "Job is already filled."));
+    }
+    // --------------------------------
+    // 3. 送信先が同一 VDR 内の個人であることを確認
+    // --------------------------------
+    find_usr_by_type_for_rt(conn, ids.apx_id, ids.vdr_id, req.to, UsrType::Indi).await?;
+    // --------------------------------
+    // 4. アプローチ時点のバッジ数と優先度スコアを記録
+    // --------------------------------
+    let reason = match req.match_reason {
+        Some(v) => MatchReason::from_u8(v).ok_or_else(|| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "Invalid match_reason."))?,
+        None => MatchReason::Manual,
+    };
+    let badge_count = load_badge_counts(conn, ids, Some(req.to)).await?.get(&req.to).copied().unwrap_or(0);
+    let priority_score = match ranking::strategy_for(reason) {
+        Some(strategy) => {
+            let ctx = load_ranking_context(conn, ids, &job).await?;
+            strategy.score(&Candidate { usr_id: req.to, badge_count }, &ctx)
+        }
+        None => Decimal::ZERO,
+    };
+    log::debug!("<MatchBl> create_match: Snapshot. reason: {}, badge_count: {}, score: {}", reason.as_str(), badge_count, priority_score);
+    // --------------------------------
+    // 5. 保存（アプローチ本体と初回履歴）
+    //    求人の行をロックした上で進行中のアプローチの重複を確認するため、同時実行による重複作成は起きない
+    // --------------------------------
+    log::debug!("<MatchBl> create_match: Starting insertion transaction.");
+    let res = conn.transaction::<_, matches::Model, ApiError>(|tx| {
+        Box::pin(async move {
+            jobs::Entity::find_by_id(job.id)
+                .lock_exclusive()
+                .one(tx)
+                .await
+                .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock job error: {}", e)))?
+                .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Job not found."))?;
+            let exists = matches::Entity::find()
+                .filter(matches::Column::JobId.eq(req.job_id))
+                .filter(matches::Column::To.eq(req.to))
+                .filter(matches::Column::Status.ne(MatchStatus::Declined as u8))
+                .one(tx)
+                .await
+                .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch match error: {}", e)))?;
+            if exists.is_some() {
+                log::debug!("<MatchBl> create_match: Duplicate match for job: {}, to: {}", req.job_id, req.to);
+                return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Match already exists."));
+            }
+            let active = matches::ActiveModel {
+                job_id: Set(job.id as u32),
+                from: Set(job.corp_id),
+                to: Set(req.to),
+                status: Set(MatchStatus::Approach as u8),
+                priority_score: Set(priority_score),
+                badge_count: Set(badge_count),
+                match_reason: Set(reason as u8),
+                apx_id: Set(job.apx_id),
+                vdr_id: Set(job.vdr_id),
+                ..Default::default()
+            };
+            let model = active.insert(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Insert match error: {}", e)))?;
+            insert_status_history(tx, &model, MatchStatus::Approach, false).await?;
+            Ok(model)
+        })
+    }).await?;
+    log::debug!("<MatchBl> create_match: Success. ID: {}", res.id);
+    Ok(CreateMatchRes { id: res.id as u32 })
+}
+
+// ============================================================
+// Accept
+// ============================================================
+pub async fn accept_match(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    match_id: u32,
+    req: AcceptMatchReq,
+) -> Result<AcceptMatchRes, ApiError> {
+    log::debug!("<MatchBl> accept_match: Fetching target match: {}", match_id);
+    let model = find_match_for_update(conn, ju, ids, match_id, MatchSide::Indi).await?;
+    check_transition(current_status(&model)?, MatchStatus::InterviewSet)?;
+    log::debug!("<MatchBl> accept_match: Applying status. is_tmp: {}", req.is_tmp);
+    apply_status(conn, model, MatchStatus::InterviewSet, req.is_tmp).await?;
+    Ok(AcceptMatchRes { id: match_id })
+}
+
+// ============================================================
+// Decline
+// ============================================================
+pub async fn decline_match(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    match_id: u32,
+) -> Result<DeclineMatchRes, ApiError> {
+    log::debug!("<MatchBl> decline_match: Fetching target match: {}", match_id);
+    let model = find_match_for_update(conn, ju, ids, match_id, MatchSide::Indi).await?;
+    check_transition(current_status(&model)?, MatchStatus::Declined)?;
+    apply_status(conn, model, MatchStatus::Declined, false).await?;
+    Ok(DeclineMatchRes { id: match_id })
+}
+
+// ============================================================
+// Confirm
+// ============================================================
+pub async fn confirm_match(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    match_id: u32,
+) -> Result<ConfirmMatchRes, ApiError> {
+    // --------------------------------
+    // 1. 対象の取得と状態確認（面談設定かつ仮予定であること）
+    // --------------------------------
+    log::debug!("<MatchBl> confirm_match: Fetching target match: {}", match_id);
+    let model = find_match_for_update(conn, ju, ids, match_id, MatchSide::Indi).await?;
+    if current_status(&model)? != MatchStatus::InterviewSet {
+        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Only interview-set match can be confirmed."));
+    }
+    let is_tmp = find_latest_status(conn, match_id).await?.is_some_and(|h| h.is_tmp != 0);
+    if !is_tmp {
+        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Schedule is already confirmed."));
+    }
+    // --------------------------------
+    // 2. 確定として履歴を追記
+    // --------------------------------
+    log::debug!("<MatchBl> confirm_match: Confirming schedule.");
+    apply_status(conn, model, MatchStatus::InterviewSet, false).await?;
+    Ok(ConfirmMatchRes { id: match_id })
+}
+
+// ============================================================
+// Update Status
+// ============================================================
+pub async fn update_match_status(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    match_id: u32,
+    req: UpdateMatchStatusReq,
+) -> Result<UpdateMatchStatusRes, ApiError> {
+    // --------------------------------
+    // 1. 対象の取得と遷移の検証
+    // --------------------------------
+    log::debug!("<MatchBl> update_match_status: Fetching target match: {}", match_id);
+    let model = find_match_for_update(conn, ju, ids, match_id, MatchSide::Corp).await?;
+    let next = MatchStatus::from_u8(req.status).ok_or_else(|| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "Invalid status."))?;
+    check_transition(current_status(&model)?, next)?;
+    // --------------------------------
+    // 2. 面談実行は、仮予定が確定されている場合のみ
+    // --------------------------------
+    if next == MatchStatus::InterviewDone {
+        let is_tmp = find_latest_status(conn, match_id).await?.is_none_or(|h| h.is_tmp != 0);
+        if is_tmp {
+            return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Schedule is not confirmed yet."));
+        }
+    }
+    // --------------------------------
+    // 3. 保存
+    // --------------------------------
+    log::debug!("<MatchBl> update_match_status: Applying status: {:?}", next);
+    if next == MatchStatus::Hired {
+        let vdr = find_vdr_for_rt(conn, model.apx_id, model.vdr_id).await?;
+        apply_hire(conn, model, vdr.max_works).await?;
+    } else {
+        apply_status(conn, model, next, false).await?;
+    }
+    Ok(UpdateMatchStatusRes { id: match_id })
+}
+
+// ============================================================
+// Candidates
+// ============================================================
+pub async fn search_match_candidates(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: SearchMatchCandidatesReq,
+) -> Result<SearchMatchCandidatesRes, ApiError> {
+    // --------------------------------
+    // 1. 求人と戦略の決定
+    // --------------------------------
+    log::debug!("<MatchBl> search_match_candidates: Fetching job: {}", req.job_id);
+    let job = find_job_for_match(conn, ju, ids, req.job_id).await?;
+    let reason = MatchReason::from_u8(req.match_reason).ok_or_else(|| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "Invalid match_reason."))?;
+    let strategy = ranking::strategy_for(reason).ok_or_else(|| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "match_reason has no ranking strategy."))?;
+    // --------------------------------
+    // 2. 対象となる個人（有効期間内）を取得
+    // --------------------------------
+    let now = Local::now().naive_local();
+    let indis = usrs::Entity::find()
+        .filter(usrs::Column::ApxId.eq(ids.apx_id))
+        .filter(usrs::Column::VdrId.eq(ids.vdr_id))
+        .filter(usrs::Column::Type.eq(UsrType::Indi as u8))
+        .filter(usrs::Column::BgnAt.lte(now))
+        .filter(usrs::Column::EndAt.gte(now))
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch usrs error: {}", e)))?;
+    // --------------------------------
+    // 3. 進行中のアプローチがある個人を除外
+    // --------------------------------
+    let approached: HashSet<u32> = matches::Entity::find()
+        .select_only()
+        .column(matches::Column::To)
+        .filter(matches::Column::JobId.eq(req.job_id))
+        .filter(matches::Column::Status.ne(MatchStatus::Declined as u8))
+        .into_tuple::<u32>()
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch matches error: {}", e)))?
+        .into_iter()
+        .collect();
+    let names: HashMap<u32, String> = indis
+        .into_iter()
+        .filter(|u| !approached.contains(&(u.id as u32)))
+        .map(|u| (u.id as u32, u.name))
+        .collect();
+    log::debug!("<MatchBl> search_match_candidates: {} eligible individuals.", names.len());
+    // --------------------------------
+    // 4. スコアリングと並べ替え
+    // --------------------------------
+    let counts = load_badge_counts(conn, ids, None).await?;
+    let ctx = load_ranking_context(conn, ids, &job).await?;
+    let candidates = names
+        .keys()
+        .map(|id| Candidate { usr_id: *id, badge_count: counts.get(id).copied().unwrap_or(0) })
+        .collect();
+    let ranked = ranking::rank(strategy.as_ref(), candidates, &ctx);
+    // --------------------------------
+    // 5. 最終レスポンス
+    // --------------------------------
+    let candidates = ranked
+        .into_iter()
+        .take(req.limit as usize)
+        .map(|r| SearchMatchCandidatesResItem {
+            usr_id: r.usr_id,
+            name: names.get(&r.usr_id).cloned().unwrap_or_default(),
+            badge_count: r.badge_count,
+            priority_score: r.score.to_f64().unwrap_or(0.0),
+            match_reason: r.reason as u8,
+        })
+        .collect();
+    Ok(SearchMatchCandidatesRes { candidates })
+}
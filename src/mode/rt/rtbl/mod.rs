@@ -1,2 +1,17 @@
 pub mod usrs_bl;
 pub mod cryptos_bl;
+pub mod jobs_bl;
+pub mod matches_bl;
+pub mod works_bl;
+pub mod belongs_bl;
+pub mod badges_bl;
+pub mod usr_badges_bl;
+pub mod points_bl;
+pub mod payments_bl;
+pub mod flushes_bl;
+pub mod bank_accounts_bl;
+pub mod withdrawals_bl;
+pub mod journals_bl;
+pub mod pools_bl;
+pub mod point_adjustments_bl;
+pub mod collusion_bl;
\ No newline at end of file
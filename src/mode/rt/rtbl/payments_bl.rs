@@ -0,0 +1,292 @@
+use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, ColumnTrait, QuerySelect, QueryOrder, Select, ActiveModelTrait, IntoActiveModel, Set, TransactionTrait};
+use crate::entities::{payments, pools};
+use crate::utils::jwt::{JwtUsr, JwtIDs, JwtRole};
+use crate::mode::rt::rtreq::payments_req::{SearchPaymentsReq, CreatePaymentReq, RefundPaymentReq};
+use crate::mode::rt::rtres::payments_res::{SearchPaymentsRes, SearchPaymentsResItem, GetPaymentRes, CreatePaymentRes, RefundPaymentRes};
+use crate::mode::rt::rtres::errs_res::ApiError;
+use crate::mode::rt::rtutils::usrs_for_rt::find_usr_by_type_for_rt;
+use crate::mode::rt::rtutils::config_for_rt::RtConfig;
+use axum::http::StatusCode;
+use crate::mode::rt::rterr::rterr;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use crate::enums::usrtype::UsrType;
+use crate::enums::paymenttype::PaymentType;
+
+// ============================================================
+// Private Helper for Search and Get
+// ============================================================
+/// 権限に基づいた共通のクエリベースを作成する
+/// USR は自身が支払った入金（corp_id = 自分）のみ参照できる
+async fn find_payments_base(
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+) -> Result<Select<payments::Entity>, ApiError> {
+    let query = payments::Entity::find();
+    match ju.role() {
+        JwtRole::BD => {
+            log::debug!("<PaymentBl> find_payments_base: BD role. No filtering.");
+            Ok(query)
+        }
+        JwtRole::APX => {
+            log::debug!("<PaymentBl> find_payments_base: APX role. Filter apx_id: {}", ids.apx_id);
+            Ok(query.filter(payments::Column::ApxId.eq(ids.apx_id)))
+        }
+        JwtRole::VDR => {
+            log::debug!("<PaymentBl> find_payments_base: VDR role. Filter apx_id: {}, vdr_id: {}", ids.apx_id, ids.vdr_id);
+            Ok(query
+                .filter(payments::Column::ApxId.eq(ids.apx_id))
+                .filter(payments::Column::VdrId.eq(ids.vdr_id)))
+        }
+        JwtRole::USR => {
+            log::debug!("<PaymentBl> find_payments_base: USR role. Filter apx_id: {}, vdr_id: {}, corp_id: {}", ids.apx_id, ids.vdr_id, ids.usr_id);
+            Ok(query
+                .filter(payments::Column::ApxId.eq(ids.apx_id))
+                .filter(payments::Column::VdrId.eq(ids.vdr_id))
+                .filter(payments::Column::CorpId.eq(ids.usr_id)))
+        }
+    }
+}
+
+/// 運営費控除分を算出する（1 円未満は切り捨て）
+fn calc_fee(amount: u32, rate: Decimal) -> u32 {
+    if rate <= Decimal::ZERO {
+        return 0;
+    }
+    (Decimal::from(amount) * rate).floor().to_u32().unwrap_or(amount).min(amount)
+}
+
+// ============================================================
+// Search
+// ============================================================
+pub async fn search_payments(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: SearchPaymentsReq,
+) -> Result<SearchPaymentsRes, ApiError> {
+    // --------------------------------
+    // 1. クエリの基本形を取得
+    // --------------------------------
+    log::debug!("<PaymentBl> search_payments: Constructing base query.");
+    let mut query = find_payments_base(ju, ids).await?;
+    // --------------------------------
+    // 2. 検索条件（完全一致）
+    // --------------------------------
+    if let Some(corp_id) = req.corp_id {
+        log::debug!("<PaymentBl> search_payments: Filter by corp_id: {}", corp_id);
+        query = query.filter(payments::Column::CorpId.eq(corp_id));
+    }
+    if let Some(t) = req.r#type {
+        log::debug!("<PaymentBl> search_payments: Filter by type: {}", t);
+        query = query.filter(payments::Column::Type.eq(t));
+    }
+    // --------------------------------
+    // 3. データの取得
+    // --------------------------------
+    log::debug!("<PaymentBl> search_payments: Fetching records. limit: {}, offset: {}", req.limit, req.offset);
+    let models = query
+        .order_by_desc(payments::Column::Id)
+        .offset(req.offset as u64)
+        .limit(req.limit as u64)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Search query error: {}", e)))?;
+    log::debug!("<PaymentBl> search_payments: Found {} records.", models.len());
+    // --------------------------------
+    // 4. 最終レスポンス
+    // --------------------------------
+    let payments = models.into_iter().map(SearchPaymentsResItem::from).collect();
+    Ok(SearchPaymentsRes { payments })
+}
+
+// ============================================================
+// Get
+// ============================================================
+pub async fn get_payment(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    payment_id: u32,
+) -> Result<GetPaymentRes, ApiError> {
+    log::debug!("<PaymentBl> get_payment: Fetching payment: {}", payment_id);
+    let model = find_payments_base(ju, ids).await?
+        .filter(payments::Column::Id.eq(payment_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch payment error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Payment not found."))?;
+    Ok(GetPaymentRes::from(model))
+}
+
+// ============================================================
+// Create
+// ============================================================
+pub async fn create_payment(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    cfg: &RtConfig,
+    req: CreatePaymentReq,
+) -> Result<CreatePaymentRes, ApiError> {
+    // --------------------------------
+    // 1. ロールに基づく支払法人の決定
+    // --------------------------------
+    log::debug!("<PaymentBl> create_payment: Role-based validation for {:?}.", ju.role());
+    let corp_id = match ju.role() {
+        JwtRole::VDR => req.corp_id.ok_or_else(|| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "VDR requires corp_id."))?,
+        JwtRole::USR => {
+            if req.corp_id.is_some() {
+                return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "USR cannot set corp_id."));
+            }
+            ids.usr_id
+        }
+        _ => {
+            return Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Only VDR or corporate USR can create payments."));
+        }
+    };
+    find_usr_by_type_for_rt(conn, ids.apx_id, ids.vdr_id, corp_id, UsrType::Corp).await.map_err(|e| {
+        if ju.role() == JwtRole::USR && e.status == StatusCode::NOT_FOUND.as_u16() {
+            ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Only corporate USR can create payments.")
+        } else {
+            e
+        }
+    })?;
+    let payment_type = PaymentType::from_u8(req.r#type)
+        .ok_or_else(|| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, format!("Invalid type: {}", req.r#type)))?;
+    // --------------------------------
+    // 2. 運営費控除分とプール流入額の算出
+    // --------------------------------
+    let fee = calc_fee(req.amount, cfg.payment_fee_rate);
+    let net = req.amount - fee;
+    log::debug!("<PaymentBl> create_payment: amount: {}, fee: {}, net: {}, rate: {}", req.amount, fee, net, cfg.payment_fee_rate);
+    // --------------------------------
+    // 3. 入金の記録とプールの更新（プール行をロックして更新の消失を防ぐ）
+    // --------------------------------
+    let apx_id = ids.apx_id;
+    let vdr_id = ids.vdr_id;
+    let (id, remain) = conn.transaction::<_, (i32, u32), ApiError>(|tx| {
+        Box::pin(async move {
+            let pool = pools::Entity::find()
+                .filter(pools::Column::ApxId.eq(apx_id))
+                .filter(pools::Column::VdrId.eq(vdr_id))
+                .lock_exclusive()
+                .one(tx)
+                .await
+                .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock pool error: {}", e)))?
+                .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Pool not found."))?;
+            let remain = pool.remain.checked_add(net)
+                .ok_or_else(|| ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Pool remain overflow."))?;
+            let total_in = pool.total_in.checked_add(net)
+                .ok_or_else(|| ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Pool total_in overflow."))?;
+            let payment = payments::ActiveModel {
+                corp_id: Set(corp_id),
+                r#type: Set(payment_type as u8),
+                amount: Set(req.amount),
+                fee: Set(fee),
+                net: Set(net),
+                note: Set(req.note),
+                apx_id: Set(apx_id),
+                vdr_id: Set(vdr_id),
+                ..Default::default()
+            };
+            let payment = payment.insert(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Insert payment error: {}", e)))?;
+            let mut active_pool: pools::ActiveModel = pool.into_active_model();
+            active_pool.remain = Set(remain);
+            active_pool.total_in = Set(total_in);
+            active_pool.update(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update pool error: {}", e)))?;
+            Ok((payment.id, remain))
+        })
+    }).await?;
+    log::debug!("<PaymentBl> create_payment: Success. ID: {}, pool remain: {}", id, remain);
+    Ok(CreatePaymentRes { id: id as u32, fee, net })
+}
+
+// ============================================================
+// Refund
+// ============================================================
+pub async fn refund_payment(
+    conn: &DatabaseConnection,
+    ids: &JwtIDs,
+    payment_id: u32,
+    req: RefundPaymentReq,
+) -> Result<RefundPaymentRes, ApiError> {
+    log::debug!("<PaymentBl> refund_payment: Refunding payment: {}", payment_id);
+    let apx_id = ids.apx_id;
+    let vdr_id = ids.vdr_id;
+    let res = conn.transaction::<_, RefundPaymentRes, ApiError>(|tx| {
+        Box::pin(async move {
+            // --------------------------------
+            // 1. プールと元の入金をロック
+            // --------------------------------
+            let pool = pools::Entity::find()
+                .filter(pools::Column::ApxId.eq(apx_id))
+                .filter(pools::Column::VdrId.eq(vdr_id))
+                .lock_exclusive()
+                .one(tx)
+                .await
+                .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock pool error: {}", e)))?
+                .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Pool not found."))?;
+            let original = payments::Entity::find()
+                .filter(payments::Column::Id.eq(payment_id))
+                .filter(payments::Column::ApxId.eq(apx_id))
+                .filter(payments::Column::VdrId.eq(vdr_id))
+                .lock_exclusive()
+                .one(tx)
+                .await
+                .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock payment error: {}", e)))?
+                .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Payment not found."))?;
+            // --------------------------------
+            // 2. 返金できることを確認
+            // --------------------------------
+            if original.reversal_of.is_some() {
+                return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Refund payment cannot be refunded."));
+            }
+            let refunded = payments::Entity::find()
+                .filter(payments::Column::ReversalOf.eq(payment_id))
+                .one(tx)
+                .await
+                .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch refund error: {}", e)))?;
+            if refunded.is_some() {
+                return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Payment is already refunded."));
+            }
+            if pool.remain < original.net {
+                log::debug!("<PaymentBl> refund_payment: Pool remain {} is below refund net {}.", pool.remain, original.net);
+                return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Pool has already been flushed below the refund amount."));
+            }
+            let remain = pool.remain - original.net;
+            let total_in = pool.total_in.checked_sub(original.net)
+                .ok_or_else(|| ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Pool total_in underflow."))?;
+            // --------------------------------
+            // 3. 返金（取消）の入金を記録し、プールから差し引く
+            // --------------------------------
+            let refund = payments::ActiveModel {
+                corp_id: Set(original.corp_id),
+                r#type: Set(original.r#type),
+                amount: Set(original.amount),
+                fee: Set(original.fee),
+                net: Set(original.net),
+                note: Set(req.note),
+                reversal_of: Set(Some(payment_id)),
+                apx_id: Set(apx_id),
+                vdr_id: Set(vdr_id),
+                ..Default::default()
+            };
+            let refund = refund.insert(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Insert refund error: {}", e)))?;
+            let mut active_pool: pools::ActiveModel = pool.into_active_model();
+            active_pool.remain = Set(remain);
+            active_pool.total_in = Set(total_in);
+            active_pool.update(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update pool error: {}", e)))?;
+            Ok(RefundPaymentRes {
+                id: refund.id as u32,
+                reversal_of: payment_id,
+                amount: refund.amount,
+                fee: refund.fee,
+                net: refund.net,
+                remain,
+            })
+        })
+    }).await?;
+    log::debug!("<PaymentBl> refund_payment: Success. ID: {}, pool remain: {}", res.id, res.remain);
+    Ok(res)
+}
@@ -0,0 +1,318 @@
+use chrono::Local;
+use sea_orm::{DatabaseConnection, DatabaseTransaction, EntityTrait, QueryFilter, ColumnTrait, QuerySelect, QueryOrder, Select, ActiveModelTrait, IntoActiveModel, Set, TransactionTrait};
+use sea_orm::sea_query::Expr;
+use crate::entities::{usrs, point_adjustments};
+use crate::utils::jwt::{JwtUsr, JwtIDs, JwtRole};
+use crate::mode::rt::rtreq::point_adjustments_req::{SearchPointAdjustmentsReq, CreatePointAdjustmentReq};
+use crate::mode::rt::rtres::point_adjustments_res::{SearchPointAdjustmentsRes, SearchPointAdjustmentsResItem, GetPointAdjustmentRes, CreatePointAdjustmentRes, ReviewPointAdjustmentRes};
+use crate::mode::rt::rtres::errs_res::ApiError;
+use axum::http::StatusCode;
+use crate::mode::rt::rterr::rterr;
+use crate::enums::usrtype::UsrType;
+use crate::enums::pointadjustreason::PointAdjustReason;
+use crate::enums::pointadjuststatus::PointAdjustStatus;
+
+// ============================================================
+// Private Helper for Search and Get
+// ============================================================
+/// 権限に基づいた共通のクエリベースを作成する
+/// USR は自身に対する調整のみ参照できる
+async fn find_point_adjustments_base(
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+) -> Result<Select<point_adjustments::Entity>, ApiError> {
+    let query = point_adjustments::Entity::find();
+    match ju.role() {
+        JwtRole::BD => {
+            log::debug!("<PointAdjustmentBl> find_point_adjustments_base: BD role. No filtering.");
+            Ok(query)
+        }
+        JwtRole::APX => {
+            log::debug!("<PointAdjustmentBl> find_point_adjustments_base: APX role. Filter apx_id: {}", ids.apx_id);
+            Ok(query.filter(point_adjustments::Column::ApxId.eq(ids.apx_id)))
+        }
+        JwtRole::VDR => {
+            log::debug!("<PointAdjustmentBl> find_point_adjustments_base: VDR role. Filter apx_id: {}, vdr_id: {}", ids.apx_id, ids.vdr_id);
+            Ok(query
+                .filter(point_adjustments::Column::ApxId.eq(ids.apx_id))
+                .filter(point_adjustments::Column::VdrId.eq(ids.vdr_id)))
+        }
+        JwtRole::USR => {
+            log::debug!("<PointAdjustmentBl> find_point_adjustments_base: USR role. Filter apx_id: {}, vdr_id: {}, usr_id: {}", ids.apx_id, ids.vdr_id, ids.usr_id);
+            Ok(query
+                .filter(point_adjustments::Column::ApxId.eq(ids.apx_id))
+                .filter(point_adjustments::Column::VdrId.eq(ids.vdr_id))
+                .filter(point_adjustments::Column::UsrId.eq(ids.usr_id)))
+        }
+    }
+}
+
+// ============================================================
+// Search
+// ============================================================
+pub async fn search_point_adjustments(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: SearchPointAdjustmentsReq,
+) -> Result<SearchPointAdjustmentsRes, ApiError> {
+    // --------------------------------
+    // 1. クエリの基本形を取得
+    // --------------------------------
+    log::debug!("<PointAdjustmentBl> search_point_adjustments: Constructing base query.");
+    let mut query = find_point_adjustments_base(ju, ids).await?;
+    // --------------------------------
+    // 2. 検索条件（完全一致）
+    // --------------------------------
+    if let Some(usr_id) = req.usr_id {
+        log::debug!("<PointAdjustmentBl> search_point_adjustments: Filter by usr_id: {}", usr_id);
+        query = query.filter(point_adjustments::Column::UsrId.eq(usr_id));
+    }
+    if let Some(reason) = req.reason {
+        log::debug!("<PointAdjustmentBl> search_point_adjustments: Filter by reason: {}", reason);
+        query = query.filter(point_adjustments::Column::Reason.eq(reason));
+    }
+    if let Some(status) = req.status {
+        log::debug!("<PointAdjustmentBl> search_point_adjustments: Filter by status: {}", status);
+        query = query.filter(point_adjustments::Column::Status.eq(status));
+    }
+    // --------------------------------
+    // 3. データの取得
+    // --------------------------------
+    log::debug!("<PointAdjustmentBl> search_point_adjustments: Fetching records. limit: {}, offset: {}", req.limit, req.offset);
+    let models = query
+        .order_by_desc(point_adjustments::Column::Id)
+        .offset(req.offset as u64)
+        .limit(req.limit as u64)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Search query error: {}", e)))?;
+    log::debug!("<PointAdjustmentBl> search_point_adjustments: Found {} records.", models.len());
+    // --------------------------------
+    // 4. 最終レスポンス
+    // --------------------------------
+    let point_adjustments = models.into_iter().map(SearchPointAdjustmentsResItem::from).collect();
+    Ok(SearchPointAdjustmentsRes { point_adjustments })
+}
+
+// ============================================================
+// Get
+// ============================================================
+pub async fn get_point_adjustment(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    point_adjustment_id: u32,
+) -> Result<GetPointAdjustmentRes, ApiError> {
+    log::debug!("<PointAdjustmentBl> get_point_adjustment: Fetching point adjustment: {}", point_adjustment_id);
+    let model = find_point_adjustments_base(ju, ids).await?
+        .filter(point_adjustments::Column::Id.eq(point_adjustment_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch point adjustment error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Point adjustment not found."))?;
+    Ok(GetPointAdjustmentRes::from(model))
+}
+
+// ============================================================
+// Private Helper for Create and Review
+// ============================================================
+/// 操作者（VDR または スタッフ token）の UsrID とスタッフ ID を返す
+/// スタッフは自分自身に対する調整を申請・承認できない
+fn ensure_operator(ju: &JwtUsr, ids: &JwtIDs, usr_id: u32) -> Result<(u32, u32), ApiError> {
+    let staff_id = ju.staff_id.unwrap_or(0);
+    if ju.role() == JwtRole::USR {
+        if staff_id == 0 {
+            return Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Only VDR or staff can adjust points."));
+        }
+        if usr_id == ids.usr_id {
+            return Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Staff cannot adjust their own points."));
+        }
+    }
+    Ok((ids.usr_id, staff_id))
+}
+
+/// VDR 配下のポイント調整をロックして取得する
+async fn lock_point_adjustment(
+    tx: &DatabaseTransaction,
+    apx_id: u32,
+    vdr_id: u32,
+    point_adjustment_id: u32,
+) -> Result<point_adjustments::Model, ApiError> {
+    point_adjustments::Entity::find()
+        .filter(point_adjustments::Column::Id.eq(point_adjustment_id))
+        .filter(point_adjustments::Column::ApxId.eq(apx_id))
+        .filter(point_adjustments::Column::VdrId.eq(vdr_id))
+        .lock_exclusive()
+        .one(tx)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock point adjustment error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Point adjustment not found."))
+}
+
+/// 申請者とは別の承認者による承認・却下を 1 トランザクションで行う
+/// 承認時は個人の行をロックし、保有ポイントの更新と調整前後の値の記録を行う
+async fn review_point_adjustment(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    point_adjustment_id: u32,
+    next: PointAdjustStatus,
+) -> Result<ReviewPointAdjustmentRes, ApiError> {
+    let apx_id = ids.apx_id;
+    let vdr_id = ids.vdr_id;
+    let staff_id = ju.staff_id.unwrap_or(0);
+    let is_usr = ju.role() == JwtRole::USR;
+    if is_usr && staff_id == 0 {
+        return Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Only VDR or staff can review point adjustments."));
+    }
+    let reviewer_id = ids.usr_id;
+    let res = conn.transaction::<_, ReviewPointAdjustmentRes, ApiError>(|tx| {
+        Box::pin(async move {
+            // --------------------------------
+            // 1. 調整をロックし、状態と承認者を検証
+            // --------------------------------
+            let model = lock_point_adjustment(tx, apx_id, vdr_id, point_adjustment_id).await?;
+            if model.status != PointAdjustStatus::Pending as u8 {
+                return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Point adjustment is already reviewed."));
+            }
+            if model.actor_id == reviewer_id && model.staff_id == staff_id {
+                return Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Requester cannot review their own point adjustment."));
+            }
+            if is_usr && model.usr_id == reviewer_id {
+                return Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Staff cannot adjust their own points."));
+            }
+            let mut active: point_adjustments::ActiveModel = model.clone().into_active_model();
+            // --------------------------------
+            // 2. 承認時は個人をロックして調整後の値を検証し、保有ポイントを更新
+            // --------------------------------
+            if next == PointAdjustStatus::Approved {
+                let usr = usrs::Entity::find()
+                    .filter(usrs::Column::Id.eq(model.usr_id))
+                    .filter(usrs::Column::ApxId.eq(apx_id))
+                    .filter(usrs::Column::VdrId.eq(vdr_id))
+                    .filter(usrs::Column::Type.eq(UsrType::Indi as u8))
+                    .lock_exclusive()
+                    .one(tx)
+                    .await
+                    .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock usr error: {}", e)))?
+                    .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Personal USR not found."))?;
+                let bef_points = usr.points;
+                let aft_points = bef_points as i64 + model.delta as i64;
+                log::debug!("<PointAdjustmentBl> review_point_adjustment: usr: {}, delta: {}, points: {} -> {}", model.usr_id, model.delta, bef_points, aft_points);
+                if aft_points < 0 {
+                    return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, format!("Points would become negative: {} {:+}.", bef_points, model.delta)));
+                }
+                if aft_points > u32::MAX as i64 {
+                    return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Points would overflow."));
+                }
+                let amount = model.delta.unsigned_abs();
+                let expr = if model.delta > 0 {
+                    Expr::col(usrs::Column::Points).add(amount)
+                } else {
+                    Expr::col(usrs::Column::Points).sub(amount)
+                };
+                usrs::Entity::update_many()
+                    .col_expr(usrs::Column::Points, expr)
+                    .filter(usrs::Column::Id.eq(model.usr_id))
+                    .exec(tx)
+                    .await
+                    .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update usr points error: {}", e)))?;
+                active.bef_points = Set(bef_points);
+                active.aft_points = Set(aft_points as u32);
+            }
+            // --------------------------------
+            // 3. 調整の状態と承認者の記録
+            // --------------------------------
+            active.status = Set(next as u8);
+            active.reviewer_id = Set(reviewer_id);
+            active.reviewer_staff_id = Set(staff_id);
+            active.reviewed_at = Set(Some(Local::now().naive_local()));
+            let model = active.update(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update point adjustment error: {}", e)))?;
+            Ok(ReviewPointAdjustmentRes {
+                id: model.id as u32,
+                usr_id: model.usr_id,
+                status: model.status,
+                bef_points: model.bef_points,
+                aft_points: model.aft_points,
+            })
+        })
+    }).await?;
+    log::debug!("<PointAdjustmentBl> review_point_adjustment: Success. ID: {}, status: {:?}", point_adjustment_id, next);
+    Ok(res)
+}
+
+// ============================================================
+// Create
+// ============================================================
+pub async fn create_point_adjustment(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: CreatePointAdjustmentReq,
+) -> Result<CreatePointAdjustmentRes, ApiError> {
+    // --------------------------------
+    // 1. 操作者の確認（VDR または スタッフ token のみ）
+    // --------------------------------
+    let (actor_id, staff_id) = ensure_operator(ju, ids, req.usr_id)?;
+    // --------------------------------
+    // 2. パラメータの整合性チェック
+    // --------------------------------
+    if req.delta == 0 {
+        return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "delta must not be 0."));
+    }
+    let reason = PointAdjustReason::from_u8(req.reason)
+        .ok_or_else(|| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, format!("Invalid reason: {}", req.reason)))?;
+    usrs::Entity::find()
+        .filter(usrs::Column::Id.eq(req.usr_id))
+        .filter(usrs::Column::ApxId.eq(ids.apx_id))
+        .filter(usrs::Column::VdrId.eq(ids.vdr_id))
+        .filter(usrs::Column::Type.eq(UsrType::Indi as u8))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch usr error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Personal USR not found."))?;
+    // --------------------------------
+    // 3. 承認待ちとして登録（保有ポイントは承認時に更新する）
+    // --------------------------------
+    log::debug!("<PointAdjustmentBl> create_point_adjustment: usr: {}, reason: {}, delta: {}", req.usr_id, reason.as_str(), req.delta);
+    let adjustment = point_adjustments::ActiveModel {
+        usr_id: Set(req.usr_id),
+        delta: Set(req.delta),
+        reason: Set(reason as u8),
+        note: Set(req.note),
+        actor_id: Set(actor_id),
+        staff_id: Set(staff_id),
+        status: Set(PointAdjustStatus::Pending as u8),
+        apx_id: Set(ids.apx_id),
+        vdr_id: Set(ids.vdr_id),
+        ..Default::default()
+    };
+    let adjustment = adjustment.insert(conn).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Insert point adjustment error: {}", e)))?;
+    log::debug!("<PointAdjustmentBl> create_point_adjustment: Success. ID: {}", adjustment.id);
+    Ok(CreatePointAdjustmentRes { id: adjustment.id as u32, usr_id: adjustment.usr_id, status: adjustment.status })
+}
+
+// ============================================================
+// Approve
+// ============================================================
+pub async fn approve_point_adjustment(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    point_adjustment_id: u32,
+) -> Result<ReviewPointAdjustmentRes, ApiError> {
+    review_point_adjustment(conn, ju, ids, point_adjustment_id, PointAdjustStatus::Approved).await
+}
+
+// ============================================================
+// Reject
+// ============================================================
+pub async fn reject_point_adjustment(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    point_adjustment_id: u32,
+) -> Result<ReviewPointAdjustmentRes, ApiError> {
+    review_point_adjustment(conn, ju, ids, point_adjustment_id, PointAdjustStatus::Rejected).await
+}
@@ -0,0 +1,170 @@
+use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, ColumnTrait, QuerySelect, QueryOrder, Select, Condition};
+use crate::entities::points;
+use crate::utils::jwt::{JwtUsr, JwtIDs, JwtRole};
+use crate::mode::rt::rtreq::points_req::{SearchPointsReq, ExpiringPointsReq};
+use crate::mode::rt::rtres::points_res::{SearchPointsRes, SearchPointsResItem, GetPointRes, ExpiringPointsRes, ExpiringPointsResLot};
+use crate::mode::rt::rtres::errs_res::ApiError;
+use crate::mode::rt::rtutils::usrs_for_rt::{find_vdr_for_rt, find_usr_by_type_for_rt};
+use crate::mode::rt::rtutils::point_expiry_for_rt::load_point_lots_for_rt;
+use crate::utils::point_expiry::expiring_by;
+use axum::http::StatusCode;
+use crate::mode::rt::rterr::rterr;
+use crate::enums::usrtype::UsrType;
+use chrono::{Duration, Local};
+
+// ============================================================
+// Private Helper for Search and Get
+// ============================================================
+/// 権限に基づいた共通のクエリベースを作成する
+/// USR は自身が法人（corp_id）・授与者（from）・受領者（to）のいずれかであるポイント履歴のみ参照できる
+async fn find_points_base(
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+) -> Result<Select<points::Entity>, ApiError> {
+    let query = points::Entity::find();
+    match ju.role() {
+        JwtRole::BD => {
+            log::debug!("<PointBl> find_points_base: BD role. No filtering.");
+            Ok(query)
+        }
+        JwtRole::APX => {
+            log::debug!("<PointBl> find_points_base: APX role. Filter apx_id: {}", ids.apx_id);
+            Ok(query.filter(points::Column::ApxId.eq(ids.apx_id)))
+        }
+        JwtRole::VDR => {
+            log::debug!("<PointBl> find_points_base: VDR role. Filter apx_id: {}, vdr_id: {}", ids.apx_id, ids.vdr_id);
+            Ok(query
+                .filter(points::Column::ApxId.eq(ids.apx_id))
+                .filter(points::Column::VdrId.eq(ids.vdr_id)))
+        }
+        JwtRole::USR => {
+            log::debug!("<PointBl> find_points_base: USR role. Filter apx_id: {}, vdr_id: {}, corp/from/to: {}", ids.apx_id, ids.vdr_id, ids.usr_id);
+            Ok(query
+                .filter(points::Column::ApxId.eq(ids.apx_id))
+                .filter(points::Column::VdrId.eq(ids.vdr_id))
+                .filter(Condition::any()
+                    .add(points::Column::CorpId.eq(ids.usr_id))
+                    .add(points::Column::From.eq(ids.usr_id))
+                    .add(points::Column::To.eq(ids.usr_id))))
+        }
+    }
+}
+
+// ============================================================
+// Search
+// ============================================================
+pub async fn search_points(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: SearchPointsReq,
+) -> Result<SearchPointsRes, ApiError> {
+    // --------------------------------
+    // 1. クエリの基本形を取得
+    // --------------------------------
+    log::debug!("<PointBl> search_points: Constructing base query.");
+    let mut query = find_points_base(ju, ids).await?;
+    // --------------------------------
+    // 2. 検索条件（完全一致）
+    // --------------------------------
+    if let Some(badge_id) = req.badge_id {
+        log::debug!("<PointBl> search_points: Filter by badge_id: {}", badge_id);
+        query = query.filter(points::Column::BadgeId.eq(badge_id));
+    }
+    if let Some(corp_id) = req.corp_id {
+        log::debug!("<PointBl> search_points: Filter by corp_id: {}", corp_id);
+        query = query.filter(points::Column::CorpId.eq(corp_id));
+    }
+    if let Some(from) = req.from {
+        log::debug!("<PointBl> search_points: Filter by from: {}", from);
+        query = query.filter(points::Column::From.eq(from));
+    }
+    if let Some(to) = req.to {
+        log::debug!("<PointBl> search_points: Filter by to: {}", to);
+        query = query.filter(points::Column::To.eq(to));
+    }
+    // --------------------------------
+    // 3. データの取得
+    // --------------------------------
+    log::debug!("<PointBl> search_points: Fetching records. limit: {}, offset: {}", req.limit, req.offset);
+    let models = query
+        .order_by_desc(points::Column::Id)
+        .offset(req.offset as u64)
+        .limit(req.limit as u64)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Search query error: {}", e)))?;
+    log::debug!("<PointBl> search_points: Found {} records.", models.len());
+    // --------------------------------
+    // 4. 最終レスポンス
+    // --------------------------------
+    let points = models.into_iter().map(SearchPointsResItem::from).collect();
+    Ok(SearchPointsRes { points })
+}
+
+// ============================================================
+// Get
+// ============================================================
+pub async fn get_point(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    point_id: u32,
+) -> Result<GetPointRes, ApiError> {
+    log::debug!("<PointBl> get_point: Fetching point: {}", point_id);
+    let model = find_points_base(ju, ids).await?
+        .filter(points::Column::Id.eq(point_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch point error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Point not found."))?;
+    Ok(GetPointRes::from(model))
+}
+
+// ============================================================
+// Expiring
+// ============================================================
+pub async fn get_expiring_points(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: ExpiringPointsReq,
+) -> Result<ExpiringPointsRes, ApiError> {
+    // --------------------------------
+    // 1. 対象の個人を決定（USR は自分自身、VDR は usr_id で指定）
+    // --------------------------------
+    let usr = match ju.role() {
+        JwtRole::VDR => {
+            let usr_id = req.usr_id.ok_or_else(|| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "usr_id is required for VDR."))?;
+            find_usr_by_type_for_rt(conn, ids.apx_id, ids.vdr_id, usr_id, UsrType::Indi).await?
+        }
+        JwtRole::USR => {
+            if req.usr_id.is_some() {
+                return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "USR cannot specify usr_id."));
+            }
+            find_usr_by_type_for_rt(conn, ids.apx_id, ids.vdr_id, ids.usr_id, UsrType::Indi).await.map_err(|e| {
+                if e.status == StatusCode::NOT_FOUND.as_u16() {
+                    ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Only individual USR has expiring points.")
+                } else {
+                    e
+                }
+            })?
+        }
+        _ => return Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Only VDR or USR can get expiring points.")),
+    };
+    let usr_id = usr.id as u32;
+    // --------------------------------
+    // 2. VDR の失効ポリシーに基づき、保有ポイントを付与日時ごとの残りに割り当てる
+    // --------------------------------
+    let vdr = find_vdr_for_rt(conn, ids.apx_id, ids.vdr_id).await?;
+    let months = vdr.point_expire_months;
+    let lots = load_point_lots_for_rt(conn, ids.apx_id, ids.vdr_id, usr_id, usr.points, months).await?;
+    // --------------------------------
+    // 3. 期間内に失効日時を迎える分の抽出
+    // --------------------------------
+    let until = Local::now().naive_local() + Duration::days(req.days as i64);
+    let expiring = expiring_by(&lots, until);
+    log::debug!("<PointBl> get_expiring_points: usr: {}, points: {}, months: {}, days: {}, expiring: {}", usr_id, usr.points, months, req.days, expiring);
+    let lots = lots.into_iter().filter(|l| l.expires_at <= until).map(ExpiringPointsResLot::from).collect();
+    Ok(ExpiringPointsRes { usr_id, points: usr.points, point_expire_months: months, expiring, lots })
+}
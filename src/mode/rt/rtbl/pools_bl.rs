@@ -0,0 +1,90 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use sea_orm::DatabaseConnection;
+use crate::utils::jwt::{JwtUsr, JwtIDs, JwtRole};
+use crate::mode::rt::rtreq::pools_req::{SearchPoolLedgerReq, CheckPoolReq};
+use crate::mode::rt::rtres::pools_res::{SearchPoolLedgerRes, SearchPoolLedgerResItem, CheckPoolRes};
+use crate::mode::rt::rtres::errs_res::ApiError;
+use crate::mode::rt::rtutils::pool_ledger_for_rt::{check_pool_for_rt, load_pool_ledger_page_for_rt};
+use crate::mode::rt::rtutils::usrs_for_rt::find_vdr_for_rt;
+use axum::http::StatusCode;
+use crate::mode::rt::rterr::rterr;
+
+// ============================================================
+// Private Helper
+// ============================================================
+/// ロールに基づいて対象の VDR を決定する
+/// APX は vdr_id が必須、VDR は自身のみ（vdr_id は指定不可）
+async fn resolve_vdr_id(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    vdr_id: Option<u32>,
+) -> Result<u32, ApiError> {
+    log::debug!("<PoolBl> resolve_vdr_id: Role-based validation for {:?}.", ju.role());
+    let vdr_id = match ju.role() {
+        JwtRole::APX => vdr_id.ok_or_else(|| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "APX requires vdr_id."))?,
+        JwtRole::VDR => {
+            if vdr_id.is_some() {
+                return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "VDR cannot set vdr_id."));
+            }
+            ids.vdr_id
+        }
+        _ => {
+            return Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Only APX or VDR can access pools."));
+        }
+    };
+    find_vdr_for_rt(conn, ids.apx_id, vdr_id).await?;
+    Ok(vdr_id)
+}
+
+/// 日付（YYYY-MM-DD）をその日の 00:00:00 に変換する（days_after 日後）
+fn parse_date(s: &str, field: &str, days_after: u64) -> Result<NaiveDateTime, ApiError> {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.checked_add_days(chrono::Days::new(days_after)))
+        .map(|d| d.and_time(Default::default()))
+        .ok_or_else(|| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, format!("Invalid {}: {}", field, s)))
+}
+
+// ============================================================
+// Ledger
+// ============================================================
+pub async fn search_pool_ledger(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: SearchPoolLedgerReq,
+) -> Result<SearchPoolLedgerRes, ApiError> {
+    // --------------------------------
+    // 1. 対象 VDR と期間の決定
+    // --------------------------------
+    let vdr_id = resolve_vdr_id(conn, ju, ids, req.vdr_id).await?;
+    let bgn = req.bgn_date.as_deref().map(|s| parse_date(s, "bgn_date", 0)).transpose()?;
+    let end = req.end_date.as_deref().map(|s| parse_date(s, "end_date", 1)).transpose()?;
+    if let (Some(bgn), Some(end)) = (bgn, end) && end <= bgn {
+        return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "end_date must be on or after bgn_date."));
+    }
+    // --------------------------------
+    // 2. 期間開始時点の残高から積み上げてページを取得
+    // --------------------------------
+    log::debug!("<PoolBl> search_pool_ledger: vdr: {}, bgn: {:?}, end: {:?}, offset: {}, limit: {}", vdr_id, bgn, end, req.offset, req.limit);
+    let page = load_pool_ledger_page_for_rt(conn, ids.apx_id, vdr_id, bgn, end, req.offset as u64, req.limit as u64).await?;
+    let lines: Vec<SearchPoolLedgerResItem> = page.moves.into_iter().map(SearchPoolLedgerResItem::from).collect();
+    log::debug!("<PoolBl> search_pool_ledger: Found {} lines.", lines.len());
+    Ok(SearchPoolLedgerRes { vdr_id, opening_balance: page.opening_balance, closing_balance: page.closing_balance, lines })
+}
+
+// ============================================================
+// Check
+// ============================================================
+pub async fn check_pool(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: CheckPoolReq,
+) -> Result<CheckPoolRes, ApiError> {
+    let vdr_id = resolve_vdr_id(conn, ju, ids, req.vdr_id).await?;
+    let check = check_pool_for_rt(conn, ids.apx_id, vdr_id).await?;
+    log::debug!("<PoolBl> check_pool: vdr: {}, mismatches: {}", vdr_id, check.mismatches.len());
+    Ok(CheckPoolRes::new(vdr_id, check))
+}
@@ -0,0 +1,473 @@
+use sea_orm::{DatabaseConnection, DatabaseTransaction, EntityTrait, QueryFilter, ColumnTrait, QuerySelect, QueryOrder, Select, ActiveModelTrait, Set, TransactionTrait, Condition, PaginatorTrait};
+use sea_orm::sea_query::Expr;
+use crate::entities::{badges, jobs, points, usrs, usr_badges, works};
+use crate::utils::jwt::{JwtUsr, JwtIDs, JwtRole};
+use crate::mode::rt::rtreq::usr_badges_req::{SearchUsrBadgesReq, AwardUsrBadgeReq, RevokeUsrBadgeReq};
+use crate::mode::rt::rtres::usr_badges_res::{SearchUsrBadgesRes, SearchUsrBadgesResItem, GetUsrBadgeRes, AwardUsrBadgeRes, RevokeUsrBadgeRes};
+use crate::mode::rt::rtres::errs_res::ApiError;
+use crate::mode::rt::rtutils::usrs_for_rt::find_usr_by_type_for_rt;
+use crate::mode::rt::rtutils::belongs_for_rt::is_belonging_at_for_rt;
+use crate::mode::rt::rtutils::points_for_rt::{calc_award_point_for_rt, insert_point_for_rt};
+use axum::http::StatusCode;
+use crate::mode::rt::rterr::rterr;
+use chrono::Local;
+use crate::enums::usrtype::UsrType;
+use crate::enums::pointkind::PointKind;
+use crate::enums::badgerevokereason::BadgeRevokeReason;
+use crate::utils::point_expiry::{expires_at, revocable_points};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+
+// ============================================================
+// Private Helper for Search and Get
+// ============================================================
+/// 権限に基づいた共通のクエリベースを作成する
+/// USR は自身が発行法人（corp_id）・授与者（from）・受領者（to）のいずれかである授与のみ参照できる
+async fn find_usr_badges_base(
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+) -> Result<Select<usr_badges::Entity>, ApiError> {
+    let query = usr_badges::Entity::find();
+    match ju.role() {
+        JwtRole::BD => {
+            log::debug!("<UsrBadgeBl> find_usr_badges_base: BD role. No filtering.");
+            Ok(query)
+        }
+        JwtRole::APX => {
+            log::debug!("<UsrBadgeBl> find_usr_badges_base: APX role. Filter apx_id: {}", ids.apx_id);
+            Ok(query.filter(usr_badges::Column::ApxId.eq(ids.apx_id)))
+        }
+        JwtRole::VDR => {
+            log::debug!("<UsrBadgeBl> find_usr_badges_base: VDR role. Filter apx_id: {}, vdr_id: {}", ids.apx_id, ids.vdr_id);
+            Ok(query
+                .filter(usr_badges::Column::ApxId.eq(ids.apx_id))
+                .filter(usr_badges::Column::VdrId.eq(ids.vdr_id)))
+        }
+        JwtRole::USR => {
+            log::debug!("<UsrBadgeBl> find_usr_badges_base: USR role. Filter apx_id: {}, vdr_id: {}, corp/from/to: {}", ids.apx_id, ids.vdr_id, ids.usr_id);
+            Ok(query
+                .filter(usr_badges::Column::ApxId.eq(ids.apx_id))
+                .filter(usr_badges::Column::VdrId.eq(ids.vdr_id))
+                .filter(Condition::any()
+                    .add(usr_badges::Column::CorpId.eq(ids.usr_id))
+                    .add(usr_badges::Column::From.eq(ids.usr_id))
+                    .add(usr_badges::Column::To.eq(ids.usr_id))))
+        }
+    }
+}
+
+/// usrs の非正規化カウンタを 1 加算する（読み取りを伴わない原子的な更新）
+async fn increment_usr_counter(
+    tx: &DatabaseTransaction,
+    usr_id: u32,
+    col: usrs::Column,
+) -> Result<(), ApiError> {
+    usrs::Entity::update_many()
+        .col_expr(col, Expr::col(col).add(1))
+        .filter(usrs::Column::Id.eq(usr_id))
+        .exec(tx)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update usr counter error: {}", e)))?;
+    Ok(())
+}
+
+/// usrs の非正規化カウンタを 1 減算する（0 の場合は更新しない）
+async fn decrement_usr_counter(
+    tx: &DatabaseTransaction,
+    usr_id: u32,
+    col: usrs::Column,
+) -> Result<(), ApiError> {
+    usrs::Entity::update_many()
+        .col_expr(col, Expr::col(col).sub(1))
+        .filter(usrs::Column::Id.eq(usr_id))
+        .filter(col.gt(0))
+        .exec(tx)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update usr counter error: {}", e)))?;
+    Ok(())
+}
+
+// ============================================================
+// Search
+// ============================================================
+pub async fn search_usr_badges(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: SearchUsrBadgesReq,
+) -> Result<SearchUsrBadgesRes, ApiError> {
+    // --------------------------------
+    // 1. クエリの基本形を取得
+    // --------------------------------
+    log::debug!("<UsrBadgeBl> search_usr_badges: Constructing base query.");
+    let mut query = find_usr_badges_base(ju, ids).await?;
+    // --------------------------------
+    // 2. 検索条件（完全一致）
+    // --------------------------------
+    if let Some(badge_id) = req.badge_id {
+        log::debug!("<UsrBadgeBl> search_usr_badges: Filter by badge_id: {}", badge_id);
+        query = query.filter(usr_badges::Column::BadgeId.eq(badge_id));
+    }
+    if let Some(corp_id) = req.corp_id {
+        log::debug!("<UsrBadgeBl> search_usr_badges: Filter by corp_id: {}", corp_id);
+        query = query.filter(usr_badges::Column::CorpId.eq(corp_id));
+    }
+    if let Some(from) = req.from {
+        log::debug!("<UsrBadgeBl> search_usr_badges: Filter by from: {}", from);
+        query = query.filter(usr_badges::Column::From.eq(from));
+    }
+    if let Some(to) = req.to {
+        log::debug!("<UsrBadgeBl> search_usr_badges: Filter by to: {}", to);
+        query = query.filter(usr_badges::Column::To.eq(to));
+    }
+    if let Some(job_id) = req.job_id {
+        log::debug!("<UsrBadgeBl> search_usr_badges: Filter by job_id: {}", job_id);
+        query = query.filter(usr_badges::Column::JobId.eq(job_id));
+    }
+    // --------------------------------
+    // 3. 有効状態（取り消されておらず、有効期限内）
+    // --------------------------------
+    if let Some(active) = req.active {
+        let now = Local::now().naive_local();
+        log::debug!("<UsrBadgeBl> search_usr_badges: Filter by active: {}, at: {}", active, now);
+        let valid = Condition::all()
+            .add(usr_badges::Column::RevokedAt.is_null())
+            .add(Condition::any()
+                .add(usr_badges::Column::ExpiresAt.is_null())
+                .add(usr_badges::Column::ExpiresAt.gt(now)));
+        query = query.filter(if active { valid } else { valid.not() });
+    }
+    // --------------------------------
+    // 4. データの取得
+    // --------------------------------
+    log::debug!("<UsrBadgeBl> search_usr_badges: Fetching records. limit: {}, offset: {}", req.limit, req.offset);
+    let models = query
+        .order_by_desc(usr_badges::Column::Id)
+        .offset(req.offset as u64)
+        .limit(req.limit as u64)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Search query error: {}", e)))?;
+    log::debug!("<UsrBadgeBl> search_usr_badges: Found {} records.", models.len());
+    // --------------------------------
+    // 5. 最終レスポンス
+    // --------------------------------
+    let usr_badges = models.into_iter().map(SearchUsrBadgesResItem::from).collect();
+    Ok(SearchUsrBadgesRes { usr_badges })
+}
+
+// ============================================================
+// Get
+// ============================================================
+pub async fn get_usr_badge(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    usr_badge_id: u32,
+) -> Result<GetUsrBadgeRes, ApiError> {
+    log::debug!("<UsrBadgeBl> get_usr_badge: Fetching usr_badge: {}", usr_badge_id);
+    let model = find_usr_badges_base(ju, ids).await?
+        .filter(usr_badges::Column::Id.eq(usr_badge_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch usr_badge error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "UsrBadge not found."))?;
+    Ok(GetUsrBadgeRes::from(model))
+}
+
+// ============================================================
+// Award
+// ============================================================
+pub async fn award_usr_badge(
+    conn: &DatabaseConnection,
+    ids: &JwtIDs,
+    req: AwardUsrBadgeReq,
+) -> Result<AwardUsrBadgeRes, ApiError> {
+    // --------------------------------
+    // 1. 授与者（自分）の種別を確認
+    // --------------------------------
+    let giver = usrs::Entity::find()
+        .filter(usrs::Column::Id.eq(ids.usr_id))
+        .filter(usrs::Column::ApxId.eq(ids.apx_id))
+        .filter(usrs::Column::VdrId.eq(ids.vdr_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch usr error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "USR not found."))?;
+    let giver_type = match giver.r#type {
+        t if t == UsrType::Corp as u8 => UsrType::Corp,
+        t if t == UsrType::Indi as u8 => UsrType::Indi,
+        _ => return Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Only corporate or individual USR can award badges.")),
+    };
+    // --------------------------------
+    // 2. バッジの確認（廃止済みは授与不可）
+    // --------------------------------
+    let badge = badges::Entity::find()
+        .filter(badges::Column::Id.eq(req.badge_id))
+        .filter(badges::Column::ApxId.eq(ids.apx_id))
+        .filter(badges::Column::VdrId.eq(ids.vdr_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch badge error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Badge not found."))?;
+    if badge.retired_at.is_some() {
+        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Badge is retired."));
+    }
+    // --------------------------------
+    // 3. 授与権限の確認
+    //    法人は自身のバッジのみ、個人はバッジ発行法人へ所属している場合のみ授与できる
+    // --------------------------------
+    let now = Local::now().naive_local();
+    match giver_type {
+        UsrType::Corp => {
+            if badge.corp_id != ids.usr_id {
+                return Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Corporate USR can only award own badges."));
+            }
+        }
+        _ => {
+            if !is_belonging_at_for_rt(conn, ids.apx_id, ids.vdr_id, badge.corp_id, ids.usr_id, now).await? {
+                return Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Individual USR must belong to the badge's corp."));
+            }
+        }
+    }
+    // --------------------------------
+    // 4. 受領者の確認（自分自身には授与できない）
+    // --------------------------------
+    if req.to == ids.usr_id {
+        return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "Cannot award a badge to yourself."));
+    }
+    find_usr_by_type_for_rt(conn, ids.apx_id, ids.vdr_id, req.to, UsrType::Indi).await?;
+    // --------------------------------
+    // 5. 求人に紐づく場合の確認（発行法人の求人で、受領者が就業していること）
+    // --------------------------------
+    if let Some(job_id) = req.job_id {
+        let job = jobs::Entity::find()
+            .filter(jobs::Column::Id.eq(job_id))
+            .filter(jobs::Column::ApxId.eq(ids.apx_id))
+            .filter(jobs::Column::VdrId.eq(ids.vdr_id))
+            .one(conn)
+            .await
+            .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch job error: {}", e)))?
+            .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Job not found."))?;
+        if job.corp_id != badge.corp_id {
+            return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "Job is not issued by the badge's corp."));
+        }
+        let worked = works::Entity::find()
+            .filter(works::Column::JobId.eq(job_id))
+            .filter(works::Column::To.eq(req.to))
+            .count(conn)
+            .await
+            .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Count works error: {}", e)))?;
+        if worked == 0 {
+            return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "Recipient has no work for the job."));
+        }
+    }
+    // --------------------------------
+    // 6. 受領者に付与するポイントの算出（授与時点の所属で割増を判定）
+    // --------------------------------
+    let award_point = calc_award_point_for_rt(conn, ids.apx_id, ids.vdr_id, badge.corp_id, req.to, now).await?;
+    // --------------------------------
+    // 7. 授与・ポイント付与・カウンタ更新（トランザクション）
+    // --------------------------------
+    let apx_id = ids.apx_id;
+    let vdr_id = ids.vdr_id;
+    let from = ids.usr_id;
+    let badge_expires_at = (badge.valid_months > 0).then(|| expires_at(now, badge.valid_months));
+    let id = conn.transaction::<_, i32, ApiError>(|tx| {
+        Box::pin(async move {
+            // 求人に紐づく場合は求人をロックして max_badges を検証（0 は上限なし）
+            if let Some(job_id) = req.job_id {
+                let job = jobs::Entity::find_by_id(job_id as i32)
+                    .lock_exclusive()
+                    .one(tx)
+                    .await
+                    .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock job error: {}", e)))?
+                    .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Job not found."))?;
+                if job.max_badges > 0 {
+                    let awarded = usr_badges::Entity::find()
+                        .filter(usr_badges::Column::ApxId.eq(apx_id))
+                        .filter(usr_badges::Column::VdrId.eq(vdr_id))
+                        .filter(usr_badges::Column::JobId.eq(job_id))
+                        .filter(usr_badges::Column::RevokedAt.is_null())
+                        .count(tx)
+                        .await
+                        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Count usr_badges error: {}", e)))?;
+                    if awarded >= job.max_badges as u64 {
+                        log::debug!("<UsrBadgeBl> award_usr_badge: Job {} reached max_badges. awarded: {}, max: {}", job_id, awarded, job.max_badges);
+                        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Job has reached max badges."));
+                    }
+                }
+            }
+            let active = usr_badges::ActiveModel {
+                badge_id: Set(req.badge_id),
+                job_id: Set(req.job_id.unwrap_or(0)),
+                corp_id: Set(badge.corp_id),
+                from: Set(from),
+                to: Set(req.to),
+                title: Set(req.title),
+                message: Set(req.message),
+                r#type: Set(giver_type as u8),
+                expires_at: Set(badge_expires_at),
+                apx_id: Set(apx_id),
+                vdr_id: Set(vdr_id),
+                ..Default::default()
+            };
+            let res = active.insert(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Insert usr_badge error: {}", e)))?;
+            // ポイント履歴の追記と受領者の保有ポイント加算
+            let point = points::ActiveModel {
+                badge_id: Set(res.badge_id),
+                usr_badge_id: Set(res.id as u32),
+                corp_id: Set(res.corp_id),
+                from: Set(res.from),
+                to: Set(res.to),
+                point: Set(award_point.point),
+                extra: Set(award_point.extra),
+                kind: Set(PointKind::Award as u8),
+                delta: Set((award_point.point + award_point.extra) as i32),
+                apx_id: Set(apx_id),
+                vdr_id: Set(vdr_id),
+                ..Default::default()
+            };
+            let point = insert_point_for_rt(tx, point).await?;
+            log::debug!("<UsrBadgeBl> award_usr_badge: Point created. ID: {}, point: {}, extra: {}", point.id, point.point, point.extra);
+            // 受領者の保有数、授与者の授与累積数、VDR 内の授与総数
+            increment_usr_counter(tx, req.to, usrs::Column::Badged).await?;
+            increment_usr_counter(tx, from, usrs::Column::TotalBadged).await?;
+            increment_usr_counter(tx, vdr_id, usrs::Column::TotalBadges).await?;
+            Ok(res.id)
+        })
+    }).await?;
+    log::debug!("<UsrBadgeBl> award_usr_badge: Success. ID: {}", id);
+    Ok(AwardUsrBadgeRes { id: id as u32 })
+}
+
+// ============================================================
+// Revoke
+// ============================================================
+pub async fn revoke_usr_badge(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    usr_badge_id: u32,
+    req: RevokeUsrBadgeReq,
+) -> Result<RevokeUsrBadgeRes, ApiError> {
+    // --------------------------------
+    // 1. パラメータの整合性チェック
+    // --------------------------------
+    let reason = BadgeRevokeReason::from_u8(req.reason)
+        .ok_or_else(|| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, format!("Invalid reason: {}", req.reason)))?;
+    // --------------------------------
+    // 2. 対象の取得と状態確認
+    // --------------------------------
+    log::debug!("<UsrBadgeBl> revoke_usr_badge: Fetching usr_badge: {}", usr_badge_id);
+    let model = usr_badges::Entity::find()
+        .filter(usr_badges::Column::Id.eq(usr_badge_id))
+        .filter(usr_badges::Column::ApxId.eq(ids.apx_id))
+        .filter(usr_badges::Column::VdrId.eq(ids.vdr_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch usr_badge error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "UsrBadge not found."))?;
+    if model.revoked_at.is_some() {
+        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "UsrBadge is already revoked."));
+    }
+    // --------------------------------
+    // 3. 操作者の確認（VDR、バッジ発行法人、または スタッフ token のみ）
+    // --------------------------------
+    let staff_id = ju.staff_id.unwrap_or(0);
+    if ju.role() == JwtRole::USR {
+        if model.corp_id != ids.usr_id && staff_id == 0 {
+            return Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Only VDR, the badge's corp or staff can revoke badges."));
+        }
+        if model.to == ids.usr_id {
+            return Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Cannot revoke your own badge."));
+        }
+    }
+    // --------------------------------
+    // 4. 取り消し・ポイントの戻し・カウンタ更新（トランザクション）
+    //    受領者の行をロックするため、分配（flush）とは直列化される
+    // --------------------------------
+    let apx_id = ids.apx_id;
+    let vdr_id = ids.vdr_id;
+    let actor_id = ids.usr_id;
+    let revoked_points = conn.transaction::<_, u32, ApiError>(|tx| {
+        Box::pin(async move {
+            let model = usr_badges::Entity::find_by_id(usr_badge_id as i32)
+                .lock_exclusive()
+                .one(tx)
+                .await
+                .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock usr_badge error: {}", e)))?
+                .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "UsrBadge not found."))?;
+            if model.revoked_at.is_some() {
+                return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "UsrBadge is already revoked."));
+            }
+            let usr = usrs::Entity::find_by_id(model.to as i32)
+                .lock_exclusive()
+                .one(tx)
+                .await
+                .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock usr error: {}", e)))?
+                .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "USR not found."))?;
+            // 授与で付与したポイントが未分配（flush_id が 0）であれば、その授与について失効済みの分を除いた残りを、保有ポイントの範囲で戻す
+            // 保留中の授与は分配・失効の対象から除かれているため、常に未分配かつ失効済みの分はない
+            let award = points::Entity::find()
+                .filter(points::Column::UsrBadgeId.eq(usr_badge_id))
+                .filter(points::Column::Kind.eq(PointKind::Award as u8))
+                .one(tx)
+                .await
+                .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch point error: {}", e)))?;
+            let mut revoked_points = 0;
+            if let Some(award) = award {
+                let expired: Option<Decimal> = points::Entity::find()
+                    .select_only()
+                    .column_as(points::Column::Delta.sum(), "expired")
+                    .filter(points::Column::UsrBadgeId.eq(usr_badge_id))
+                    .filter(points::Column::Kind.eq(PointKind::Expiry as u8))
+                    .into_tuple()
+                    .one(tx)
+                    .await
+                    .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Sum expired points error: {}", e)))?
+                    .flatten();
+                let expired = expired.and_then(|v| v.to_i64()).unwrap_or(0).unsigned_abs() as u32;
+                if model.flush_id == 0 {
+                    revoked_points = revocable_points(award.point + award.extra, expired, usr.points);
+                }
+                log::debug!("<UsrBadgeBl> revoke_usr_badge: point_id: {}, point: {}, extra: {}, expired: {}, flush_id: {}, holding: {}, revoke: {}", award.id, award.point, award.extra, expired, model.flush_id, usr.points, revoked_points);
+            }
+            if revoked_points > 0 {
+                let point = points::ActiveModel {
+                    badge_id: Set(model.badge_id),
+                    usr_badge_id: Set(usr_badge_id),
+                    corp_id: Set(model.corp_id),
+                    from: Set(model.from),
+                    to: Set(model.to),
+                    point: Set(0),
+                    extra: Set(0),
+                    kind: Set(PointKind::Revocation as u8),
+                    delta: Set(-(revoked_points as i32)),
+                    apx_id: Set(apx_id),
+                    vdr_id: Set(vdr_id),
+                    ..Default::default()
+                };
+                insert_point_for_rt(tx, point).await?;
+            }
+            // 授与時に加算したカウンタを戻す
+            decrement_usr_counter(tx, model.to, usrs::Column::Badged).await?;
+            decrement_usr_counter(tx, model.from, usrs::Column::TotalBadged).await?;
+            decrement_usr_counter(tx, vdr_id, usrs::Column::TotalBadges).await?;
+            // 取り消しの記録（行は削除しない）
+            let now = Local::now().naive_local();
+            log::debug!("<UsrBadgeBl> revoke_usr_badge: usr_badge: {}, reason: {}, actor: {}, staff: {}, at: {}", usr_badge_id, reason.as_str(), actor_id, staff_id, now);
+            let mut active: usr_badges::ActiveModel = model.into();
+            active.revoked_at = Set(Some(now));
+            active.revoke_reason = Set(reason as u8);
+            active.revoke_note = Set(req.note);
+            active.revoked_by = Set(actor_id);
+            active.revoked_staff_id = Set(staff_id);
+            active.revoked_points = Set(revoked_points);
+            active.update(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update usr_badge error: {}", e)))?;
+            Ok(revoked_points)
+        })
+    }).await?;
+    log::debug!("<UsrBadgeBl> revoke_usr_badge: Success. ID: {}, revoked_points: {}", usr_badge_id, revoked_points);
+    Ok(RevokeUsrBadgeRes { id: usr_badge_id, revoked_points })
+}
@@ -1,5 +1,5 @@
 use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, ColumnTrait, QuerySelect, Select, ActiveModelTrait, IntoActiveModel, Set, ModelTrait, TransactionTrait, Condition};
-use crate::entities::{usrs, pools, jobs, matches, match_statuses, works, belongs, badges, usr_badges, points, payments, flushes, payouts, cryptos};
+use crate::entities::{usrs, pools, jobs, matches, match_statuses, works, belongs, badges, usr_badges, points, payments, flushes, payouts, cryptos, work_corrections, bank_accounts, withdrawals, withdrawal_statuses, point_adjustments};
 use crate::utils::jwt::{JwtUsr, JwtIDs, JwtRole};
 use crate::mode::rt::rtreq::usrs_req::{SearchUsrsReq, UpdateUsrReq, CreateUsrReq};
 use crate::mode::rt::rtres::usrs_res::{SearchUsrsRes, SearchUsrsResItem, GetUsrRes, UpdateUsrRes, DeleteUsrRes, CreateUsrRes, HireUsrRes, DehireUsrRes};
@@ -50,6 +50,11 @@ async fn find_usrs_base(
     }
 }
 
+/// VDR の源泉徴収ルールに関する項目が 1 つでも指定されているか
+fn has_withholding(req: &CreateUsrReq) -> bool {
+    req.withholding_threshold.is_some() || req.withholding_rate.is_some() || req.withholding_reduced_rate.is_some() || req.withholding_reduced_limit.is_some()
+}
+
 // ============================================================
 // Search
 // ============================================================
@@ -158,7 +163,7 @@ pub async fn create_usr(
             utype = UsrType::Corp as u8; // APX は常に法人タイプ
             target_label = "APX";
             // 不要な項目があればエラー
-            if req.usr_type.is_some() || req.base_point.is_some() || req.belong_rate.is_some() || req.max_works.is_some() || req.flush_days.is_some() || req.rate.is_some() || req.flush_fee_rate.is_some() {
+            if req.usr_type.is_some() || req.base_point.is_some() || req.belong_rate.is_some() || req.max_works.is_some() || req.flush_days.is_some() || req.rate.is_some() || req.flush_fee_rate.is_some() || has_withholding(&req) || req.point_expire_months.is_some() {
                 return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "BD can only create APX. Unnecessary parameters provided."));
             }
         }
@@ -173,7 +178,10 @@ pub async fn create_usr(
                 return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "VDR requires base_point, belong_rate, max_works, and flush_fee_rate."));
             }
             // 不要な項目があればエラー
-            if req.usr_type.is_some() || req.flush_days.is_some() || req.rate.is_some() {
+            // flush_days は任意（VDR の定期分配サイクル。0 または未指定の場合は定期分配しない）
+            // withholding_* は任意（VDR の源泉徴収ルール。未指定の場合は源泉徴収しない）
+            // point_expire_months は任意（VDR のポイント失効ポリシー。0 または未指定の場合は失効しない）
+            if req.usr_type.is_some() || req.rate.is_some() {
                 return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "APX can only create VDR. Unnecessary parameters provided."));
             }
         }
@@ -186,8 +194,8 @@ pub async fn create_usr(
             let t = req.usr_type.ok_or_else(|| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "Usr type is required."))?;
             utype = t;
             // 不要な項目のチェック
-            if req.base_point.is_some() || req.belong_rate.is_some() || req.max_works.is_some() || req.flush_fee_rate.is_some() {
-                return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "VDR cannot set base_point, belong_rate, max_works, or flush_fee_rate for USR."));
+            if req.base_point.is_some() || req.belong_rate.is_some() || req.max_works.is_some() || req.flush_fee_rate.is_some() || has_withholding(&req) || req.point_expire_months.is_some() {
+                return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "VDR cannot set base_point, belong_rate, max_works, flush_fee_rate, withholding, or point_expire_months for USR."));
             }
             if utype == UsrType::Corp as u8 {
                 // 法人としての必須項目
@@ -267,6 +275,11 @@ pub async fn create_usr(
             active.flush_days = Set(req.flush_days.unwrap_or(0));
             active.rate = Set(Decimal::from_f64(req.rate.unwrap_or(0.0)).unwrap_or_default());
             active.flush_fee_rate = Set(Decimal::from_f64(req.flush_fee_rate.unwrap_or(0.0)).unwrap_or_default());
+            active.withholding_threshold = Set(req.withholding_threshold.unwrap_or(0));
+            active.withholding_rate = Set(Decimal::from_f64(req.withholding_rate.unwrap_or(0.0)).unwrap_or_default());
+            active.withholding_reduced_rate = Set(Decimal::from_f64(req.withholding_reduced_rate.unwrap_or(0.0)).unwrap_or_default());
+            active.withholding_reduced_limit = Set(req.withholding_reduced_limit.unwrap_or(0));
+            active.point_expire_months = Set(req.point_expire_months.unwrap_or(0));
             let res: usrs::Model = active.insert(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Insert user error: {}", e)))?;
             // VDR作成時のみ Pool を作成
             if is_vdr_creation {
@@ -367,6 +380,17 @@ pub async fn update_usr(
     if let Some(v) = req.flush_fee_rate { 
         active.flush_fee_rate = Set(Decimal::from_f64(v).ok_or_else(|| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "Invalid flush_fee_rate"))?); 
     }
+    // VDR の源泉徴収ルール
+    if let Some(v) = req.withholding_threshold { active.withholding_threshold = Set(v); }
+    if let Some(v) = req.withholding_rate {
+        active.withholding_rate = Set(Decimal::from_f64(v).ok_or_else(|| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "Invalid withholding_rate"))?);
+    }
+    if let Some(v) = req.withholding_reduced_rate {
+        active.withholding_reduced_rate = Set(Decimal::from_f64(v).ok_or_else(|| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "Invalid withholding_reduced_rate"))?);
+    }
+    if let Some(v) = req.withholding_reduced_limit { active.withholding_reduced_limit = Set(v); }
+    // VDR のポイント失効ポリシー
+    if let Some(v) = req.point_expire_months { active.point_expire_months = Set(v); }
     // --------------------------------
     // 4. 保存
     // --------------------------------
@@ -416,6 +440,7 @@ pub async fn delete_usr(
                 matches::Entity::delete_many().filter(matches::Column::VdrId.eq(vid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete matches error: {}", e)))?;
                 match_statuses::Entity::delete_many().filter(match_statuses::Column::VdrId.eq(vid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete match_statuses error: {}", e)))?;
                 works::Entity::delete_many().filter(works::Column::VdrId.eq(vid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete works error: {}", e)))?;
+                work_corrections::Entity::delete_many().filter(work_corrections::Column::VdrId.eq(vid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete work_corrections error: {}", e)))?;
                 belongs::Entity::delete_many().filter(belongs::Column::VdrId.eq(vid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete belongs error: {}", e)))?;
                 badges::Entity::delete_many().filter(badges::Column::VdrId.eq(vid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete badges error: {}", e)))?;
                 usr_badges::Entity::delete_many().filter(usr_badges::Column::VdrId.eq(vid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete usr_badges error: {}", e)))?;
@@ -424,6 +449,10 @@ pub async fn delete_usr(
                 pools::Entity::delete_many().filter(pools::Column::VdrId.eq(vid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete pools error: {}", e)))?;
                 flushes::Entity::delete_many().filter(flushes::Column::VdrId.eq(vid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete flushes error: {}", e)))?;
                 payouts::Entity::delete_many().filter(payouts::Column::VdrId.eq(vid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete payouts error: {}", e)))?;
+                bank_accounts::Entity::delete_many().filter(bank_accounts::Column::VdrId.eq(vid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete bank_accounts error: {}", e)))?;
+                withdrawals::Entity::delete_many().filter(withdrawals::Column::VdrId.eq(vid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete withdrawals error: {}", e)))?;
+                withdrawal_statuses::Entity::delete_many().filter(withdrawal_statuses::Column::VdrId.eq(vid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete withdrawal_statuses error: {}", e)))?;
+                point_adjustments::Entity::delete_many().filter(point_adjustments::Column::VdrId.eq(vid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete point_adjustments error: {}", e)))?;
                 cryptos::Entity::delete_many().filter(cryptos::Column::VdrId.eq(vid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete cryptos error: {}", e)))?;
             } else if model.apx_id.is_some() && model.vdr_id.is_some() {
                 log::debug!("<UsrBl> delete_usr: Target is USR. Cascading sub-records deletion.");
@@ -435,6 +464,8 @@ pub async fn delete_usr(
                 match_statuses::Entity::delete_many().filter(Condition::any().add(match_statuses::Column::From.eq(uid)).add(match_statuses::Column::To.eq(uid))).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete match_statuses error: {}", e)))?;
                 // works (from, to)
                 works::Entity::delete_many().filter(Condition::any().add(works::Column::From.eq(uid)).add(works::Column::To.eq(uid))).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete works error: {}", e)))?;
+                // work_corrections (from, to)
+                work_corrections::Entity::delete_many().filter(Condition::any().add(work_corrections::Column::From.eq(uid)).add(work_corrections::Column::To.eq(uid))).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete work_corrections error: {}", e)))?;
                 // belongs (corp_id, usr_id)
                 belongs::Entity::delete_many().filter(Condition::any().add(belongs::Column::CorpId.eq(uid)).add(belongs::Column::UsrId.eq(uid))).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete belongs error: {}", e)))?;
                 // usr_badges (corp_id, from, to)
@@ -445,6 +476,14 @@ pub async fn delete_usr(
                 payments::Entity::delete_many().filter(payments::Column::CorpId.eq(uid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete payments error: {}", e)))?;
                 // payouts (usr_id)
                 payouts::Entity::delete_many().filter(payouts::Column::UsrId.eq(uid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete payouts error: {}", e)))?;
+                // bank_accounts (usr_id)
+                bank_accounts::Entity::delete_many().filter(bank_accounts::Column::UsrId.eq(uid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete bank_accounts error: {}", e)))?;
+                // withdrawals (usr_id)
+                withdrawals::Entity::delete_many().filter(withdrawals::Column::UsrId.eq(uid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete withdrawals error: {}", e)))?;
+                // withdrawal_statuses (usr_id)
+                withdrawal_statuses::Entity::delete_many().filter(withdrawal_statuses::Column::UsrId.eq(uid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete withdrawal_statuses error: {}", e)))?;
+                // point_adjustments (usr_id)
+                point_adjustments::Entity::delete_many().filter(point_adjustments::Column::UsrId.eq(uid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete point_adjustments error: {}", e)))?;
                 // jobs (corp_id)
                 jobs::Entity::delete_many().filter(jobs::Column::CorpId.eq(uid)).exec(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Delete jobs error: {}", e)))?;
                 // badges (corp_id)
@@ -0,0 +1,323 @@
+use sea_orm::{DatabaseConnection, DatabaseTransaction, EntityTrait, QueryFilter, ColumnTrait, QuerySelect, QueryOrder, Select, ActiveModelTrait, IntoActiveModel, Set, TransactionTrait};
+use crate::entities::{usrs, withdrawals, withdrawal_statuses};
+use crate::utils::jwt::{JwtUsr, JwtIDs, JwtRole};
+use crate::mode::rt::rtreq::withdrawals_req::{SearchWithdrawalsReq, CreateWithdrawalReq, ReviewWithdrawalReq};
+use crate::mode::rt::rtres::withdrawals_res::{SearchWithdrawalsRes, SearchWithdrawalsResItem, GetWithdrawalRes, CreateWithdrawalRes, ReviewWithdrawalRes};
+use crate::mode::rt::rtres::errs_res::ApiError;
+use crate::mode::rt::rtutils::usrs_for_rt::find_usr_by_type_for_rt;
+use crate::mode::rt::rtutils::withdrawals_for_rt::calc_withdrawable_for_rt;
+use axum::http::StatusCode;
+use crate::mode::rt::rterr::rterr;
+use chrono::Local;
+use crate::enums::usrtype::UsrType;
+use crate::enums::withdrawalstatus::WithdrawalStatus;
+
+// ============================================================
+// Private Helper for Search and Get
+// ============================================================
+/// 権限に基づいた共通のクエリベースを作成する
+/// USR は自身の出金申請のみ参照できる
+async fn find_withdrawals_base(
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+) -> Result<Select<withdrawals::Entity>, ApiError> {
+    let query = withdrawals::Entity::find();
+    match ju.role() {
+        JwtRole::BD => {
+            log::debug!("<WithdrawalBl> find_withdrawals_base: BD role. No filtering.");
+            Ok(query)
+        }
+        JwtRole::APX => {
+            log::debug!("<WithdrawalBl> find_withdrawals_base: APX role. Filter apx_id: {}", ids.apx_id);
+            Ok(query.filter(withdrawals::Column::ApxId.eq(ids.apx_id)))
+        }
+        JwtRole::VDR => {
+            log::debug!("<WithdrawalBl> find_withdrawals_base: VDR role. Filter apx_id: {}, vdr_id: {}", ids.apx_id, ids.vdr_id);
+            Ok(query
+                .filter(withdrawals::Column::ApxId.eq(ids.apx_id))
+                .filter(withdrawals::Column::VdrId.eq(ids.vdr_id)))
+        }
+        JwtRole::USR => {
+            log::debug!("<WithdrawalBl> find_withdrawals_base: USR role. Filter apx_id: {}, vdr_id: {}, usr_id: {}", ids.apx_id, ids.vdr_id, ids.usr_id);
+            Ok(query
+                .filter(withdrawals::Column::ApxId.eq(ids.apx_id))
+                .filter(withdrawals::Column::VdrId.eq(ids.vdr_id))
+                .filter(withdrawals::Column::UsrId.eq(ids.usr_id)))
+        }
+    }
+}
+
+/// 遷移が許可されているか確認する（許可されていない場合は 409）
+fn check_transition(current: WithdrawalStatus, next: WithdrawalStatus) -> Result<(), ApiError> {
+    if !current.can_transition_to(next) {
+        log::debug!("<WithdrawalBl> check_transition: Illegal transition {:?} -> {:?}", current, next);
+        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, format!("Illegal status transition: {} -> {}.", current as u8, next as u8)));
+    }
+    Ok(())
+}
+
+/// ステータス履歴（監査用の台帳）を1件追記する
+async fn insert_status_history(
+    tx: &DatabaseTransaction,
+    model: &withdrawals::Model,
+    status: WithdrawalStatus,
+    actor_id: u32,
+    note: String,
+) -> Result<(), ApiError> {
+    let history = withdrawal_statuses::ActiveModel {
+        withdrawal_id: Set(model.id as u32),
+        usr_id: Set(model.usr_id),
+        amount: Set(model.amount),
+        status: Set(status as u8),
+        actor_id: Set(actor_id),
+        note: Set(note),
+        apx_id: Set(model.apx_id),
+        vdr_id: Set(model.vdr_id),
+        ..Default::default()
+    };
+    history.insert(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Insert withdrawal status error: {}", e)))?;
+    Ok(())
+}
+
+/// 個人の行をロックする（同一個人の出金申請・承認を直列化する）
+async fn lock_usr(tx: &DatabaseTransaction, usr_id: u32) -> Result<(), ApiError> {
+    usrs::Entity::find_by_id(usr_id as i32)
+        .lock_exclusive()
+        .one(tx)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock usr error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "USR not found."))?;
+    Ok(())
+}
+
+/// VDR 配下の出金申請をロックして取得する
+async fn lock_withdrawal(
+    tx: &DatabaseTransaction,
+    apx_id: u32,
+    vdr_id: u32,
+    withdrawal_id: u32,
+) -> Result<withdrawals::Model, ApiError> {
+    withdrawals::Entity::find()
+        .filter(withdrawals::Column::Id.eq(withdrawal_id))
+        .filter(withdrawals::Column::ApxId.eq(apx_id))
+        .filter(withdrawals::Column::VdrId.eq(vdr_id))
+        .lock_exclusive()
+        .one(tx)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock withdrawal error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Withdrawal not found."))
+}
+
+/// VDR による審査（承認・却下・支払済）を 1 トランザクションで行う
+/// 承認時は個人の行をロックした上で、出金可能残高を再検証する
+async fn review_withdrawal(
+    conn: &DatabaseConnection,
+    ids: &JwtIDs,
+    withdrawal_id: u32,
+    next: WithdrawalStatus,
+    note: String,
+) -> Result<ReviewWithdrawalRes, ApiError> {
+    let apx_id = ids.apx_id;
+    let vdr_id = ids.vdr_id;
+    let actor_id = ids.usr_id;
+    conn.transaction::<_, (), ApiError>(|tx| {
+        Box::pin(async move {
+            // --------------------------------
+            // 1. 出金申請をロックして遷移を検証
+            // --------------------------------
+            let model = lock_withdrawal(tx, apx_id, vdr_id, withdrawal_id).await?;
+            let current = WithdrawalStatus::from_u8(model.status)
+                .ok_or_else(|| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_UNEXPECTED, format!("Invalid withdrawal status: {}", model.status)))?;
+            check_transition(current, next)?;
+            // --------------------------------
+            // 2. 承認時は出金可能残高を再検証
+            // --------------------------------
+            if next == WithdrawalStatus::Approved {
+                lock_usr(tx, model.usr_id).await?;
+                let balance = calc_withdrawable_for_rt(tx, apx_id, vdr_id, model.usr_id).await?;
+                log::debug!("<WithdrawalBl> review_withdrawal: usr: {}, amount: {}, available: {}", model.usr_id, model.amount, balance.available());
+                if model.amount as u64 > balance.available() {
+                    return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Insufficient balance."));
+                }
+            }
+            // --------------------------------
+            // 3. ステータスの更新と履歴の追記
+            // --------------------------------
+            insert_status_history(tx, &model, next, actor_id, note.clone()).await?;
+            let now = Local::now().naive_local();
+            let mut active: withdrawals::ActiveModel = model.into_active_model();
+            active.status = Set(next as u8);
+            match next {
+                WithdrawalStatus::Paid => active.paid_at = Set(Some(now)),
+                _ => active.reviewed_at = Set(Some(now)),
+            }
+            if !note.is_empty() {
+                active.note = Set(note);
+            }
+            active.update(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update withdrawal error: {}", e)))?;
+            Ok(())
+        })
+    }).await?;
+    log::debug!("<WithdrawalBl> review_withdrawal: Success. ID: {}, status: {:?}", withdrawal_id, next);
+    Ok(ReviewWithdrawalRes { id: withdrawal_id })
+}
+
+// ============================================================
+// Search
+// ============================================================
+pub async fn search_withdrawals(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: SearchWithdrawalsReq,
+) -> Result<SearchWithdrawalsRes, ApiError> {
+    // --------------------------------
+    // 1. クエリの基本形を取得
+    // --------------------------------
+    log::debug!("<WithdrawalBl> search_withdrawals: Constructing base query.");
+    let mut query = find_withdrawals_base(ju, ids).await?;
+    // --------------------------------
+    // 2. 検索条件（完全一致）
+    // --------------------------------
+    if let Some(usr_id) = req.usr_id {
+        log::debug!("<WithdrawalBl> search_withdrawals: Filter by usr_id: {}", usr_id);
+        query = query.filter(withdrawals::Column::UsrId.eq(usr_id));
+    }
+    if let Some(status) = req.status {
+        log::debug!("<WithdrawalBl> search_withdrawals: Filter by status: {}", status);
+        query = query.filter(withdrawals::Column::Status.eq(status));
+    }
+    // --------------------------------
+    // 3. データの取得
+    // --------------------------------
+    log::debug!("<WithdrawalBl> search_withdrawals: Fetching records. limit: {}, offset: {}", req.limit, req.offset);
+    let models = query
+        .order_by_desc(withdrawals::Column::Id)
+        .offset(req.offset as u64)
+        .limit(req.limit as u64)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Search query error: {}", e)))?;
+    log::debug!("<WithdrawalBl> search_withdrawals: Found {} records.", models.len());
+    // --------------------------------
+    // 4. 最終レスポンス
+    // --------------------------------
+    let withdrawals = models.into_iter().map(SearchWithdrawalsResItem::from).collect();
+    Ok(SearchWithdrawalsRes { withdrawals })
+}
+
+// ============================================================
+// Get
+// ============================================================
+pub async fn get_withdrawal(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    withdrawal_id: u32,
+) -> Result<GetWithdrawalRes, ApiError> {
+    log::debug!("<WithdrawalBl> get_withdrawal: Fetching withdrawal: {}", withdrawal_id);
+    let model = find_withdrawals_base(ju, ids).await?
+        .filter(withdrawals::Column::Id.eq(withdrawal_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch withdrawal error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Withdrawal not found."))?;
+    log::debug!("<WithdrawalBl> get_withdrawal: Fetching histories.");
+    let histories = withdrawal_statuses::Entity::find()
+        .filter(withdrawal_statuses::Column::WithdrawalId.eq(withdrawal_id))
+        .order_by_asc(withdrawal_statuses::Column::Id)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch withdrawal statuses error: {}", e)))?;
+    Ok(GetWithdrawalRes::new(model, histories))
+}
+
+// ============================================================
+// Create
+// ============================================================
+pub async fn create_withdrawal(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: CreateWithdrawalReq,
+) -> Result<CreateWithdrawalRes, ApiError> {
+    // --------------------------------
+    // 1. 申請者が個人であることを確認
+    // --------------------------------
+    if ju.role() != JwtRole::USR {
+        return Err(ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Only individual USR can request withdrawals."));
+    }
+    find_usr_by_type_for_rt(conn, ids.apx_id, ids.vdr_id, ids.usr_id, UsrType::Indi).await.map_err(|e| {
+        if e.status == StatusCode::NOT_FOUND.as_u16() {
+            ApiError::new_system(StatusCode::FORBIDDEN, rterr::ERR_AUTH, "Only individual USR can request withdrawals.")
+        } else {
+            e
+        }
+    })?;
+    // --------------------------------
+    // 2. 個人をロックして申請可能額を検証し、申請と履歴を作成
+    // --------------------------------
+    let apx_id = ids.apx_id;
+    let vdr_id = ids.vdr_id;
+    let usr_id = ids.usr_id;
+    let id = conn.transaction::<_, i32, ApiError>(|tx| {
+        Box::pin(async move {
+            lock_usr(tx, usr_id).await?;
+            let balance = calc_withdrawable_for_rt(tx, apx_id, vdr_id, usr_id).await?;
+            log::debug!("<WithdrawalBl> create_withdrawal: usr: {}, amount: {}, available: {}, pending: {}", usr_id, req.amount, balance.available(), balance.pending);
+            if req.amount as u64 > balance.requestable() {
+                return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Insufficient balance."));
+            }
+            let withdrawal = withdrawals::ActiveModel {
+                usr_id: Set(usr_id),
+                amount: Set(req.amount),
+                status: Set(WithdrawalStatus::Requested as u8),
+                note: Set(req.note.clone()),
+                apx_id: Set(apx_id),
+                vdr_id: Set(vdr_id),
+                ..Default::default()
+            };
+            let withdrawal = withdrawal.insert(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Insert withdrawal error: {}", e)))?;
+            insert_status_history(tx, &withdrawal, WithdrawalStatus::Requested, usr_id, req.note).await?;
+            Ok(withdrawal.id)
+        })
+    }).await?;
+    log::debug!("<WithdrawalBl> create_withdrawal: Success. ID: {}", id);
+    Ok(CreateWithdrawalRes { id: id as u32 })
+}
+
+// ============================================================
+// Approve
+// ============================================================
+pub async fn approve_withdrawal(
+    conn: &DatabaseConnection,
+    ids: &JwtIDs,
+    withdrawal_id: u32,
+    req: ReviewWithdrawalReq,
+) -> Result<ReviewWithdrawalRes, ApiError> {
+    review_withdrawal(conn, ids, withdrawal_id, WithdrawalStatus::Approved, req.note).await
+}
+
+// ============================================================
+// Reject
+// ============================================================
+pub async fn reject_withdrawal(
+    conn: &DatabaseConnection,
+    ids: &JwtIDs,
+    withdrawal_id: u32,
+    req: ReviewWithdrawalReq,
+) -> Result<ReviewWithdrawalRes, ApiError> {
+    review_withdrawal(conn, ids, withdrawal_id, WithdrawalStatus::Rejected, req.note).await
+}
+
+// ============================================================
+// Pay
+// ============================================================
+pub async fn pay_withdrawal(
+    conn: &DatabaseConnection,
+    ids: &JwtIDs,
+    withdrawal_id: u32,
+    req: ReviewWithdrawalReq,
+) -> Result<ReviewWithdrawalRes, ApiError> {
+    review_withdrawal(conn, ids, withdrawal_id, WithdrawalStatus::Paid, req.note).await
+}
@@ -0,0 +1,309 @@
+use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, ColumnTrait, QuerySelect, QueryOrder, Select, ActiveModelTrait, IntoActiveModel, Set, TransactionTrait, Condition};
+use sea_orm::sea_query::Expr;
+use crate::entities::{works, work_corrections};
+use crate::utils::jwt::{JwtUsr, JwtIDs, JwtRole};
+use crate::mode::rt::rtreq::works_req::{SearchWorksReq, CorrectWorkReq};
+use crate::mode::rt::rtres::works_res::{SearchWorksRes, SearchWorksResItem, GetWorkRes, ClockInWorkRes, ClockOutWorkRes, CorrectWorkRes};
+use crate::mode::rt::rtres::errs_res::ApiError;
+use crate::mode::rt::rtutils::config_for_rt::RtConfig;
+use axum::http::StatusCode;
+use crate::mode::rt::rterr::rterr;
+use chrono::{Local, NaiveDateTime, TimeDelta};
+use crate::utils::db::str_to_naive_datetime;
+
+// ============================================================
+// Private Helper for Search and Get
+// ============================================================
+/// 権限に基づいた共通のクエリベースを作成する
+/// USR は自身が法人（from）または就業者（to）である就業情報のみ参照できる
+async fn find_works_base(
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+) -> Result<Select<works::Entity>, ApiError> {
+    let query = works::Entity::find();
+    match ju.role() {
+        JwtRole::BD => {
+            log::debug!("<WorkBl> find_works_base: BD role. No filtering.");
+            Ok(query)
+        }
+        JwtRole::APX => {
+            log::debug!("<WorkBl> find_works_base: APX role. Filter apx_id: {}", ids.apx_id);
+            Ok(query.filter(works::Column::ApxId.eq(ids.apx_id)))
+        }
+        JwtRole::VDR => {
+            log::debug!("<WorkBl> find_works_base: VDR role. Filter apx_id: {}, vdr_id: {}", ids.apx_id, ids.vdr_id);
+            Ok(query
+                .filter(works::Column::ApxId.eq(ids.apx_id))
+                .filter(works::Column::VdrId.eq(ids.vdr_id)))
+        }
+        JwtRole::USR => {
+            log::debug!("<WorkBl> find_works_base: USR role. Filter apx_id: {}, vdr_id: {}, from/to: {}", ids.apx_id, ids.vdr_id, ids.usr_id);
+            Ok(query
+                .filter(works::Column::ApxId.eq(ids.apx_id))
+                .filter(works::Column::VdrId.eq(ids.vdr_id))
+                .filter(Condition::any().add(works::Column::From.eq(ids.usr_id)).add(works::Column::To.eq(ids.usr_id))))
+        }
+    }
+}
+
+/// 就業者本人（to = 自分）の就業情報を取得する（打刻用）
+async fn find_own_work(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    work_id: u32,
+) -> Result<works::Model, ApiError> {
+    find_works_base(ju, ids).await?
+        .filter(works::Column::Id.eq(work_id))
+        .filter(works::Column::To.eq(ids.usr_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch work error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Work not found."))
+}
+
+/// 任意の日時文字列を NaiveDateTime に変換する
+fn parse_opt_datetime(v: &Option<String>, key: &str) -> Result<Option<NaiveDateTime>, ApiError> {
+    match v {
+        Some(s) => str_to_naive_datetime(s)
+            .map(Some)
+            .map_err(|e| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, format!("Invalid {}: {}", key, e))),
+        None => Ok(None),
+    }
+}
+
+// ============================================================
+// Search
+// ============================================================
+pub async fn search_works(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    req: SearchWorksReq,
+) -> Result<SearchWorksRes, ApiError> {
+    // --------------------------------
+    // 1. クエリの基本形を取得
+    // --------------------------------
+    log::debug!("<WorkBl> search_works: Constructing base query.");
+    let mut query = find_works_base(ju, ids).await?;
+    // --------------------------------
+    // 2. 検索条件（完全一致）
+    // --------------------------------
+    if let Some(job_id) = req.job_id {
+        log::debug!("<WorkBl> search_works: Filter by job_id: {}", job_id);
+        query = query.filter(works::Column::JobId.eq(job_id));
+    }
+    if let Some(from) = req.from {
+        log::debug!("<WorkBl> search_works: Filter by from: {}", from);
+        query = query.filter(works::Column::From.eq(from));
+    }
+    if let Some(to) = req.to {
+        log::debug!("<WorkBl> search_works: Filter by to: {}", to);
+        query = query.filter(works::Column::To.eq(to));
+    }
+    // --------------------------------
+    // 3. データの取得
+    // --------------------------------
+    log::debug!("<WorkBl> search_works: Fetching records. limit: {}, offset: {}", req.limit, req.offset);
+    let models = query
+        .order_by_desc(works::Column::Id)
+        .offset(req.offset as u64)
+        .limit(req.limit as u64)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Search query error: {}", e)))?;
+    log::debug!("<WorkBl> search_works: Found {} records.", models.len());
+    // --------------------------------
+    // 4. 最終レスポンス
+    // --------------------------------
+    let works = models.into_iter().map(SearchWorksResItem::from).collect();
+    Ok(SearchWorksRes { works })
+}
+
+// ============================================================
+// Get
+// ============================================================
+pub async fn get_work(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    work_id: u32,
+) -> Result<GetWorkRes, ApiError> {
+    log::debug!("<WorkBl> get_work: Fetching work: {}", work_id);
+    let model = find_works_base(ju, ids).await?
+        .filter(works::Column::Id.eq(work_id))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch work error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Work not found."))?;
+    log::debug!("<WorkBl> get_work: Fetching corrections.");
+    let corrections = work_corrections::Entity::find()
+        .filter(work_corrections::Column::WorkId.eq(work_id))
+        .order_by_asc(work_corrections::Column::Id)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch work corrections error: {}", e)))?;
+    Ok(GetWorkRes::new(model, corrections))
+}
+
+// ============================================================
+// Clock In
+// ============================================================
+pub async fn clock_in_work(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    cfg: &RtConfig,
+    work_id: u32,
+) -> Result<ClockInWorkRes, ApiError> {
+    // --------------------------------
+    // 1. 対象の取得と二重打刻の確認
+    // --------------------------------
+    log::debug!("<WorkBl> clock_in_work: Fetching target work: {}", work_id);
+    let model = find_own_work(conn, ju, ids, work_id).await?;
+    if model.real_work_bgn_at.is_some() {
+        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Already clocked in."));
+    }
+    // --------------------------------
+    // 2. 就業開始日時予定の前後の許容幅を確認
+    // --------------------------------
+    let now = Local::now().naive_local();
+    if let Some(bgn) = model.work_bgn_at {
+        let tolerance = TimeDelta::minutes(cfg.clock_tolerance_min as i64);
+        if now < bgn - tolerance || bgn + tolerance < now {
+            log::debug!("<WorkBl> clock_in_work: Outside window. now: {}, work_bgn_at: {}, tolerance: {}min", now, bgn, cfg.clock_tolerance_min);
+            return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, format!("Clock-in is allowed only within {} minutes of work_bgn_at.", cfg.clock_tolerance_min)));
+        }
+    }
+    // --------------------------------
+    // 3. 保存（未打刻の場合のみ更新し、同時打刻による二重記録を防ぐ）
+    // --------------------------------
+    log::debug!("<WorkBl> clock_in_work: Saving real_work_bgn_at: {}", now);
+    let res = works::Entity::update_many()
+        .col_expr(works::Column::RealWorkBgnAt, Expr::value(now))
+        .col_expr(works::Column::UpdatedAt, Expr::value(now))
+        .filter(works::Column::Id.eq(work_id))
+        .filter(works::Column::RealWorkBgnAt.is_null())
+        .exec(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update work error: {}", e)))?;
+    if res.rows_affected == 0 {
+        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Already clocked in."));
+    }
+    Ok(ClockInWorkRes { id: work_id })
+}
+
+// ============================================================
+// Clock Out
+// ============================================================
+pub async fn clock_out_work(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    work_id: u32,
+) -> Result<ClockOutWorkRes, ApiError> {
+    // --------------------------------
+    // 1. 対象の取得と打刻状態の確認
+    // --------------------------------
+    log::debug!("<WorkBl> clock_out_work: Fetching target work: {}", work_id);
+    let model = find_own_work(conn, ju, ids, work_id).await?;
+    let bgn = model.real_work_bgn_at.ok_or_else(|| ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Not clocked in yet."))?;
+    if model.real_work_end_at.is_some() {
+        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Already clocked out."));
+    }
+    let now = Local::now().naive_local();
+    if now < bgn {
+        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Clock-out cannot be before clock-in."));
+    }
+    // --------------------------------
+    // 2. 保存（未打刻の場合のみ更新し、同時打刻による二重記録を防ぐ）
+    // --------------------------------
+    log::debug!("<WorkBl> clock_out_work: Saving real_work_end_at: {}", now);
+    let res = works::Entity::update_many()
+        .col_expr(works::Column::RealWorkEndAt, Expr::value(now))
+        .col_expr(works::Column::UpdatedAt, Expr::value(now))
+        .filter(works::Column::Id.eq(work_id))
+        .filter(works::Column::RealWorkEndAt.is_null())
+        .exec(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update work error: {}", e)))?;
+    if res.rows_affected == 0 {
+        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Already clocked out."));
+    }
+    Ok(ClockOutWorkRes { id: work_id })
+}
+
+// ============================================================
+// Correct
+// ============================================================
+pub async fn correct_work(
+    conn: &DatabaseConnection,
+    ju: &JwtUsr,
+    ids: &JwtIDs,
+    work_id: u32,
+    req: CorrectWorkReq,
+) -> Result<CorrectWorkRes, ApiError> {
+    // --------------------------------
+    // 1. 対象の取得（USR は自身が法人である就業情報のみ）
+    // --------------------------------
+    log::debug!("<WorkBl> correct_work: Fetching target work: {}", work_id);
+    let mut query = find_works_base(ju, ids).await?.filter(works::Column::Id.eq(work_id));
+    if ju.role() == JwtRole::USR {
+        query = query.filter(works::Column::From.eq(ids.usr_id));
+    }
+    let model = query
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch work error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Work not found."))?;
+    // --------------------------------
+    // 2. 修正後の値の整合性チェック
+    // --------------------------------
+    if req.real_work_bgn_at.is_none() && req.real_work_end_at.is_none() {
+        return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "Nothing to correct."));
+    }
+    let bgn = parse_opt_datetime(&req.real_work_bgn_at, "real_work_bgn_at")?.or(model.real_work_bgn_at);
+    let end = parse_opt_datetime(&req.real_work_end_at, "real_work_end_at")?.or(model.real_work_end_at);
+    match (bgn, end) {
+        (None, Some(_)) => {
+            return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "real_work_end_at requires real_work_bgn_at."));
+        }
+        (Some(b), Some(e)) if e < b => {
+            return Err(ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, "real_work_end_at must be after real_work_bgn_at."));
+        }
+        _ => {}
+    }
+    // --------------------------------
+    // 3. 修正履歴の記録と更新（Transaction）
+    // --------------------------------
+    log::debug!("<WorkBl> correct_work: Saving correction. bgn: {:?}, end: {:?}", bgn, end);
+    let usr_id = ids.usr_id;
+    let staff_id = ju.staff_id.unwrap_or(0);
+    conn.transaction::<_, (), ApiError>(|tx| {
+        Box::pin(async move {
+            let correction = work_corrections::ActiveModel {
+                work_id: Set(work_id),
+                from: Set(model.from),
+                to: Set(model.to),
+                usr_id: Set(usr_id),
+                staff_id: Set(staff_id),
+                bef_real_work_bgn_at: Set(model.real_work_bgn_at),
+                bef_real_work_end_at: Set(model.real_work_end_at),
+                aft_real_work_bgn_at: Set(bgn),
+                aft_real_work_end_at: Set(end),
+                reason: Set(req.reason),
+                apx_id: Set(model.apx_id),
+                vdr_id: Set(model.vdr_id),
+                ..Default::default()
+            };
+            correction.insert(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Insert work correction error: {}", e)))?;
+            let mut active: works::ActiveModel = model.into_active_model();
+            active.real_work_bgn_at = Set(bgn);
+            active.real_work_end_at = Set(end);
+            active.update(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update work error: {}", e)))?;
+            log::debug!("<WorkBl> correct_work: Transaction success.");
+            Ok(())
+        })
+    }).await?;
+    Ok(CorrectWorkRes { id: work_id })
+}
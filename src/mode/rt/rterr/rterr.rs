@@ -7,6 +7,7 @@ pub const ERR_AUTH: &str = "E0003";
 pub const ERR_VALIDATION: &str = "E0004";
 pub const ERR_INVALID_REQUEST: &str = "E0005";
 pub const ERR_NOT_FOUND: &str = "E0012";
+pub const ERR_CONFLICT: &str = "E0024";
 
 // ================================
 // 具体バリデーションエラー
@@ -77,3 +78,5 @@ define_numeric_adapter!(numeric_err, "E0022", "Must be numeric.");
 
 // `#[garde(custom(datetime_err))]` - 日時形式 "YYYY-MM-DDThh:mm:ss"
 define_datetime_adapter!(datetime_err, "%Y-%m-%dT%H:%M:%S", "E0023", "Invalid datetime format.");
+// `#[garde(custom(date_err))]` - 日付形式 "YYYY-MM-DD"
+define_date_adapter!(date_err, "%Y-%m-%d", "E0025", "Invalid date format.");
@@ -103,6 +103,23 @@ macro_rules! define_datetime_adapter {
     };
 }
 
+#[macro_export]
+macro_rules! define_date_adapter {
+    ($name:ident, $format:expr, $code:expr, $msg:expr) => {
+        pub fn $name<T: AsRef<str>>(v: &T, _ctx: &()) -> garde::Result {
+            let s = v.as_ref();
+            if chrono::NaiveDate::parse_from_str(s, $format).is_ok() {
+                Ok(())
+            } else {
+                Err(garde::Error::new(format!(
+                    "{} | {} (Expected format: {})",
+                    $code, $msg, $format
+                )))
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! define_numeric_adapter {
     ($name:ident, $code:expr, $msg:expr) => {
@@ -0,0 +1,257 @@
+use std::sync::Arc;
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use garde::Validate;
+use crate::{
+    mode::rt::{
+        rtreq::badges_req::{SearchBadgesReq, CreateBadgeReq, UpdateBadgeReq},
+        rtres::{errs_res::ApiError, badges_res::{SearchBadgesRes, GetBadgeRes, CreateBadgeRes, UpdateBadgeRes, RetireBadgeRes}},
+        rtutils::db_for_rt::DbPoolsExt
+    },
+    utils::{db::DbPools, jwt::{JwtUsr, JwtIDs, JwtRole}}
+};
+
+const TAG: &str = "v1 Badge";
+
+// ============================================================
+// Search
+// ============================================================
+const SEARCH_DESC: &str = r#"
+### ⚫︎ 概要
+- BD は全てのバッジを検索できる
+- APX は配下の VDR 以下の全てのバッジを検索できる
+- VDR は、配下の全てのバッジを検索できる
+- USR は、同一 VDR 内の全てのバッジを検索できる
+- 法人が自分のバッジ一覧を取得する場合は corp_id に自分の UsrID を指定する
+- 廃止済みのバッジは include_retired が true の場合のみ含まれる
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `name` | string | max=50 | バッジ名（部分一致） |
+| `corp_id` | number | gte=1 | 発行した法人ID |
+| `include_retired` | boolean | | 廃止済みを含めるか |
+| `limit` | number | gte=1, lte=25 | 取得数 |
+| `offset` | number | gte=0 | オフセット |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/badges/search",
+    summary = "バッジを検索する。",
+    description = SEARCH_DESC,
+    request_body = SearchBadgesReq,
+    responses(
+        (status = 200, description = "Success", body = SearchBadgesRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn search_badges(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<SearchBadgesReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::BD, JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::badges_bl::search_badges(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Get
+// ============================================================
+const GET_DESC: &str = r#"
+### ⚫︎ 概要
+- APX は配下の VDR 以下の全てのバッジを取得できる
+- VDR は、配下の全てのバッジを取得できる
+- USR は、同一 VDR 内の全てのバッジを取得できる（廃止済みも含む）
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `badge_id` | number | required, gte=1 | バッジID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    get,
+    security(("api_jwt_token" = [])),
+    path = "/badges/{badge_id}",
+    summary = "バッジを1件取得する。",
+    description = GET_DESC,
+    params(
+        ("badge_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetBadgeRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn get_badge(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(badge_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::badges_bl::get_badge(conn, &ju, &ids, badge_id).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Create
+// ============================================================
+const CREATE_DESC: &str = r#"
+### ⚫︎ 概要
+- 法人 USR のみがバッジを作成できる（個人 USR は 403）
+- 作成したバッジの corp_id は自分自身となる
+- short_name は法人内で一意でなければならず、重複する場合は 409 となる（廃止済みのバッジも対象）
+
+### 有効期間について
+- valid_months を指定すると、資格型のバッジとして授与から valid_months ヶ月後に失効する（省略または 0 は無期限）
+- 授与ごとの有効期限（usr_badges.expires_at）は授与時点の valid_months で決まり、後から変更しても既存の授与には影響しない
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `name` | string | required, max=50 | バッジ名 |
+| `short_name` | string | required, max=20 | 表示用の短い名前 |
+| `description` | string | max=255 | 説明 |
+| `valid_months` | number | gte=0, lte=120 | 有効期間（月）。0 は無期限 |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/badges",
+    summary = "バッジを新規作成する。",
+    description = CREATE_DESC,
+    request_body = CreateBadgeReq,
+    responses(
+        (status = 200, description = "Success", body = CreateBadgeRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn create_badge(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<CreateBadgeReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::badges_bl::create_badge(conn, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Update
+// ============================================================
+const UPDATE_DESC: &str = r#"
+### ⚫︎ 概要
+- 法人 USR は、自分が作成したバッジのみ更新できる
+- 省略した項目は現在の値のまま
+- 廃止済みのバッジは更新できず 409 となる
+- short_name を変更する場合、法人内で重複すると 409 となる
+- valid_months の変更は、以降の授与の有効期限にのみ反映される
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `badge_id` | number | required, gte=1 | バッジID |
+| `name` | string | min=1, max=50 | バッジ名 |
+| `short_name` | string | min=1, max=20 | 表示用の短い名前 |
+| `description` | string | max=255 | 説明 |
+| `valid_months` | number | gte=0, lte=120 | 有効期間（月）。0 は無期限 |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    patch,
+    security(("api_jwt_token" = [])),
+    path = "/badges/{badge_id}",
+    summary = "バッジを更新する。",
+    description = UPDATE_DESC,
+    params(
+        ("badge_id" = u32, Path),
+    ),
+    request_body = UpdateBadgeReq,
+    responses(
+        (status = 200, description = "Success", body = UpdateBadgeRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn update_badge(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(badge_id): Path<u32>,
+    Json(req): Json<UpdateBadgeReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::badges_bl::update_badge(conn, &ju, &ids, badge_id, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Retire
+// ============================================================
+const RETIRE_DESC: &str = r#"
+### ⚫︎ 概要
+- 法人 USR は、自分が作成したバッジを廃止できる
+- 廃止されたバッジは新たに授与できなくなる
+- バッジは削除されないため、既に授与された履歴（usr_badges）は保持される
+- 既に廃止済みの場合は 409 となる
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `badge_id` | number | required, gte=1 | バッジID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    patch,
+    security(("api_jwt_token" = [])),
+    path = "/badges/{badge_id}/retire",
+    summary = "バッジを廃止する。",
+    description = RETIRE_DESC,
+    params(
+        ("badge_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = RetireBadgeRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn retire_badge(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(badge_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::USR])?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::badges_bl::retire_badge(conn, &ju, &ids, badge_id).await?;
+    Ok(Json(res))
+}
@@ -0,0 +1,220 @@
+use std::sync::Arc;
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use garde::Validate;
+use crate::{
+    mode::rt::{
+        rtreq::bank_accounts_req::{SearchBankAccountsReq, CreateBankAccountReq, UpdateBankAccountReq},
+        rtres::{errs_res::ApiError, bank_accounts_res::{SearchBankAccountsRes, GetBankAccountRes, CreateBankAccountRes, UpdateBankAccountRes}},
+        rtutils::db_for_rt::DbPoolsExt
+    },
+    utils::{db::DbPools, jwt::{JwtUsr, JwtIDs, JwtRole, JwtConfig}}
+};
+
+const TAG: &str = "v1 BankAccount";
+
+// ============================================================
+// Search
+// ============================================================
+const SEARCH_DESC: &str = r#"
+### ⚫︎ 概要
+- BD は全ての振込先口座を検索できる
+- APX は配下の VDR 以下の全ての振込先口座を検索できる
+- VDR は、配下の全ての振込先口座を検索できる
+- USR は、自分の振込先口座のみ検索できる
+
+### マスクについて
+- 口座番号は下 4 桁のみ返し、それ以外はマスクする（例: ***4567）
+- 口座名義は返さない
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `usr_id` | number | gte=1 | 口座を所有する個人ID |
+| `limit` | number | gte=1, lte=25 | 取得数 |
+| `offset` | number | gte=0 | オフセット |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/bank_accounts/search",
+    summary = "振込先口座を検索する。",
+    description = SEARCH_DESC,
+    request_body = SearchBankAccountsReq,
+    responses(
+        (status = 200, description = "Success", body = SearchBankAccountsRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn search_bank_accounts(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<SearchBankAccountsReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::BD, JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::bank_accounts_bl::search_bank_accounts(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Get
+// ============================================================
+const GET_DESC: &str = r#"
+### ⚫︎ 概要
+- APX は配下の VDR 以下の全ての振込先口座を取得できる
+- VDR は、配下の全ての振込先口座を取得できる
+- USR は、自分の振込先口座のみ取得できる
+- 口座番号は下 4 桁以外をマスクし、口座名義は返さない
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `bank_account_id` | number | required, gte=1 | 振込先口座ID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    get,
+    security(("api_jwt_token" = [])),
+    path = "/bank_accounts/{bank_account_id}",
+    summary = "振込先口座を1件取得する。",
+    description = GET_DESC,
+    params(
+        ("bank_account_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetBankAccountRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn get_bank_account(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(bank_account_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::bank_accounts_bl::get_bank_account(conn, &ju, &ids, bank_account_id).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Create
+// ============================================================
+const CREATE_DESC: &str = r#"
+### ⚫︎ 概要
+- 個人 USR が自分の振込先口座を登録する（1 人 1 口座）
+- 登録済みの場合は 409 となる
+- 法人・VDR・APX は登録できない
+
+### 保存について
+- 口座番号と口座名義は AES-256-GCM（RT_CRYPTO_KEY）で暗号化して保存する
+- 金融機関名・支店名・口座名義は全銀協規定の半角カナへ変換して保存する（変換できない文字を含む場合は 400）
+- 変換後の桁数は、金融機関名・支店名が 15 桁、口座名義が 30 桁まで
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `bank_code` | string | required, numeric, len=4 | 金融機関コード |
+| `bank_name` | string | required, max=15 | 金融機関名（カナ） |
+| `branch_code` | string | required, numeric, len=3 | 支店コード |
+| `branch_name` | string | required, max=15 | 支店名（カナ） |
+| `account_type` | number | required, oneof=1 2 4 | 1:普通, 2:当座, 4:貯蓄 |
+| `account_number` | string | required, numeric, max=7 | 口座番号 |
+| `holder` | string | required, max=30 | 口座名義（カナ） |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/bank_accounts",
+    summary = "振込先口座を登録する。",
+    description = CREATE_DESC,
+    request_body = CreateBankAccountReq,
+    responses(
+        (status = 200, description = "Success", body = CreateBankAccountRes),
+        (status = 400, description = "Bad Request", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn create_bank_account(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Extension(jwt_config): Extension<Arc<JwtConfig>>,
+    Json(req): Json<CreateBankAccountReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::bank_accounts_bl::create_bank_account(conn, &ids, &jwt_config.crypto_key, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Update
+// ============================================================
+const UPDATE_DESC: &str = r#"
+### ⚫︎ 概要
+- 個人 USR は、自分の振込先口座のみ更新できる
+- 省略した項目は現在の値のまま
+- 保存形式（暗号化・半角カナ変換）は登録時と同じ
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `bank_account_id` | number | required, gte=1 | 振込先口座ID |
+| `bank_code` | string | numeric, len=4 | 金融機関コード |
+| `bank_name` | string | min=1, max=15 | 金融機関名（カナ） |
+| `branch_code` | string | numeric, len=3 | 支店コード |
+| `branch_name` | string | min=1, max=15 | 支店名（カナ） |
+| `account_type` | number | oneof=1 2 4 | 1:普通, 2:当座, 4:貯蓄 |
+| `account_number` | string | numeric, max=7 | 口座番号 |
+| `holder` | string | min=1, max=30 | 口座名義（カナ） |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    patch,
+    security(("api_jwt_token" = [])),
+    path = "/bank_accounts/{bank_account_id}",
+    summary = "振込先口座を更新する。",
+    description = UPDATE_DESC,
+    params(
+        ("bank_account_id" = u32, Path),
+    ),
+    request_body = UpdateBankAccountReq,
+    responses(
+        (status = 200, description = "Success", body = UpdateBankAccountRes),
+        (status = 400, description = "Bad Request", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn update_bank_account(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Extension(jwt_config): Extension<Arc<JwtConfig>>,
+    Path(bank_account_id): Path<u32>,
+    Json(req): Json<UpdateBankAccountReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::bank_accounts_bl::update_bank_account(conn, &ids, &jwt_config.crypto_key, bank_account_id, req).await?;
+    Ok(Json(res))
+}
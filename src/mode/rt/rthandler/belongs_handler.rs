@@ -0,0 +1,212 @@
+use std::sync::Arc;
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use garde::Validate;
+use crate::{
+    mode::rt::{
+        rtreq::belongs_req::{SearchBelongsReq, CreateBelongReq, CloseBelongReq},
+        rtres::{errs_res::ApiError, belongs_res::{SearchBelongsRes, GetBelongRes, CreateBelongRes, CloseBelongRes}},
+        rtutils::db_for_rt::DbPoolsExt
+    },
+    utils::{db::DbPools, jwt::{JwtUsr, JwtIDs, JwtRole}}
+};
+
+const TAG: &str = "v1 Belong";
+
+// ============================================================
+// Search
+// ============================================================
+const SEARCH_DESC: &str = r#"
+### ⚫︎ 概要
+- BD は全ての所属を検索できる
+- APX は配下の VDR 以下の全ての所属を検索できる
+- VDR は、配下の全ての所属を検索できる
+- USR は、自分が法人（corp_id）または個人（usr_id）である所属のみ検索できる
+
+### 指定日時での所属について
+- at を指定すると、その日時に有効な所属のみを返す
+- 所属期間は open_at 以上 close_at 未満とし、未設定の側は無期限として扱う
+- usr_id と at を指定することで、ある個人のその時点での所属先を取得できる
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `corp_id` | number | gte=1 | 所属先法人ID |
+| `usr_id` | number | gte=1 | 個人ID |
+| `at` | string | datetime | 有効日時 |
+| `limit` | number | gte=1, lte=25 | 取得数 |
+| `offset` | number | gte=0 | オフセット |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/belongs/search",
+    summary = "所属を検索する。",
+    description = SEARCH_DESC,
+    request_body = SearchBelongsReq,
+    responses(
+        (status = 200, description = "Success", body = SearchBelongsRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn search_belongs(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<SearchBelongsReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::BD, JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::belongs_bl::search_belongs(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Get
+// ============================================================
+const GET_DESC: &str = r#"
+### ⚫︎ 概要
+- APX は配下の VDR 以下の全ての所属を取得できる
+- VDR は、配下の全ての所属を取得できる
+- USR は、自分が当事者である所属のみ取得できる
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `belong_id` | number | required, gte=1 | 所属ID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    get,
+    security(("api_jwt_token" = [])),
+    path = "/belongs/{belong_id}",
+    summary = "所属を1件取得する。",
+    description = GET_DESC,
+    params(
+        ("belong_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetBelongRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn get_belong(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(belong_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::belongs_bl::get_belong(conn, &ju, &ids, belong_id).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Create
+// ============================================================
+const CREATE_DESC: &str = r#"
+### ⚫︎ 概要
+- BD / APX は所属を作成できない
+- VDR は、配下の法人を corp_id で指定して、個人を所属させることができる
+- USR は、法人である場合のみ、自分自身に個人を所属させることができる（corp_id は指定しないこと）
+- 所属する側（usr_id）は同一 VDR 内の個人でなければならない
+- 同一の法人・個人で、open_at 以降も有効な所属が既に存在する場合は 409 となる
+
+### 所属割増について
+- 所属期間中に当該法人の関係で個人へポイントが付与される場合、VDR の belong_rate による割増が適用される
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `corp_id` | number | ⭐️ VDR必須, 🔴 USRでは入れないこと, gte=1 | 所属先法人ID |
+| `usr_id` | number | required, gte=1 | 個人ID |
+| `open_at` | string | datetime | 所属開始日時（省略時は現在日時） |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/belongs",
+    summary = "所属を新規作成する。",
+    description = CREATE_DESC,
+    request_body = CreateBelongReq,
+    responses(
+        (status = 200, description = "Success", body = CreateBelongRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn create_belong(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<CreateBelongReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::belongs_bl::create_belong(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Close
+// ============================================================
+const CLOSE_DESC: &str = r#"
+### ⚫︎ 概要
+- 所属を終了する（close_at を設定する）
+- VDR は、配下の全ての所属を終了できる
+- USR は、自分が法人または個人として当事者である所属のみ終了できる
+- 既に終了している所属は 409 となる
+- 所属情報は削除されず、過去の時点での所属判定に引き続き使用される
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `belong_id` | number | required, gte=1 | 所属ID |
+| `close_at` | string | datetime | 所属終了日時（省略時は現在日時、open_at 以降であること） |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    patch,
+    security(("api_jwt_token" = [])),
+    path = "/belongs/{belong_id}/close",
+    summary = "所属を終了する。",
+    description = CLOSE_DESC,
+    params(
+        ("belong_id" = u32, Path),
+    ),
+    request_body = CloseBelongReq,
+    responses(
+        (status = 200, description = "Success", body = CloseBelongRes),
+        (status = 400, description = "Bad Request", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn close_belong(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(belong_id): Path<u32>,
+    Json(req): Json<CloseBelongReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::belongs_bl::close_belong(conn, &ju, &ids, belong_id, req).await?;
+    Ok(Json(res))
+}
@@ -0,0 +1,170 @@
+use std::sync::Arc;
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use garde::Validate;
+use crate::{
+    mode::rt::{
+        rtreq::collusion_req::CollusionReportReq,
+        rtres::{errs_res::ApiError, collusion_res::{CollusionReportRes, HoldCollusionAwardsRes, ReleaseCollusionHoldRes}},
+        rtutils::db_for_rt::DbPoolsExt
+    },
+    utils::{db::DbPools, jwt::{JwtUsr, JwtIDs, JwtRole}}
+};
+
+const TAG: &str = "v1 Collusion";
+
+// ============================================================
+// Report
+// ============================================================
+const REPORT_DESC: &str = r#"
+### ⚫︎ 概要
+- 個人間のバッジ授与（usr_badges.type = 2）の授与者（from）→ 受領者（to）のグラフを分析し、共謀の疑いがある授与を検出する
+- VDR、または スタッフ token を持つ USR のみ操作できる（それ以外は 403）
+- 分析対象は、操作者と同じ VDR 配下で直近 days 日以内の、取り消されていない授与
+- 参照のみで、DB への書き込みは行わない（保留は POST /collusion/holds で行う）
+
+### 検出について
+- pairs: 互いに min_reciprocal 回以上ずつ授与し合っている 2 人（相互授与）
+- cliques: min_clique 人以上で、全ての 2 人の間に授与（方向は問わない）があるグループ（極大なもののみ）
+- bursts: 同じ授与者による、burst_hours 時間以内に burst_count 件以上の授与（集中）
+- flagged: 相互授与の 2 人の間、グループのメンバー間、集中に含まれる授与の UsrBadgeID
+- usrs: 個人ごとの検出数と、受領した疑わしい授与の件数（flagged_awards）・ポイント（flagged_points）。flagged_points の多い順
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `days` | number | gte=1, lte=365 | 分析対象の期間（日）。未指定の場合は 30 |
+| `min_reciprocal` | number | gte=1, lte=100 | 相互授与とみなす各方向の最小授与数。未指定の場合は 1 |
+| `min_clique` | number | gte=3, lte=10 | グループとみなす最小人数。未指定の場合は 3 |
+| `burst_hours` | number | gte=1, lte=168 | 集中とみなす時間幅（時間）。未指定の場合は 24 |
+| `burst_count` | number | gte=2, lte=1000 | 集中とみなす授与数。未指定の場合は 10 |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/collusion/report",
+    summary = "共謀の疑いがある授与を検出する。",
+    description = REPORT_DESC,
+    request_body = CollusionReportReq,
+    responses(
+        (status = 200, description = "Success", body = CollusionReportRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn get_collusion_report(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<CollusionReportReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::collusion_bl::get_collusion_report(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Hold
+// ============================================================
+const HOLD_DESC: &str = r#"
+### ⚫︎ 概要
+- POST /collusion/report と同じ条件で共謀の疑いを検出し、flagged の授与を分配（flush）の対象から保留する
+- VDR、または スタッフ token を持つ USR のみ操作できる（それ以外は 403）
+- 保留は授与ごとに、授与と受領者の行をロックしたトランザクションで行うため、分配・取り消しとは直列化される
+
+### 保留について
+- 保留した授与のポイント（held_points = point + extra）は受領者の保有ポイントに残したまま、分配対象から除かれる
+- 既に分配済み（usr_badges.flush_id が設定済み）・取り消し済み・保留中の授与は保留しない
+- 検出した授与の UsrBadgeID を flagged で、今回保留した授与の UsrBadgeID を held で返す
+- 保留は解除（PATCH /collusion/holds/{usr_badge_id}/release）されるか、授与が取り消される（PATCH /usr_badges/{usr_badge_id}/revoke）まで続く
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `days` | number | gte=1, lte=365 | 分析対象の期間（日）。未指定の場合は 30 |
+| `min_reciprocal` | number | gte=1, lte=100 | 相互授与とみなす各方向の最小授与数。未指定の場合は 1 |
+| `min_clique` | number | gte=3, lte=10 | グループとみなす最小人数。未指定の場合は 3 |
+| `burst_hours` | number | gte=1, lte=168 | 集中とみなす時間幅（時間）。未指定の場合は 24 |
+| `burst_count` | number | gte=2, lte=1000 | 集中とみなす授与数。未指定の場合は 10 |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/collusion/holds",
+    summary = "共謀の疑いがある授与を保留する。",
+    description = HOLD_DESC,
+    request_body = CollusionReportReq,
+    responses(
+        (status = 200, description = "Success", body = HoldCollusionAwardsRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn hold_collusion_awards(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<CollusionReportReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::collusion_bl::hold_collusion_awards(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Release
+// ============================================================
+const RELEASE_DESC: &str = r#"
+### ⚫︎ 概要
+- 保留中の授与の保留を解除し、以降の分配（flush）の対象に戻す
+- VDR、または スタッフ token を持つ USR のみ操作できる（それ以外は 403）
+- USR は、自分が授与者・受領者である授与の保留は解除できない（403）
+- 保留中でない場合は 409 となる
+- 授与と受領者の行をロックしたトランザクションで解除するため、分配（flush）・取り消しとは直列化される
+- 保留中の授与は分配済み（flush_id）にならないため、解除後の最初の分配で受領者の他の授与と同様に分配され、flush_id が記録される
+- 保留を行った ID（held_by / held_staff_id）と held_points は記録として残る
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `usr_badge_id` | number | required, gte=1 | バッジ授与ID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    patch,
+    security(("api_jwt_token" = [])),
+    path = "/collusion/holds/{usr_badge_id}/release",
+    summary = "授与の保留を解除する。",
+    description = RELEASE_DESC,
+    params(
+        ("usr_badge_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = ReleaseCollusionHoldRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn release_collusion_hold(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(usr_badge_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::collusion_bl::release_collusion_hold(conn, &ju, &ids, usr_badge_id).await?;
+    Ok(Json(res))
+}
@@ -0,0 +1,370 @@
+use std::sync::Arc;
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use garde::Validate;
+use crate::{
+    mode::rt::{
+        rtreq::flushes_req::{SearchFlushesReq, PreviewFlushReq, SearchPayoutsReq, SummarizeWithholdingReq, ExportFlushZenginReq},
+        rtres::{errs_res::ApiError, flushes_res::{SearchFlushesRes, GetFlushRes, CreateFlushRes, PreviewFlushRes, SearchPayoutsRes, SummarizeWithholdingRes, ExportFlushZenginRes}},
+        rtutils::db_for_rt::DbPoolsExt
+    },
+    utils::{db::DbPools, s3client::S3Client, jwt::{JwtUsr, JwtIDs, JwtRole, JwtConfig}}
+};
+
+const TAG: &str = "v1 Flush";
+
+// ============================================================
+// Search
+// ============================================================
+const SEARCH_DESC: &str = r#"
+### ⚫︎ 概要
+- BD は全ての分配を検索できる
+- APX は配下の VDR 以下の全ての分配を検索できる
+- VDR は、配下の全ての分配を検索できる
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `limit` | number | gte=1, lte=25 | 取得数 |
+| `offset` | number | gte=0 | オフセット |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/flushes/search",
+    summary = "分配を検索する。",
+    description = SEARCH_DESC,
+    request_body = SearchFlushesReq,
+    responses(
+        (status = 200, description = "Success", body = SearchFlushesRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn search_flushes(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<SearchFlushesReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::BD, JwtRole::APX, JwtRole::VDR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::flushes_bl::search_flushes(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Get
+// ============================================================
+const GET_DESC: &str = r#"
+### ⚫︎ 概要
+- APX は配下の VDR 以下の全ての分配を取得できる
+- VDR は、配下の全ての分配を取得できる
+- 個人ごとの分配（payouts）を含めて返す
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `flush_id` | number | required, gte=1 | 分配ID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    get,
+    security(("api_jwt_token" = [])),
+    path = "/flushes/{flush_id}",
+    summary = "分配を1件取得する。",
+    description = GET_DESC,
+    params(
+        ("flush_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetFlushRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn get_flush(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(flush_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::APX, JwtRole::VDR])?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::flushes_bl::get_flush(conn, &ju, &ids, flush_id).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Create
+// ============================================================
+const CREATE_DESC: &str = r#"
+### ⚫︎ 概要
+- VDR が現金プールの残高（pools.remain）を個人へ分配する
+- 分配対象は VDR 内でポイントを保有している個人
+- 共謀の疑いで保留中の授与（POST /collusion/report で hold を指定）のポイントは分配対象から除き、保有ポイントに残す
+- プールが空の場合、分配対象のポイントが 0 の場合、または事務費用の控除後に分配額が 0 となる場合は 409 となる
+
+### 金額について
+- fee（事務費用）は floor(remain × VDR の flush_fee_rate)
+- 分配額（distributable）は remain - fee
+- 各個人へは floor(distributable × points / 総ポイント) を配り、切り捨てで余った額は剰余の大きい順に 1 円ずつ配る
+- 分配金額の合計は必ず distributable と一致する
+
+### 源泉徴収について
+- 各個人の分配金額（総額）に VDR の源泉徴収ルールを適用し、源泉徴収額（withheld）と差引支払額（net）を payouts に保存する
+- 総額が withholding_threshold 未満の場合は源泉徴収しない
+- 総額のうち withholding_reduced_limit までの部分は withholding_reduced_rate、超える部分は withholding_rate を適用し、それぞれ 1 円未満を切り捨てる
+- 全銀フォーマットでの振込額・出金可能残高は net を用いる
+
+### 更新内容について
+- 以下は全て同一トランザクションで行われ、プールと分配対象の個人は行ロックされる
+- flushes を 1 件、payouts を個人ごとに 1 件作成する
+- 各個人の points から分配時の points を減算し（保留中のポイントのみが残る）、sum_p に分配時の points、sum_c に分配金額を加算する
+- プールの remain を 0 にし、total_out に remain を加算する
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/flushes",
+    summary = "現金プールを個人へ分配する。",
+    description = CREATE_DESC,
+    responses(
+        (status = 200, description = "Success", body = CreateFlushRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn create_flush(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR])?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::flushes_bl::create_flush(conn, &ids).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Preview
+// ============================================================
+const PREVIEW_DESC: &str = r#"
+### ⚫︎ 概要
+- VDR が分配を実行した場合の結果を試算する
+- DB への書き込みは一切行わない
+- 実際の分配（POST /flushes）と同じ計算処理を用いるため、同じ状態であれば試算結果と実行結果は必ず一致する
+
+### total について
+- 未指定の場合は現在のプール残高（pools.remain）で試算する
+- 指定した場合はその額がプールにあるものとして試算する（分配対象とポイントは現在の値を用いる）
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `total` | number | gte=1 | 仮定するプール残高 |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/flushes/preview",
+    summary = "分配結果を試算する。",
+    description = PREVIEW_DESC,
+    request_body = PreviewFlushReq,
+    responses(
+        (status = 200, description = "Success", body = PreviewFlushRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn preview_flush(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<PreviewFlushReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::flushes_bl::preview_flush(conn, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Search Payouts
+// ============================================================
+const SEARCH_PAYOUTS_DESC: &str = r#"
+### ⚫︎ 概要
+- BD は全ての個人への分配を検索できる
+- APX は配下の VDR 以下の全ての個人への分配を検索できる
+- VDR は、配下の全ての個人への分配を検索できる
+- USR は、自分への分配のみ検索できる
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `flush_id` | number | gte=1 | 分配ID |
+| `usr_id` | number | gte=1 | 個人ID |
+| `limit` | number | gte=1, lte=25 | 取得数 |
+| `offset` | number | gte=0 | オフセット |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/payouts/search",
+    summary = "個人への分配を検索する。",
+    description = SEARCH_PAYOUTS_DESC,
+    request_body = SearchPayoutsReq,
+    responses(
+        (status = 200, description = "Success", body = SearchPayoutsRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn search_payouts(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<SearchPayoutsReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::BD, JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::flushes_bl::search_payouts(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Withholding Summary
+// ============================================================
+const SUMMARIZE_WITHHOLDING_DESC: &str = r#"
+### ⚫︎ 概要
+- 指定した暦年の、個人ごとの分配金額（総額）・源泉徴収額・差引支払額を集計する
+- 支払調書や源泉徴収の納付額の確認に用いる
+- BD は全ての個人を集計できる
+- APX は配下の VDR 以下の全ての個人を集計できる
+- VDR は、配下の全ての個人を集計できる
+- USR は、自分のみ集計できる
+
+### 集計について
+- 対象は分配日時（payouts.created_at）が year の 1/1 00:00:00 〜 12/31 23:59:59 の payouts
+- 個人ごと（apx_id, vdr_id, usr_id）に集計し、全体の合計も返す
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `year` | number | required, gte=2000, lte=2100 | 集計する暦年 |
+| `usr_id` | number | gte=1 | 個人ID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/payouts/withholding",
+    summary = "年間の源泉徴収額を個人ごとに集計する。",
+    description = SUMMARIZE_WITHHOLDING_DESC,
+    request_body = SummarizeWithholdingReq,
+    responses(
+        (status = 200, description = "Success", body = SummarizeWithholdingRes),
+        (status = 400, description = "Bad Request", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn summarize_withholding(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<SummarizeWithholdingReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::BD, JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::flushes_bl::summarize_withholding(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Export Zengin
+// ============================================================
+const EXPORT_ZENGIN_DESC: &str = r#"
+### ⚫︎ 概要
+- VDR が、分配（flush）の個人ごとの分配（payouts）を全銀フォーマット（総合振込）の振込ファイルとして出力する
+- 出力したファイルはアップロードされ、そのキーを返す
+- 分配金額が 0 の payout は出力しない
+- 振込先口座（bank_accounts）が登録されていない個人が含まれる場合は 409 となる
+- 出力済みの payout は再度出力しない。出力対象の payout が無い場合は 409 となる
+- 出力した payout は出金可能残高（withdrawals）から除外される
+- 出金申請（withdrawals）の承認・支払済みにより既に引き出された payout を含む個人がいる場合は、二重払いを防ぐため 409 となる
+
+### ファイル形式について
+- ヘッダー・データ・トレーラー・エンドの各レコードを 120 バイト固定長 + CRLF で出力する
+- 文字コードは Shift_JIS、名称は全銀協規定の半角カナへ変換する
+- データレコードの顧客コード1には、突合用に UsrID を 10 桁ゼロ埋めで出力する
+- 振込先の口座番号と口座名義は、この出力処理でのみ復号される
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `flush_id` | number | required, gte=1 | 分配ID |
+| `consignor_code` | string | required, numeric, len=10 | 振込依頼人コード |
+| `consignor_name` | string | required, max=40 | 振込依頼人名（カナ） |
+| `transfer_date` | string | required, date | 振込指定日（YYYY-MM-DD） |
+| `bank_code` | string | required, numeric, len=4 | 振込元の金融機関コード |
+| `bank_name` | string | required, max=15 | 振込元の金融機関名（カナ） |
+| `branch_code` | string | required, numeric, len=3 | 振込元の支店コード |
+| `branch_name` | string | required, max=15 | 振込元の支店名（カナ） |
+| `account_type` | number | required, oneof=1 2 4 | 振込元の預金種目（1:普通, 2:当座, 4:貯蓄） |
+| `account_number` | string | required, numeric, max=7 | 振込元の口座番号 |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/flushes/{flush_id}/zengin",
+    summary = "分配を全銀フォーマットの振込ファイルとして出力する。",
+    description = EXPORT_ZENGIN_DESC,
+    params(
+        ("flush_id" = u32, Path),
+    ),
+    request_body = ExportFlushZenginReq,
+    responses(
+        (status = 200, description = "Success", body = ExportFlushZenginRes),
+        (status = 400, description = "Bad Request", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn export_flush_zengin(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Extension(s3c): Extension<Arc<S3Client>>,
+    Extension(jwt_config): Extension<Arc<JwtConfig>>,
+    Path(flush_id): Path<u32>,
+    Json(req): Json<ExportFlushZenginReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::flushes_bl::export_flush_zengin(conn, &ids, &s3c, &jwt_config.crypto_key, flush_id, req).await?;
+    Ok(Json(res))
+}
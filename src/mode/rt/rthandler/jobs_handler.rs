@@ -0,0 +1,283 @@
+use std::sync::Arc;
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use garde::Validate;
+use crate::{
+    mode::rt::{
+        rtreq::jobs_req::{SearchJobsReq, CreateJobReq, UpdateJobReq},
+        rtres::{errs_res::ApiError, jobs_res::{SearchJobsRes, GetJobRes, CreateJobRes, UpdateJobRes, DeleteJobRes}},
+        rtutils::db_for_rt::DbPoolsExt
+    },
+    utils::{db::DbPools, jwt::{JwtUsr, JwtIDs, JwtRole}}
+};
+
+const TAG: &str = "v1 Job";
+
+// ============================================================
+// Search
+// ============================================================
+const SEARCH_DESC: &str = r#"
+### ⚫︎ 概要
+- BD は全ての求人を検索できる
+- APX は配下の VDR 以下の全ての求人を検索できる
+- VDR は、配下の全ての求人を検索できる
+- USR は、所属する VDR 内の全ての求人を検索できる（法人・個人を問わない）
+
+### 検索期間について
+- 募集期間 [open_at, close_at] が [bgn_at, end_at] と重なる求人を返す
+- open_at / close_at が未設定の求人は、その側を無期限として扱う
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `name` | string | max=100 | 求人名（部分一致） |
+| `location` | string | max=128 | 勤務地（部分一致） |
+| `corp_id` | number | gte=1 | 発行元法人ID |
+| `bgn_at` | string | required, datetime | 開始日時 |
+| `end_at` | string | required, datetime | 終了日時 |
+| `limit` | number | gte=1, lte=25 | 取得数 |
+| `offset` | number | gte=0 | オフセット |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/jobs/search",
+    summary = "求人を検索する。",
+    description = SEARCH_DESC,
+    request_body = SearchJobsReq,
+    responses(
+        (status = 200, description = "Success", body = SearchJobsRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn search_jobs(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<SearchJobsReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::BD, JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::jobs_bl::search_jobs(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Get
+// ============================================================
+const GET_DESC: &str = r#"
+### ⚫︎ 概要
+- APX は配下の VDR 以下の全ての求人を取得できる
+- VDR は、配下の全ての求人を取得できる
+- USR は、所属する VDR 内の全ての求人を取得できる
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `job_id` | number | required, gte=1 | 求人ID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    get,
+    security(("api_jwt_token" = [])),
+    path = "/jobs/{job_id}",
+    summary = "求人を1件取得する。",
+    description = GET_DESC,
+    params(
+        ("job_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetJobRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn get_job(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(job_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::jobs_bl::get_job(conn, &ju, &ids, job_id).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Create
+// ============================================================
+const CREATE_DESC: &str = r#"
+### ⚫︎ 概要
+- BD / APX は求人を作成できない
+- VDR は、配下の法人を corp_id で指定し、その法人の求人を代理で作成できる
+- USR は、法人（type=1）である場合のみ、自分自身の求人を作成できる（corp_id は指定しないこと）
+- 個人（type=2）の USR は求人を作成できない
+
+### パラメータについて
+- max: 募集人数（1以上）
+- filled: 作成時は常に 0
+- max_badges: 当該求人で授与できるバッジの最大数
+- work_bgn_at <= work_end_at、open_at <= close_at でなければならない
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `corp_id` | number | ⭐️ VDR必須, 🔴 USRでは入れないこと, gte=1 | 発行元法人ID |
+| `name` | string | required, max=100 | 求人名 |
+| `description` | string | max=1000 | 仕事内容 |
+| `max` | number | required, gte=1 | 募集人数 |
+| `hour_price` | number | required, gte=0 | 時給 |
+| `requirements` | string | max=1000 | 応募条件 |
+| `benefits` | string | max=1000 | 待遇 |
+| `location` | string | max=128 | 勤務地 |
+| `phone` | string | max=15 | 連絡先電話番号 |
+| `max_badges` | number | required, gte=0 | 最大バッジ授与数 |
+| `work_bgn_at` | string | datetime | 就労開始日時 |
+| `work_end_at` | string | datetime | 就労終了日時 |
+| `open_at` | string | datetime | 募集開始日時 |
+| `close_at` | string | datetime | 募集終了日時 |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/jobs",
+    summary = "求人を新規作成する。",
+    description = CREATE_DESC,
+    request_body = CreateJobReq,
+    responses(
+        (status = 200, description = "Success", body = CreateJobRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn create_job(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<CreateJobReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::jobs_bl::create_job(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Update
+// ============================================================
+const UPDATE_DESC: &str = r#"
+### ⚫︎ 概要
+- BD / APX は安全の為、更新権限を持たない
+- VDR は、配下の全ての求人を更新できる
+- USR は、自分が発行した求人のみ更新できる
+- 発行元法人（corp_id）は変更できない
+
+### パラメータについて
+- max は現在の採用済み人数（filled）未満にできない
+- 更新後の値で work_bgn_at <= work_end_at、open_at <= close_at でなければならない
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `job_id` | number | required, gte=1 | 求人ID |
+| `name` | string | max=100 | 求人名 |
+| `description` | string | max=1000 | 仕事内容 |
+| `max` | number | gte=1 | 募集人数 |
+| `hour_price` | number | gte=0 | 時給 |
+| `requirements` | string | max=1000 | 応募条件 |
+| `benefits` | string | max=1000 | 待遇 |
+| `location` | string | max=128 | 勤務地 |
+| `phone` | string | max=15 | 連絡先電話番号 |
+| `max_badges` | number | gte=0 | 最大バッジ授与数 |
+| `work_bgn_at` | string | datetime | 就労開始日時 |
+| `work_end_at` | string | datetime | 就労終了日時 |
+| `open_at` | string | datetime | 募集開始日時 |
+| `close_at` | string | datetime | 募集終了日時 |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    patch,
+    security(("api_jwt_token" = [])),
+    path = "/jobs/{job_id}",
+    summary = "求人を更新する。",
+    description = UPDATE_DESC,
+    params(
+        ("job_id" = u32, Path),
+    ),
+    request_body = UpdateJobReq,
+    responses(
+        (status = 200, description = "Success", body = UpdateJobRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn update_job(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(job_id): Path<u32>,
+    Json(req): Json<UpdateJobReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::jobs_bl::update_job(conn, &ju, &ids, job_id, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Delete
+// ============================================================
+const DELETE_DESC: &str = r#"
+### ⚫︎ 概要
+- BD / APX は安全の為、削除権限を持たない
+- VDR は、配下の全ての求人を削除できる
+- USR は、自分が発行した求人のみ削除できる
+- 就労実績（works）が存在する求人は削除できない
+- 当該求人に紐づくアプローチ情報（matches / match_statuses）は同時に削除される
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `job_id` | number | required, gte=1 | 求人ID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    delete,
+    security(("api_jwt_token" = [])),
+    path = "/jobs/{job_id}",
+    summary = "求人を削除する。",
+    description = DELETE_DESC,
+    params(
+        ("job_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = DeleteJobRes),
+        (status = 400, description = "Bad Request", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn delete_job(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(job_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::jobs_bl::delete_job(conn, &ju, &ids, job_id).await?;
+    Ok(Json(res))
+}
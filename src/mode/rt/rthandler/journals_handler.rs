@@ -0,0 +1,131 @@
+use std::sync::Arc;
+use axum::{Extension, Json, http::header, response::IntoResponse};
+use garde::Validate;
+use crate::{
+    mode::rt::{
+        rtreq::journals_req::ExportJournalReq,
+        rtres::{errs_res::ApiError, journals_res::ExportJournalRes},
+        rtutils::db_for_rt::DbPoolsExt
+    },
+    utils::{db::DbPools, jwt::{JwtUsr, JwtIDs, JwtRole}}
+};
+
+const TAG: &str = "v1 Journal";
+
+// ============================================================
+// Export (JSON)
+// ============================================================
+const EXPORT_DESC: &str = r#"
+### ⚫︎ 概要
+- VDR の期間内の全ての資金移動を、複式簿記の仕訳として出力する
+- APX は vdr_id で配下の VDR を指定して出力できる
+- VDR は自身の仕訳のみ出力できる（vdr_id は指定しないこと）
+
+### 仕訳について
+| 元データ | 借方 | 貸方 |
+| --- | --- | --- |
+| payments（入金） | 普通預金 amount | 受取手数料 fee / 預り金（現金プール） net |
+| payments（返金） | 受取手数料 fee / 預り金（現金プール） net | 普通預金 amount |
+| flushes（分配） | 預り金（現金プール） total | 受取手数料 事務費用 / 未払分配金 distributable |
+| payouts（個人への分配） | 未払分配金 amount | 預り金（源泉所得税） withheld / 未払金（個人） net |
+| withdrawals（支払済の出金） | 未払金（個人） amount | 普通預金 amount |
+| exports（全銀ファイルで振り込んだ payouts） | 未払金（個人） net | 普通預金 net |
+
+- payouts は期間内の flushes に属するものを対象とする
+- 支払済の出金は paid_at、全銀ファイルでの振込は exported_at、それ以外は created_at で期間を判定する
+- 金額が 0 の明細行は出力しない
+
+### 貸借の検証について
+- 全ての仕訳で借方合計と貸方合計が一致し、かつ未払分配金が期間内で相殺されることを検証する
+- 全銀ファイルで振り込んだ payouts は、計上（payouts）と振込（exports）が共に期間内にあれば、その未払金（個人）が相殺されることを検証する
+- 一致しない場合は仕訳を出力せず 409 とする
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `vdr_id` | number | ⭐️ APX必須, 🔴 VDRでは入れないこと, gte=1 | 対象の VDR ID |
+| `bgn_date` | string | required, date | 期間の開始日（YYYY-MM-DD、この日を含む） |
+| `end_date` | string | required, date | 期間の終了日（YYYY-MM-DD、この日を含む） |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/journals",
+    summary = "仕訳を JSON で出力する。",
+    description = EXPORT_DESC,
+    request_body = ExportJournalReq,
+    responses(
+        (status = 200, description = "Success", body = ExportJournalRes),
+        (status = 400, description = "Bad Request", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn export_journal(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<ExportJournalReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::APX, JwtRole::VDR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::journals_bl::export_journal(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Export (CSV)
+// ============================================================
+const EXPORT_CSV_DESC: &str = r#"
+### ⚫︎ 概要
+- POST /journals と同じ仕訳を CSV（UTF-8、CRLF）で出力する
+- 権限・期間・貸借の検証は POST /journals と同じ
+
+### CSV について
+- 1 行目はヘッダー行: `entry_no,date,source,source_id,account,debit,credit,description`
+- 明細行ごとに 1 行を出力し、同じ仕訳の明細行は同じ entry_no を持つ
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `vdr_id` | number | ⭐️ APX必須, 🔴 VDRでは入れないこと, gte=1 | 対象の VDR ID |
+| `bgn_date` | string | required, date | 期間の開始日（YYYY-MM-DD、この日を含む） |
+| `end_date` | string | required, date | 期間の終了日（YYYY-MM-DD、この日を含む） |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/journals/csv",
+    summary = "仕訳を CSV で出力する。",
+    description = EXPORT_CSV_DESC,
+    request_body = ExportJournalReq,
+    responses(
+        (status = 200, description = "Success", body = String, content_type = "text/csv"),
+        (status = 400, description = "Bad Request", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn export_journal_csv(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<ExportJournalReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::APX, JwtRole::VDR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let csv = crate::mode::rt::rtbl::journals_bl::export_journal_csv(conn, &ju, &ids, req).await?;
+    Ok(([(header::CONTENT_TYPE, "text/csv; charset=utf-8")], csv))
+}
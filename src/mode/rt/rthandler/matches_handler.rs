@@ -0,0 +1,420 @@
+use std::sync::Arc;
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use garde::Validate;
+use crate::{
+    mode::rt::{
+        rtreq::matches_req::{SearchMatchesReq, CreateMatchReq, AcceptMatchReq, UpdateMatchStatusReq, SearchMatchCandidatesReq},
+        rtres::{errs_res::ApiError, matches_res::{SearchMatchesRes, GetMatchRes, CreateMatchRes, AcceptMatchRes, DeclineMatchRes, ConfirmMatchRes, UpdateMatchStatusRes, SearchMatchCandidatesRes}},
+        rtutils::db_for_rt::DbPoolsExt
+    },
+    utils::{db::DbPools, jwt::{JwtUsr, JwtIDs, JwtRole}}
+};
+
+const TAG: &str = "v1 Match";
+
+// ============================================================
+// Search
+// ============================================================
+const SEARCH_DESC: &str = r#"
+### ⚫︎ 概要
+- BD は全てのアプローチを検索できる
+- APX は配下の VDR 以下の全てのアプローチを検索できる
+- VDR は、配下の全てのアプローチを検索できる
+- USR は、自分が送信元（法人）または送信先（個人）であるアプローチのみ検索できる
+
+### ステータスについて
+- 1: アプローチ, 2: 面談設定, 3: 面談実行, 4: 採用成功, 5: 辞退
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `job_id` | number | gte=1 | 求人ID |
+| `from` | number | gte=1 | 送信元法人ID |
+| `to` | number | gte=1 | 送信先個人ID |
+| `status` | number | gte=1, lte=5 | ステータス |
+| `limit` | number | gte=1, lte=25 | 取得数 |
+| `offset` | number | gte=0 | オフセット |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/matches/search",
+    summary = "アプローチを検索する。",
+    description = SEARCH_DESC,
+    request_body = SearchMatchesReq,
+    responses(
+        (status = 200, description = "Success", body = SearchMatchesRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn search_matches(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<SearchMatchesReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::BD, JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::matches_bl::search_matches(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Get
+// ============================================================
+const GET_DESC: &str = r#"
+### ⚫︎ 概要
+- APX は配下の VDR 以下の全てのアプローチを取得できる
+- VDR は、配下の全てのアプローチを取得できる
+- USR は、自分が当事者であるアプローチのみ取得できる
+- ステータスの遷移履歴（histories）を時系列順に含む
+- is_tmp は最新の履歴における仮予定フラグ
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `match_id` | number | required, gte=1 | アプローチID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    get,
+    security(("api_jwt_token" = [])),
+    path = "/matches/{match_id}",
+    summary = "アプローチを1件取得する。",
+    description = GET_DESC,
+    params(
+        ("match_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetMatchRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn get_match(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(match_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::matches_bl::get_match(conn, &ju, &ids, match_id).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Create
+// ============================================================
+const CREATE_DESC: &str = r#"
+### ⚫︎ 概要
+- BD / APX はアプローチを作成できない
+- VDR は、配下の全ての求人について代理でアプローチを送信できる
+- USR は、自分が発行した求人についてのみアプローチを送信できる
+- 送信元（from）は常に求人の発行元法人となる
+- 送信先（to）は同一 VDR 内の個人でなければならない
+- 募集期間外、または募集人数に達している求人には送信できない
+- 同一求人・同一個人への進行中（辞退以外）のアプローチが存在する場合は 409 となる（求人の行をロックして確認するため、同時に作成しても重複しない）
+
+### 優先度スコアについて
+- 送信時点の個人のバッジ保有数（badge_count）を記録する（取り消し済み・期限切れのバッジは数えない）
+- match_reason を指定した場合、その戦略で算出した優先度スコア（priority_score）を記録する
+- match_reason: 0: 手動（スコアは 0）, 1: high_badge, 2: random, 3: explore
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `job_id` | number | required, gte=1 | 求人ID |
+| `to` | number | required, gte=1 | 送信先個人ID |
+| `match_reason` | number | oneof=0 1 2 3 | マッチング理由（省略時は 0: 手動） |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/matches",
+    summary = "アプローチを送信する。",
+    description = CREATE_DESC,
+    request_body = CreateMatchReq,
+    responses(
+        (status = 200, description = "Success", body = CreateMatchRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn create_match(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<CreateMatchReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::matches_bl::create_match(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Accept
+// ============================================================
+const ACCEPT_DESC: &str = r#"
+### ⚫︎ 概要
+- 個人がアプローチを承諾し、面談設定（2）へ遷移させる
+- VDR は代理で操作できる
+- USR は、自分が送信先であるアプローチのみ操作できる
+- アプローチ（1）以外からは遷移できない（409）
+
+### 仮予定について
+- is_tmp=true の場合、面談日程を「仮予定」として設定する
+- 仮予定は、後から確定（confirm）できる
+- 仮予定のままでは、法人は面談実行（3）へ進められない
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `match_id` | number | required, gte=1 | アプローチID |
+| `is_tmp` | boolean | required | 仮予定であれば true |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    patch,
+    security(("api_jwt_token" = [])),
+    path = "/matches/{match_id}/accept",
+    summary = "アプローチを承諾する。",
+    description = ACCEPT_DESC,
+    params(
+        ("match_id" = u32, Path),
+    ),
+    request_body = AcceptMatchReq,
+    responses(
+        (status = 200, description = "Success", body = AcceptMatchRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn accept_match(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(match_id): Path<u32>,
+    Json(req): Json<AcceptMatchReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::matches_bl::accept_match(conn, &ju, &ids, match_id, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Decline
+// ============================================================
+const DECLINE_DESC: &str = r#"
+### ⚫︎ 概要
+- 個人がアプローチを辞退し、辞退（5）へ遷移させる
+- VDR は代理で操作できる
+- USR は、自分が送信先であるアプローチのみ操作できる
+- 採用成功（4）・辞退（5）からは遷移できない（409）
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `match_id` | number | required, gte=1 | アプローチID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    patch,
+    security(("api_jwt_token" = [])),
+    path = "/matches/{match_id}/decline",
+    summary = "アプローチを辞退する。",
+    description = DECLINE_DESC,
+    params(
+        ("match_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = DeclineMatchRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn decline_match(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(match_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::matches_bl::decline_match(conn, &ju, &ids, match_id).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Confirm
+// ============================================================
+const CONFIRM_DESC: &str = r#"
+### ⚫︎ 概要
+- 個人が「仮予定」の面談日程を確定する
+- VDR は代理で操作できる
+- USR は、自分が送信先であるアプローチのみ操作できる
+- 面談設定（2）かつ仮予定である場合のみ操作できる（それ以外は 409）
+- ステータスは面談設定（2）のまま、is_tmp=false の履歴が追記される
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `match_id` | number | required, gte=1 | アプローチID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    patch,
+    security(("api_jwt_token" = [])),
+    path = "/matches/{match_id}/confirm",
+    summary = "仮予定の面談日程を確定する。",
+    description = CONFIRM_DESC,
+    params(
+        ("match_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = ConfirmMatchRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn confirm_match(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(match_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::matches_bl::confirm_match(conn, &ju, &ids, match_id).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Update Status
+// ============================================================
+const UPDATE_STATUS_DESC: &str = r#"
+### ⚫︎ 概要
+- 法人が、面談実行（3）・採用成功（4）へステータスを進める
+- VDR は代理で操作できる
+- USR は、自分が送信元であるアプローチのみ操作できる
+- 許可される遷移は 2 → 3、3 → 4 のみ（それ以外は 409）
+- 面談実行（3）へは、面談日程が確定済み（is_tmp=false）の場合のみ遷移できる
+- 同じアプローチに対して同時に別の操作が行われ、ステータスが変わっていた場合は 409 となる
+
+### 採用成功（4）について
+- 採用成功と同時に、就業情報（works）が作成され、求人の採用済み人数（filled）が 1 増える
+- 1 件のアプローチから作成される就業情報は 1 件のみで、同時に採用成功の操作が行われても重複して作成されない
+- 求人の採用済み人数が募集人数（max）に達している場合は 409 となる
+- 個人の同時就業数（終了実績のない works の数）が VDR の max_works に達している場合は 409 となる（max_works=0 は上限なし）
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `match_id` | number | required, gte=1 | アプローチID |
+| `status` | number | required, oneof=3 4 | 3: 面談実行, 4: 採用成功 |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    patch,
+    security(("api_jwt_token" = [])),
+    path = "/matches/{match_id}/status",
+    summary = "アプローチのステータスを進める。",
+    description = UPDATE_STATUS_DESC,
+    params(
+        ("match_id" = u32, Path),
+    ),
+    request_body = UpdateMatchStatusReq,
+    responses(
+        (status = 200, description = "Success", body = UpdateMatchStatusRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn update_match_status(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(match_id): Path<u32>,
+    Json(req): Json<UpdateMatchStatusReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::matches_bl::update_match_status(conn, &ju, &ids, match_id, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Candidates
+// ============================================================
+const CANDIDATES_DESC: &str = r#"
+### ⚫︎ 概要
+- 求人に対するアプローチ候補の個人を、優先度スコアの高い順に返す
+- VDR は、配下の全ての求人について候補を取得できる
+- USR は、自分が発行した求人についてのみ候補を取得できる
+- 対象は同一 VDR 内で有効期間中の個人で、当該求人に進行中のアプローチがある個人は除外される
+
+### match_reason（ランキング戦略）について
+- 1: high_badge: バッジ保有数の多い個人を優先する
+- 2: random: バッジ数に依らず無作為に並べる
+- 3: explore: バッジ保有数の少ない個人を優先し、機会を広げる
+- スコアは 0〜1 で、求人の max_badges（0 の場合は VDR の total_badges）を基準にバッジ保有数を正規化して算出する
+- バッジ保有数には、取り消し済み・期限切れのバッジ授与を含めない
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `job_id` | number | required, gte=1 | 求人ID |
+| `match_reason` | number | required, oneof=1 2 3 | ランキング戦略 |
+| `limit` | number | gte=1, lte=25 | 取得数 |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/matches/candidates",
+    summary = "アプローチ候補の個人をランキングする。",
+    description = CANDIDATES_DESC,
+    request_body = SearchMatchCandidatesReq,
+    responses(
+        (status = 200, description = "Success", body = SearchMatchCandidatesRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn search_match_candidates(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<SearchMatchCandidatesReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::matches_bl::search_match_candidates(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
@@ -1,3 +1,18 @@
 pub mod bds_handler;
 pub mod usrs_handler;
 pub mod cryptos_handler;
+pub mod jobs_handler;
+pub mod matches_handler;
+pub mod works_handler;
+pub mod belongs_handler;
+pub mod badges_handler;
+pub mod usr_badges_handler;
+pub mod points_handler;
+pub mod payments_handler;
+pub mod flushes_handler;
+pub mod bank_accounts_handler;
+pub mod withdrawals_handler;
+pub mod journals_handler;
+pub mod pools_handler;
+pub mod point_adjustments_handler;
+pub mod collusion_handler;
\ No newline at end of file
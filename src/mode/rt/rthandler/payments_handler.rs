@@ -0,0 +1,217 @@
+use std::sync::Arc;
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use garde::Validate;
+use crate::{
+    mode::rt::{
+        rtreq::payments_req::{SearchPaymentsReq, CreatePaymentReq, RefundPaymentReq},
+        rtres::{errs_res::ApiError, payments_res::{SearchPaymentsRes, GetPaymentRes, CreatePaymentRes, RefundPaymentRes}},
+        rtutils::{db_for_rt::DbPoolsExt, config_for_rt::RtConfig}
+    },
+    utils::{db::DbPools, jwt::{JwtUsr, JwtIDs, JwtRole}}
+};
+
+const TAG: &str = "v1 Payment";
+
+// ============================================================
+// Search
+// ============================================================
+const SEARCH_DESC: &str = r#"
+### ⚫︎ 概要
+- BD は全ての入金を検索できる
+- APX は配下の VDR 以下の全ての入金を検索できる
+- VDR は、配下の全ての入金を検索できる
+- USR は、自分（法人）が支払った入金のみ検索できる
+
+### 返金について
+- 返金（取消）の入金も検索結果に含まれ、reversal_of に取り消した元の入金 ID を持つ
+- 返金の amount / fee / net は、元の入金から差し引かれた額を表す
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `corp_id` | number | gte=1 | 支払った法人ID |
+| `type` | number | gte=1, lte=2 | 1:面談フィー, 2:採用紹介料 |
+| `limit` | number | gte=1, lte=25 | 取得数 |
+| `offset` | number | gte=0 | オフセット |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/payments/search",
+    summary = "入金を検索する。",
+    description = SEARCH_DESC,
+    request_body = SearchPaymentsReq,
+    responses(
+        (status = 200, description = "Success", body = SearchPaymentsRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn search_payments(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<SearchPaymentsReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::BD, JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::payments_bl::search_payments(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Get
+// ============================================================
+const GET_DESC: &str = r#"
+### ⚫︎ 概要
+- APX は配下の VDR 以下の全ての入金を取得できる
+- VDR は、配下の全ての入金を取得できる
+- USR は、自分（法人）が支払った入金のみ取得できる
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `payment_id` | number | required, gte=1 | 入金ID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    get,
+    security(("api_jwt_token" = [])),
+    path = "/payments/{payment_id}",
+    summary = "入金を1件取得する。",
+    description = GET_DESC,
+    params(
+        ("payment_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetPaymentRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn get_payment(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(payment_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::payments_bl::get_payment(conn, &ju, &ids, payment_id).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Create
+// ============================================================
+const CREATE_DESC: &str = r#"
+### ⚫︎ 概要
+- 法人が VDR の現金プールへ入金する
+- VDR は、配下の法人を corp_id で指定して代理で記録できる
+- USR は、法人である場合のみ入金できる（corp_id は指定しないこと）
+
+### 金額について
+- fee（運営費控除分）は floor(amount × RT_PAYMENT_FEE_RATE)
+- net（プール流入額）は amount - fee
+- net は同一トランザクションで pools.remain と pools.total_in に加算される
+- プール行はロックした上で更新されるため、同時に入金されても加算が失われることはない
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `corp_id` | number | ⭐️ VDR必須, 🔴 USRでは入れないこと, gte=1 | 支払う法人ID |
+| `type` | number | required, gte=1, lte=2 | 1:面談フィー, 2:採用紹介料 |
+| `amount` | number | required, gte=1 | 支払金額 |
+| `note` | string | max=255 | メモ |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/payments",
+    summary = "入金を記録する。",
+    description = CREATE_DESC,
+    request_body = CreatePaymentReq,
+    responses(
+        (status = 200, description = "Success", body = CreatePaymentRes),
+        (status = 400, description = "Bad Request", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn create_payment(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Extension(cfg): Extension<Arc<RtConfig>>,
+    Json(req): Json<CreatePaymentReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::payments_bl::create_payment(conn, &ju, &ids, &cfg, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Refund
+// ============================================================
+const REFUND_DESC: &str = r#"
+### ⚫︎ 概要
+- VDR が誤って記録された入金を取り消し、返金する
+- 元の入金は変更せず、reversal_of に元の入金 ID を持つ返金（取消）の入金を新たに記録する
+- 1 件の入金を取り消せるのは 1 度のみで、返金の入金は取り消せない（409）
+
+### 金額について
+- 返金の amount / fee / net は元の入金と同額
+- net は同一トランザクションで pools.remain と pools.total_in から差し引かれる
+- 既に分配（flush）されてプール残高（pools.remain）が net を下回っている場合は返金できない（409）
+- プール行と元の入金はロックした上で更新されるため、入金・分配と同時に実行されても残高が不整合になることはない
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `payment_id` | number | required, gte=1 | 取り消す入金ID |
+| `note` | string | max=255 | 返金理由 |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/payments/{payment_id}/refund",
+    summary = "入金を取り消して返金する。",
+    description = REFUND_DESC,
+    params(
+        ("payment_id" = u32, Path),
+    ),
+    request_body = RefundPaymentReq,
+    responses(
+        (status = 200, description = "Success", body = RefundPaymentRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn refund_payment(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(payment_id): Path<u32>,
+    Json(req): Json<RefundPaymentReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::payments_bl::refund_payment(conn, &ids, payment_id, req).await?;
+    Ok(Json(res))
+}
@@ -0,0 +1,258 @@
+use std::sync::Arc;
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use garde::Validate;
+use crate::{
+    mode::rt::{
+        rtreq::point_adjustments_req::{SearchPointAdjustmentsReq, CreatePointAdjustmentReq},
+        rtres::{errs_res::ApiError, point_adjustments_res::{SearchPointAdjustmentsRes, GetPointAdjustmentRes, CreatePointAdjustmentRes, ReviewPointAdjustmentRes}},
+        rtutils::db_for_rt::DbPoolsExt
+    },
+    utils::{db::DbPools, jwt::{JwtUsr, JwtIDs, JwtRole}}
+};
+
+const TAG: &str = "v1 PointAdjustment";
+
+// ============================================================
+// Search
+// ============================================================
+const SEARCH_DESC: &str = r#"
+### ⚫︎ 概要
+- BD は全てのポイント調整履歴を検索できる
+- APX は配下の VDR 以下の全てのポイント調整履歴を検索できる
+- VDR は、配下の全てのポイント調整履歴を検索できる
+- USR は、自分に対するポイント調整履歴のみ検索できる
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `usr_id` | number | gte=1 | 調整対象（個人）ID |
+| `reason` | number | gte=1, lte=4 | 1:誤ったバッジ付与, 2:不正, 3:訂正, 4:その他 |
+| `status` | number | gte=1, lte=3 | 1:承認待ち, 2:承認済み, 3:却下 |
+| `limit` | number | gte=1, lte=25 | 取得数 |
+| `offset` | number | gte=0 | オフセット |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/point_adjustments/search",
+    summary = "ポイント調整履歴を検索する。",
+    description = SEARCH_DESC,
+    request_body = SearchPointAdjustmentsReq,
+    responses(
+        (status = 200, description = "Success", body = SearchPointAdjustmentsRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn search_point_adjustments(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<SearchPointAdjustmentsReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::BD, JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::point_adjustments_bl::search_point_adjustments(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Get
+// ============================================================
+const GET_DESC: &str = r#"
+### ⚫︎ 概要
+- APX は配下の VDR 以下の全てのポイント調整履歴を取得できる
+- VDR は、配下の全てのポイント調整履歴を取得できる
+- USR は、自分に対するポイント調整履歴のみ取得できる
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `point_adjustment_id` | number | required, gte=1 | ポイント調整ID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    get,
+    security(("api_jwt_token" = [])),
+    path = "/point_adjustments/{point_adjustment_id}",
+    summary = "ポイント調整履歴を1件取得する。",
+    description = GET_DESC,
+    params(
+        ("point_adjustment_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetPointAdjustmentRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn get_point_adjustment(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(point_adjustment_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::point_adjustments_bl::get_point_adjustment(conn, &ju, &ids, point_adjustment_id).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Create
+// ============================================================
+const CREATE_DESC: &str = r#"
+### ⚫︎ 概要
+- 誤ったバッジ付与や不正の発覚などを受けて、個人の保有ポイント（usrs.points）の手動調整を申請する
+- VDR、または スタッフ token を持つ USR のみ操作できる
+- スタッフは自分自身のポイントの調整を申請できない
+- 調整対象は、操作者と同じ VDR 配下の個人のみ
+
+### 調整について
+- 申請した調整は承認待ち（1）となり、この時点では保有ポイントは変わらない
+- 申請者とは別の承認者が承認（PATCH /point_adjustments/{point_adjustment_id}/approve）した時点で保有ポイントへ反映される
+- `delta` は符号付きで、正の値は加算・負の値は減算となる（0 は不可）
+- 調整履歴には、理由・申請者と承認者の UsrID が記録され、削除されない
+- スタッフ token で操作した場合、スタッフの UsrID も記録される
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `usr_id` | number | required, gte=1 | 調整対象（個人）ID |
+| `delta` | number | required, gte=-1000000, lte=1000000, ne=0 | 調整量 |
+| `reason` | number | required, gte=1, lte=4 | 1:誤ったバッジ付与, 2:不正, 3:訂正, 4:その他 |
+| `note` | string | max=255 | メモ |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/point_adjustments",
+    summary = "個人の保有ポイントの調整を申請する。",
+    description = CREATE_DESC,
+    request_body = CreatePointAdjustmentReq,
+    responses(
+        (status = 200, description = "Success", body = CreatePointAdjustmentRes),
+        (status = 400, description = "Bad Request", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn create_point_adjustment(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<CreatePointAdjustmentReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::point_adjustments_bl::create_point_adjustment(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+
+// ============================================================
+// Approve
+// ============================================================
+const APPROVE_DESC: &str = r#"
+### ⚫︎ 概要
+- 承認待ち（1）のポイント調整を承認し、個人の保有ポイント（usrs.points）へ反映する
+- VDR、または スタッフ token を持つ USR のみ操作できる
+- 申請者本人（同じ UsrID かつ同じスタッフ）は承認できない（403）
+- スタッフは自分自身に対する調整を承認できない（403）
+
+### 承認について
+- 承認待ち（1）以外は承認できない（409）
+- 調整後の保有ポイントが負になる場合は承認できない（409）
+- 保有ポイントの更新と、承認時点の調整前後の値（bef_points / aft_points）・承認者の記録は同一トランザクションで行われる
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `point_adjustment_id` | number | required, gte=1 | ポイント調整ID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    patch,
+    security(("api_jwt_token" = [])),
+    path = "/point_adjustments/{point_adjustment_id}/approve",
+    summary = "ポイント調整を承認する。",
+    description = APPROVE_DESC,
+    params(
+        ("point_adjustment_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = ReviewPointAdjustmentRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn approve_point_adjustment(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(point_adjustment_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::point_adjustments_bl::approve_point_adjustment(conn, &ju, &ids, point_adjustment_id).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Reject
+// ============================================================
+const REJECT_DESC: &str = r#"
+### ⚫︎ 概要
+- 承認待ち（1）のポイント調整を却下する（保有ポイントは変わらない）
+- VDR、または スタッフ token を持つ USR のみ操作できる
+- 申請者本人（同じ UsrID かつ同じスタッフ）は却下できない（403）
+- 承認待ち（1）以外は却下できない（409）
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `point_adjustment_id` | number | required, gte=1 | ポイント調整ID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    patch,
+    security(("api_jwt_token" = [])),
+    path = "/point_adjustments/{point_adjustment_id}/reject",
+    summary = "ポイント調整を却下する。",
+    description = REJECT_DESC,
+    params(
+        ("point_adjustment_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = ReviewPointAdjustmentRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn reject_point_adjustment(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(point_adjustment_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::point_adjustments_bl::reject_point_adjustment(conn, &ju, &ids, point_adjustment_id).await?;
+    Ok(Json(res))
+}
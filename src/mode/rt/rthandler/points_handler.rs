@@ -0,0 +1,170 @@
+use std::sync::Arc;
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use garde::Validate;
+use crate::{
+    mode::rt::{
+        rtreq::points_req::{SearchPointsReq, ExpiringPointsReq},
+        rtres::{errs_res::ApiError, points_res::{SearchPointsRes, GetPointRes, ExpiringPointsRes}},
+        rtutils::db_for_rt::DbPoolsExt
+    },
+    utils::{db::DbPools, jwt::{JwtUsr, JwtIDs, JwtRole}}
+};
+
+const TAG: &str = "v1 Point";
+
+// ============================================================
+// Search
+// ============================================================
+const SEARCH_DESC: &str = r#"
+### ⚫︎ 概要
+- BD は全てのポイント履歴を検索できる
+- APX は配下の VDR 以下の全てのポイント履歴を検索できる
+- VDR は、配下の全てのポイント履歴を検索できる
+- USR は、自分が法人（corp_id）・授与者（from）・受領者（to）のいずれかであるポイント履歴のみ検索できる
+
+### ポイントについて
+- バッジ授与 1 件につき、受領者へのポイント履歴が 1 件作成される
+- point は VDR の base_point による基本ポイント
+- extra は floor(point × (法人の rate + 所属割増 belong_rate)) による割増ポイント
+- extra の積は小数のまま誤差なく計算し、1 ポイント未満は切り捨てて整数で記録する（端数は繰り越さない）
+- belong_rate は、授与時点で受領者がバッジ発行法人に所属している場合のみ適用される
+- kind は 1: 授与, 2: 失効, 3: 取り消し
+- delta は受領者（to）の保有ポイントに対する符号付きの増減で、全ての行の delta の合計が履歴上の保有ポイントとなる
+  - 授与の行は point + extra
+  - 失効の行は、VDR のポイント失効ポリシー（point_expire_months）により失効したポイント数の負の値（point / extra は 0）。付与元のバッジ授与ごとに作成し、usr_badge_id に授与ID（手動調整の分は 0）を記録する
+  - 取り消しの行は、バッジ授与の取り消しにより戻したポイント数の負の値（point / extra は 0）
+- 共謀の疑いで保留中の授与のポイントは失効しない
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `badge_id` | number | gte=1 | バッジID |
+| `corp_id` | number | gte=1 | バッジ発行法人ID |
+| `from` | number | gte=1 | 授与者ID |
+| `to` | number | gte=1 | 受領者ID |
+| `limit` | number | gte=1, lte=25 | 取得数 |
+| `offset` | number | gte=0 | オフセット |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/points/search",
+    summary = "ポイント履歴を検索する。",
+    description = SEARCH_DESC,
+    request_body = SearchPointsReq,
+    responses(
+        (status = 200, description = "Success", body = SearchPointsRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn search_points(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<SearchPointsReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::BD, JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::points_bl::search_points(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Get
+// ============================================================
+const GET_DESC: &str = r#"
+### ⚫︎ 概要
+- APX は配下の VDR 以下の全てのポイント履歴を取得できる
+- VDR は、配下の全てのポイント履歴を取得できる
+- USR は、自分が当事者であるポイント履歴のみ取得できる
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `point_id` | number | required, gte=1 | ポイント履歴ID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    get,
+    security(("api_jwt_token" = [])),
+    path = "/points/{point_id}",
+    summary = "ポイント履歴を1件取得する。",
+    description = GET_DESC,
+    params(
+        ("point_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetPointRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn get_point(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(point_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::points_bl::get_point(conn, &ju, &ids, point_id).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Expiring
+// ============================================================
+const EXPIRING_DESC: &str = r#"
+### ⚫︎ 概要
+- 個人の保有ポイントのうち、指定日数以内に失効するポイントを取得する
+- VDR は、配下の個人を usr_id で指定して取得できる
+- USR は、個人である場合のみ、自分自身について取得できる
+
+### 失効について
+- VDR の point_expire_months が 1 以上の場合、付与から point_expire_months か月の間に分配されなかったポイントは失効する（0 の場合は失効しない）
+- 分配・減算の調整・失効は古い付与から順に消費されたものとみなし、保有ポイントは新しい付与から順に割り当てる
+- 共謀の疑いで保留中の授与のポイント（held_points）は失効の対象外とし、lots に含めない
+- lots は付与ごとの残りで、usr_badge_id は付与元のバッジ授与ID（手動調整は 0）
+- 失効は pe モードによって定期的に実行されるため、失効日時を過ぎたポイントが lots に含まれることがある
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `usr_id` | number | ⭐️ VDR必須, 🔴 USRでは入れないこと, gte=1 | 個人ID |
+| `days` | number | required, gte=1, lte=365 | 何日以内に失効するポイントを対象とするか |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/points/expiring",
+    summary = "失効が近いポイントを取得する。",
+    description = EXPIRING_DESC,
+    request_body = ExpiringPointsReq,
+    responses(
+        (status = 200, description = "Success", body = ExpiringPointsRes),
+        (status = 400, description = "Bad Request", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn get_expiring_points(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<ExpiringPointsReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::points_bl::get_expiring_points(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
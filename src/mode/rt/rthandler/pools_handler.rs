@@ -0,0 +1,125 @@
+use std::sync::Arc;
+use axum::{Extension, Json, response::IntoResponse};
+use garde::Validate;
+use crate::{
+    mode::rt::{
+        rtreq::pools_req::{SearchPoolLedgerReq, CheckPoolReq},
+        rtres::{errs_res::ApiError, pools_res::{SearchPoolLedgerRes, CheckPoolRes}},
+        rtutils::db_for_rt::DbPoolsExt
+    },
+    utils::{db::DbPools, jwt::{JwtUsr, JwtIDs, JwtRole}}
+};
+
+const TAG: &str = "v1 Pool";
+
+// ============================================================
+// Ledger
+// ============================================================
+const LEDGER_DESC: &str = r#"
+### ⚫︎ 概要
+- VDR の現金プール残高（pools.remain）の推移を、入金・返金・分配を時系列に並べた台帳として取得する
+- APX は vdr_id で配下の VDR を指定して取得できる
+- VDR は自身の台帳のみ取得できる（vdr_id は指定しないこと）
+
+### 台帳について
+| kind | 元データ | amount_in | amount_out |
+| --- | --- | --- | --- |
+| 1: 入金 | payments | net | 0 |
+| 2: 返金 | payments（reversal_of あり） | 0 | net |
+| 3: 分配 | flushes | 0 | total |
+
+- balance は、その増減を反映した後の残高（期間開始時点の残高から、期間内の増減を順に積み上げた値）
+- 同一日時の増減は 入金 → 返金 → 分配 の順に並ぶ
+- 期間を指定した場合、opening_balance は期間開始時点、closing_balance は期間終了時点の残高
+- lines は期間内の増減を offset / limit で切り出したもので、opening_balance / closing_balance はページに関係なく期間全体の値
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `vdr_id` | number | ⭐️ APX必須, 🔴 VDRでは入れないこと, gte=1 | 対象の VDR ID |
+| `bgn_date` | string | date | 期間の開始日（YYYY-MM-DD、この日を含む） |
+| `end_date` | string | date | 期間の終了日（YYYY-MM-DD、この日を含む） |
+| `limit` | number | gte=1, lte=25 | 取得数 |
+| `offset` | number | gte=0 | オフセット |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/pools/ledger",
+    summary = "現金プールの台帳を取得する。",
+    description = LEDGER_DESC,
+    request_body = SearchPoolLedgerReq,
+    responses(
+        (status = 200, description = "Success", body = SearchPoolLedgerRes),
+        (status = 400, description = "Bad Request", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn search_pool_ledger(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<SearchPoolLedgerReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::APX, JwtRole::VDR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::pools_bl::search_pool_ledger(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Check
+// ============================================================
+const CHECK_DESC: &str = r#"
+### ⚫︎ 概要
+- 入金・返金・分配から再計算した残高・累計と、保存されている pools の値を比較する
+- APX は vdr_id で配下の VDR を指定して検証できる
+- VDR は自身の現金プールのみ検証できる（vdr_id は指定しないこと）
+
+### 検証内容について
+- remain / total_in / total_out が再計算した値と一致すること
+- remain が total_in - total_out と一致すること
+- 再計算した残高が途中で負にならないこと
+- 一致しない項目があれば is_consistent は false となり、mismatches に内容が入る（値の修正は行わない）
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `vdr_id` | number | ⭐️ APX必須, 🔴 VDRでは入れないこと, gte=1 | 対象の VDR ID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/pools/check",
+    summary = "現金プールの整合性を検証する。",
+    description = CHECK_DESC,
+    request_body = CheckPoolReq,
+    responses(
+        (status = 200, description = "Success", body = CheckPoolRes),
+        (status = 400, description = "Bad Request", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn check_pool(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<CheckPoolReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::APX, JwtRole::VDR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::pools_bl::check_pool(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
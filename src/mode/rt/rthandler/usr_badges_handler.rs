@@ -0,0 +1,252 @@
+use std::sync::Arc;
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use garde::Validate;
+use crate::{
+    mode::rt::{
+        rtreq::usr_badges_req::{SearchUsrBadgesReq, AwardUsrBadgeReq, RevokeUsrBadgeReq},
+        rtres::{errs_res::ApiError, usr_badges_res::{SearchUsrBadgesRes, GetUsrBadgeRes, AwardUsrBadgeRes, RevokeUsrBadgeRes}},
+        rtutils::db_for_rt::DbPoolsExt
+    },
+    utils::{db::DbPools, jwt::{JwtUsr, JwtIDs, JwtRole}}
+};
+
+const TAG: &str = "v1 UsrBadge";
+
+// ============================================================
+// Search
+// ============================================================
+const SEARCH_DESC: &str = r#"
+### ⚫︎ 概要
+- BD は全てのバッジ授与を検索できる
+- APX は配下の VDR 以下の全てのバッジ授与を検索できる
+- VDR は、配下の全てのバッジ授与を検索できる
+- USR は、自分が発行法人（corp_id）・授与者（from）・受領者（to）のいずれかであるバッジ授与のみ検索できる
+- active に true を指定すると、取り消されておらず有効期限内のバッジ授与のみ、false を指定するとそれ以外（取り消し済み、または期限切れ）のみを検索する
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `badge_id` | number | gte=1 | バッジID |
+| `corp_id` | number | gte=1 | バッジ発行法人ID |
+| `from` | number | gte=1 | 授与者ID |
+| `to` | number | gte=1 | 受領者ID |
+| `job_id` | number | gte=1 | 求人ID |
+| `active` | boolean | - | 有効なバッジ授与のみ（true） / 無効なバッジ授与のみ（false） |
+| `limit` | number | gte=1, lte=25 | 取得数 |
+| `offset` | number | gte=0 | オフセット |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/usr_badges/search",
+    summary = "バッジ授与を検索する。",
+    description = SEARCH_DESC,
+    request_body = SearchUsrBadgesReq,
+    responses(
+        (status = 200, description = "Success", body = SearchUsrBadgesRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn search_usr_badges(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<SearchUsrBadgesReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::BD, JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::usr_badges_bl::search_usr_badges(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Get
+// ============================================================
+const GET_DESC: &str = r#"
+### ⚫︎ 概要
+- APX は配下の VDR 以下の全てのバッジ授与を取得できる
+- VDR は、配下の全てのバッジ授与を取得できる
+- USR は、自分が当事者であるバッジ授与のみ取得できる
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `usr_badge_id` | number | required, gte=1 | バッジ授与ID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    get,
+    security(("api_jwt_token" = [])),
+    path = "/usr_badges/{usr_badge_id}",
+    summary = "バッジ授与を1件取得する。",
+    description = GET_DESC,
+    params(
+        ("usr_badge_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetUsrBadgeRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn get_usr_badge(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(usr_badge_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::usr_badges_bl::get_usr_badge(conn, &ju, &ids, usr_badge_id).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Award
+// ============================================================
+const AWARD_DESC: &str = r#"
+### ⚫︎ 概要
+- USR が個人にバッジを授与する
+- 法人は、自分が発行したバッジのみ授与できる（type = 1）
+- 個人は、バッジを発行した法人に現在所属している場合のみ授与できる（type = 2）
+- 受領者（to）は同一 VDR 内の個人でなければならず、自分自身には授与できない
+- 廃止済みのバッジは授与できず 409 となる
+- バッジに valid_months が設定されている場合、授与から valid_months ヶ月後を有効期限（expires_at）とする
+
+### 求人に紐づく授与について
+- job_id を指定する場合、バッジ発行法人の求人であり、受領者がその求人で就業している必要がある
+- 当該求人での授与数（取り消し済みを除く）が max_badges に達している場合は 409 となる（max_badges が 0 の場合は上限なし）
+
+### カウンタについて
+- 授与と同一トランザクションで、以下のカウンタを 1 加算する
+- 受領者の badged（保有バッジ数）
+- 授与者の total_badged（授与したバッジの累積数）
+- VDR の total_badges（VDR 内で授与されたバッジの総数）
+- これらのカウンタは有効期限（expires_at）を過ぎた授与も含む（期限切れで減算はせず、取り消し時のみ減算する）
+
+### ポイントについて
+- 授与と同一トランザクションで、受領者へのポイント履歴（points）を作成し、受領者の points を加算する
+- point は VDR の base_point
+- extra は floor(point × (バッジ発行法人の rate + 所属割増 belong_rate))
+- extra の 1 ポイント未満は切り捨てる（端数は繰り越さない）
+- belong_rate は、授与時点で受領者がバッジ発行法人に所属している場合のみ適用される
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `badge_id` | number | required, gte=1 | バッジID |
+| `to` | number | required, gte=1 | 受領者（個人）ID |
+| `job_id` | number | gte=1 | 求人ID |
+| `title` | string | max=100 | メッセージの件名 |
+| `message` | string | max=500 | メッセージ本体 |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/usr_badges",
+    summary = "バッジを授与する。",
+    description = AWARD_DESC,
+    request_body = AwardUsrBadgeReq,
+    responses(
+        (status = 200, description = "Success", body = AwardUsrBadgeRes),
+        (status = 400, description = "Bad Request", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn award_usr_badge(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<AwardUsrBadgeReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::usr_badges_bl::award_usr_badge(conn, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Revoke
+// ============================================================
+const REVOKE_DESC: &str = r#"
+### ⚫︎ 概要
+- 誤授与や不正行為が判明したバッジ授与を取り消す
+- VDR、バッジ発行法人の USR、またはスタッフ token の USR のみが取り消せる（それ以外は 403）
+- USR は、自分が受領者であるバッジ授与は取り消せない（403）
+- 既に取り消し済みの場合は 409 となる
+- 授与の行は削除せず、取り消し日時・理由・補足・取り消しを行った ID（revoked_by / revoked_staff_id）を記録する
+
+### カウンタについて
+- 取り消しと同一トランザクションで、授与時に加算した以下のカウンタを 1 減算する
+- 受領者の badged、授与者の total_badged、VDR の total_badges
+
+### ポイントについて
+- 授与で付与したポイント（point + extra）が未分配であれば、その授与について失効済みの分（usr_badge_id が一致する失効の行）を除いた残りを、受領者の保有ポイントの範囲で戻す
+- 失効済みの分は既に保有ポイントから減算されているため、二重に減算しない
+- 授与ごとの分配状態は usr_badges.flush_id / flushed_at に記録され、分配（flush）の実行時に受領者の未分配の授与へ設定される
+- flush_id が設定済み（分配済み）の授与は、ポイントを戻さない
+- 共謀の疑いにより保留中（held_at）の授与は分配の対象から除かれているため、常に未分配として戻す
+- 戻したポイントは、ポイント履歴（points）に取り消しの行（kind = 3, revoked）として記録し、受領者の points から減算する
+- 戻したポイント数はレスポンスの revoked_points で返す（戻さなかった場合は 0）
+
+### reason について
+| VALUE | DESCRIPTION |
+| --- | --- |
+| 1 | 誤った授与の取り消し |
+| 2 | 不正行為の発覚による取り消し |
+| 3 | その他（note に詳細を記載する） |
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `usr_badge_id` | number | required, gte=1 | バッジ授与ID |
+| `reason` | number | required, gte=1, lte=3 | 取り消しの理由 |
+| `note` | string | max=255 | 補足 |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    patch,
+    security(("api_jwt_token" = [])),
+    path = "/usr_badges/{usr_badge_id}/revoke",
+    summary = "バッジ授与を取り消す。",
+    description = REVOKE_DESC,
+    params(
+        ("usr_badge_id" = u32, Path),
+    ),
+    request_body = RevokeUsrBadgeReq,
+    responses(
+        (status = 200, description = "Success", body = RevokeUsrBadgeRes),
+        (status = 400, description = "Bad Request", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn revoke_usr_badge(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(usr_badge_id): Path<u32>,
+    Json(req): Json<RevokeUsrBadgeReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::usr_badges_bl::revoke_usr_badge(conn, &ju, &ids, usr_badge_id, req).await?;
+    Ok(Json(res))
+}
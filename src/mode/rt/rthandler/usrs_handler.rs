@@ -232,7 +232,12 @@ const CREATE_DESC: &str = r#"
 - belong_rate: VDRのみ必須 (所属によるポイント割増率)
 - max_works: VDRのみ必須 (VDR内の個人が就労できる最大数)
 - flush_fee_rate: VDRのみ必須 (現金プールを現金分配実行する時に、事務コストを賄うために Pool から引かれる割合)
-- flush_days: 法人のみ必須 (現金プールを現金分配実行するためのサイクルとなる日数)
+- flush_days: 法人必須, VDRでは任意 (現金プールを現金分配実行するためのサイクルとなる日数。VDR では現金プールを定期分配（fs モード）するサイクルとなり、0 の場合は定期分配しない)
+- withholding_*: VDRでは任意 (VDR の源泉徴収ルール。分配時に個人ごとの分配金額へ適用される。税率が共に 0 の場合は源泉徴収しない)
+  - withholding_threshold: 1 回の支払金額がこれ未満の場合は源泉徴収しない
+  - withholding_reduced_rate / withholding_reduced_limit: 支払金額のうち limit までの部分に適用する軽減税率
+  - withholding_rate: limit を超える部分に適用する税率
+- point_expire_months: VDRでは任意 (付与から分配されないまま経過するとポイントが失効する月数。0 の場合は失効しない)
 - rate: 法人のみ必須 (法人が、自分に所属するユーザーに対して付与する割増ポイント率)
 - VDR作成時以外にVDR用項目を送信するとエラーとなる
 - 法人作成時以外に法人用項目を送信するとエラーとなる
@@ -254,7 +259,12 @@ const CREATE_DESC: &str = r#"
 | `belong_rate` | number | ⭐️ VDR必須, gte=0 | 所属割増率 |
 | `max_works` | number | ⭐️ VDR必須, gte=0 | 最大就労数 |
 | `flush_fee_rate` | number | ⭐️ VDR必須, gte=0 | 事務コスト分配率 |
-| `flush_days` | number | 🔷 法人必須, gte=0 | 現金分配サイクル日数 |
+| `flush_days` | number | 🔷 法人必須, VDR任意, gte=0 | 現金分配サイクル日数 |
+| `withholding_threshold` | number | VDR任意, gte=0 | 源泉徴収の対象となる支払金額の下限 |
+| `withholding_rate` | number | VDR任意, gte=0, lte=0.99999 | 源泉徴収税率 |
+| `withholding_reduced_rate` | number | VDR任意, gte=0, lte=0.99999 | 源泉徴収の軽減税率 |
+| `withholding_reduced_limit` | number | VDR任意, gte=0 | 軽減税率を適用する区分の上限額 |
+| `point_expire_months` | number | VDR任意, gte=0, lte=120 | ポイント失効までの月数 |
 | `rate` | number | 🔷 法人必須, gte=0 | 割増ポイント率 |
 "#;
 #[utoipa::path(
@@ -301,7 +311,12 @@ const UPDATE_DESC: &str = r#"
 - belong_rate: VDRのみ必須 (所属によるポイント割増率)
 - max_works: VDRのみ必須 (VDR内の個人が就労できる最大数)
 - flush_fee_rate: VDRのみ必須 (現金プールを現金分配実行する時に、事務コストを賄うために Pool から引かれる割合)
-- flush_days: 法人のみ必須 (現金プールを現金分配実行するためのサイクルとなる日数)
+- flush_days: 法人必須, VDRでは任意 (現金プールを現金分配実行するためのサイクルとなる日数。VDR では現金プールを定期分配（fs モード）するサイクルとなり、0 の場合は定期分配しない)
+- withholding_*: VDRでは任意 (VDR の源泉徴収ルール。分配時に個人ごとの分配金額へ適用される。税率が共に 0 の場合は源泉徴収しない)
+  - withholding_threshold: 1 回の支払金額がこれ未満の場合は源泉徴収しない
+  - withholding_reduced_rate / withholding_reduced_limit: 支払金額のうち limit までの部分に適用する軽減税率
+  - withholding_rate: limit を超える部分に適用する税率
+- point_expire_months: VDRでは任意 (付与から分配されないまま経過するとポイントが失効する月数。0 の場合は失効しない)
 - rate: 法人のみ必須 (法人が、自分に所属するユーザーに対して付与する割増ポイント率)
 - VDR作成時以外にVDR用項目を送信するとエラーとなる
 - 法人作成時以外に法人用項目を送信するとエラーとなる
@@ -324,7 +339,12 @@ const UPDATE_DESC: &str = r#"
 | `belong_rate` | number | ⭐️ VDR必須, gte=0 | 所属割増率 |
 | `max_works` | number | ⭐️ VDR必須, gte=0 | 最大就労数 |
 | `flush_fee_rate` | number | ⭐️ VDR必須, gte=0 | 事務コスト分配率 |
-| `flush_days` | number | 🔷 法人必須, gte=0 | 現金分配サイクル日数 |
+| `flush_days` | number | 🔷 法人必須, VDR任意, gte=0 | 現金分配サイクル日数 |
+| `withholding_threshold` | number | VDR任意, gte=0 | 源泉徴収の対象となる支払金額の下限 |
+| `withholding_rate` | number | VDR任意, gte=0, lte=0.99999 | 源泉徴収税率 |
+| `withholding_reduced_rate` | number | VDR任意, gte=0, lte=0.99999 | 源泉徴収の軽減税率 |
+| `withholding_reduced_limit` | number | VDR任意, gte=0 | 軽減税率を適用する区分の上限額 |
+| `point_expire_months` | number | VDR任意, gte=0, lte=120 | ポイント失効までの月数 |
 | `rate` | number | 🔷 法人必須, gte=0 | 割増ポイント率 |
 "#;
 #[utoipa::path(
@@ -0,0 +1,301 @@
+use std::sync::Arc;
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use garde::Validate;
+use crate::{
+    mode::rt::{
+        rtreq::withdrawals_req::{SearchWithdrawalsReq, CreateWithdrawalReq, ReviewWithdrawalReq},
+        rtres::{errs_res::ApiError, withdrawals_res::{SearchWithdrawalsRes, GetWithdrawalRes, CreateWithdrawalRes, ReviewWithdrawalRes}},
+        rtutils::db_for_rt::DbPoolsExt
+    },
+    utils::{db::DbPools, jwt::{JwtUsr, JwtIDs, JwtRole}}
+};
+
+const TAG: &str = "v1 Withdrawal";
+
+// ============================================================
+// Search
+// ============================================================
+const SEARCH_DESC: &str = r#"
+### ⚫︎ 概要
+- BD は全ての出金申請を検索できる
+- APX は配下の VDR 以下の全ての出金申請を検索できる
+- VDR は、配下の全ての出金申請を検索できる
+- USR は、自分の出金申請のみ検索できる
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `usr_id` | number | gte=1 | 申請者（個人）ID |
+| `status` | number | gte=1, lte=4 | 1:申請, 2:承認, 3:却下, 4:支払済 |
+| `limit` | number | gte=1, lte=25 | 取得数 |
+| `offset` | number | gte=0 | オフセット |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/withdrawals/search",
+    summary = "出金申請を検索する。",
+    description = SEARCH_DESC,
+    request_body = SearchWithdrawalsReq,
+    responses(
+        (status = 200, description = "Success", body = SearchWithdrawalsRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn search_withdrawals(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<SearchWithdrawalsReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::BD, JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::withdrawals_bl::search_withdrawals(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Get
+// ============================================================
+const GET_DESC: &str = r#"
+### ⚫︎ 概要
+- APX は配下の VDR 以下の全ての出金申請を取得できる
+- VDR は、配下の全ての出金申請を取得できる
+- USR は、自分の出金申請のみ取得できる
+- ステータス遷移履歴（statuses）を古い順に含む
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `withdrawal_id` | number | required, gte=1 | 出金申請ID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    get,
+    security(("api_jwt_token" = [])),
+    path = "/withdrawals/{withdrawal_id}",
+    summary = "出金申請を1件取得する。",
+    description = GET_DESC,
+    params(
+        ("withdrawal_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetWithdrawalRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn get_withdrawal(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(withdrawal_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::withdrawals_bl::get_withdrawal(conn, &ju, &ids, withdrawal_id).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Create
+// ============================================================
+const CREATE_DESC: &str = r#"
+### ⚫︎ 概要
+- 個人が、分配された金額からの出金を申請する
+- USR は、個人である場合のみ申請できる
+- 申請（1）として作成され、VDR の審査を待つ
+
+### 出金可能残高について
+- 出金可能残高は「payouts の差引支払額（源泉徴収後の net）の累計 - 承認（2）・支払済（4）の出金額の累計」
+- 全銀フォーマットの振込ファイルへ出力済みの payouts は振込で支払われるため、出金可能残高に含めない
+- 申請できる額は、出金可能残高から審査待ち（1）の出金額を差し引いた額まで（超える場合は 409）
+- 個人の行をロックした上で検証するため、同時に申請されても超過することはない
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `amount` | number | required, gte=1 | 出金額 |
+| `note` | string | max=255 | メモ |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/withdrawals",
+    summary = "出金を申請する。",
+    description = CREATE_DESC,
+    request_body = CreateWithdrawalReq,
+    responses(
+        (status = 200, description = "Success", body = CreateWithdrawalRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Forbidden", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn create_withdrawal(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<CreateWithdrawalReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::withdrawals_bl::create_withdrawal(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Approve
+// ============================================================
+const APPROVE_DESC: &str = r#"
+### ⚫︎ 概要
+- VDR が出金申請を承認し、承認（2）へ遷移させる
+- 申請（1）以外からは遷移できない（409）
+- 承認時点の出金可能残高を超える場合は承認できない（409）
+- 遷移は全て、操作した VDR の ID とともにステータス履歴へ追記される
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `withdrawal_id` | number | required, gte=1 | 出金申請ID |
+| `note` | string | max=255 | メモ（空でなければ申請のメモを上書きする） |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    patch,
+    security(("api_jwt_token" = [])),
+    path = "/withdrawals/{withdrawal_id}/approve",
+    summary = "出金申請を承認する。",
+    description = APPROVE_DESC,
+    params(
+        ("withdrawal_id" = u32, Path),
+    ),
+    request_body = ReviewWithdrawalReq,
+    responses(
+        (status = 200, description = "Success", body = ReviewWithdrawalRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn approve_withdrawal(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(withdrawal_id): Path<u32>,
+    Json(req): Json<ReviewWithdrawalReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::withdrawals_bl::approve_withdrawal(conn, &ids, withdrawal_id, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Reject
+// ============================================================
+const REJECT_DESC: &str = r#"
+### ⚫︎ 概要
+- VDR が出金申請を却下し、却下（3）へ遷移させる
+- 申請（1）以外からは遷移できない（409）
+- 却下された出金額は、出金可能残高から差し引かれない
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `withdrawal_id` | number | required, gte=1 | 出金申請ID |
+| `note` | string | max=255 | 却下理由（空でなければ申請のメモを上書きする） |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    patch,
+    security(("api_jwt_token" = [])),
+    path = "/withdrawals/{withdrawal_id}/reject",
+    summary = "出金申請を却下する。",
+    description = REJECT_DESC,
+    params(
+        ("withdrawal_id" = u32, Path),
+    ),
+    request_body = ReviewWithdrawalReq,
+    responses(
+        (status = 200, description = "Success", body = ReviewWithdrawalRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn reject_withdrawal(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(withdrawal_id): Path<u32>,
+    Json(req): Json<ReviewWithdrawalReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::withdrawals_bl::reject_withdrawal(conn, &ids, withdrawal_id, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Pay
+// ============================================================
+const PAY_DESC: &str = r#"
+### ⚫︎ 概要
+- VDR が承認済みの出金を振り込んだことを記録し、支払済（4）へ遷移させる
+- 承認（2）以外からは遷移できない（409）
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `withdrawal_id` | number | required, gte=1 | 出金申請ID |
+| `note` | string | max=255 | メモ（空でなければ申請のメモを上書きする） |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    patch,
+    security(("api_jwt_token" = [])),
+    path = "/withdrawals/{withdrawal_id}/pay",
+    summary = "出金を支払済にする。",
+    description = PAY_DESC,
+    params(
+        ("withdrawal_id" = u32, Path),
+    ),
+    request_body = ReviewWithdrawalReq,
+    responses(
+        (status = 200, description = "Success", body = ReviewWithdrawalRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn pay_withdrawal(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(withdrawal_id): Path<u32>,
+    Json(req): Json<ReviewWithdrawalReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::withdrawals_bl::pay_withdrawal(conn, &ids, withdrawal_id, req).await?;
+    Ok(Json(res))
+}
@@ -0,0 +1,257 @@
+use std::sync::Arc;
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use garde::Validate;
+use crate::{
+    mode::rt::{
+        rtreq::works_req::{SearchWorksReq, CorrectWorkReq},
+        rtres::{errs_res::ApiError, works_res::{SearchWorksRes, GetWorkRes, ClockInWorkRes, ClockOutWorkRes, CorrectWorkRes}},
+        rtutils::{db_for_rt::DbPoolsExt, config_for_rt::RtConfig}
+    },
+    utils::{db::DbPools, jwt::{JwtUsr, JwtIDs, JwtRole}}
+};
+
+const TAG: &str = "v1 Work";
+
+// ============================================================
+// Search
+// ============================================================
+const SEARCH_DESC: &str = r#"
+### ⚫︎ 概要
+- BD は全ての就業情報を検索できる
+- APX は配下の VDR 以下の全ての就業情報を検索できる
+- VDR は、配下の全ての就業情報を検索できる
+- USR は、自分が法人（from）または就業者（to）である就業情報のみ検索できる
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `job_id` | number | gte=1 | 求人ID |
+| `from` | number | gte=1 | 法人ID |
+| `to` | number | gte=1 | 就業者（個人）ID |
+| `limit` | number | gte=1, lte=25 | 取得数 |
+| `offset` | number | gte=0 | オフセット |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    post,
+    security(("api_jwt_token" = [])),
+    path = "/works/search",
+    summary = "就業情報を検索する。",
+    description = SEARCH_DESC,
+    request_body = SearchWorksReq,
+    responses(
+        (status = 200, description = "Success", body = SearchWorksRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn search_works(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Json(req): Json<SearchWorksReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::BD, JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::works_bl::search_works(conn, &ju, &ids, req).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Get
+// ============================================================
+const GET_DESC: &str = r#"
+### ⚫︎ 概要
+- APX は配下の VDR 以下の全ての就業情報を取得できる
+- VDR は、配下の全ての就業情報を取得できる
+- USR は、自分が当事者である就業情報のみ取得できる
+- 打刻の修正履歴（corrections）を時系列順に含む
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `work_id` | number | required, gte=1 | 就業ID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    get,
+    security(("api_jwt_token" = [])),
+    path = "/works/{work_id}",
+    summary = "就業情報を1件取得する。",
+    description = GET_DESC,
+    params(
+        ("work_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetWorkRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn get_work(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(work_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::APX, JwtRole::VDR, JwtRole::USR])?;
+    let conn = db.get_ro_for_rt()?;
+    let res = crate::mode::rt::rtbl::works_bl::get_work(conn, &ju, &ids, work_id).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Clock In
+// ============================================================
+const CLOCK_IN_DESC: &str = r#"
+### ⚫︎ 概要
+- 就業者（個人）本人が、出勤を打刻する（real_work_bgn_at に現在日時を記録）
+- USR のみ使用でき、自分が就業者（to）である就業情報のみ操作できる
+- 既に出勤打刻済みの場合は 409 となる
+
+### 許容幅について
+- 就業開始日時予定（work_bgn_at）が設定されている場合、その前後 RT_CLOCK_TOLERANCE_MIN 分以内でのみ打刻できる
+- 許容幅の外での打刻は 400 となる
+- 就業開始日時予定が未設定の場合は、いつでも打刻できる
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `work_id` | number | required, gte=1 | 就業ID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    patch,
+    security(("api_jwt_token" = [])),
+    path = "/works/{work_id}/clock_in",
+    summary = "出勤を打刻する。",
+    description = CLOCK_IN_DESC,
+    params(
+        ("work_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = ClockInWorkRes),
+        (status = 400, description = "Bad Request", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn clock_in_work(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Extension(cfg): Extension<Arc<RtConfig>>,
+    Path(work_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::USR])?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::works_bl::clock_in_work(conn, &ju, &ids, &cfg, work_id).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Clock Out
+// ============================================================
+const CLOCK_OUT_DESC: &str = r#"
+### ⚫︎ 概要
+- 就業者（個人）本人が、退勤を打刻する（real_work_end_at に現在日時を記録）
+- USR のみ使用でき、自分が就業者（to）である就業情報のみ操作できる
+- 出勤打刻前、または既に退勤打刻済みの場合は 409 となる
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `work_id` | number | required, gte=1 | 就業ID |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    patch,
+    security(("api_jwt_token" = [])),
+    path = "/works/{work_id}/clock_out",
+    summary = "退勤を打刻する。",
+    description = CLOCK_OUT_DESC,
+    params(
+        ("work_id" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = ClockOutWorkRes),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 409, description = "Conflict", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn clock_out_work(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(work_id): Path<u32>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::USR])?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::works_bl::clock_out_work(conn, &ju, &ids, work_id).await?;
+    Ok(Json(res))
+}
+
+// ============================================================
+// Correct
+// ============================================================
+const CORRECT_DESC: &str = r#"
+### ⚫︎ 概要
+- 法人が、就業者の打刻（real_work_bgn_at / real_work_end_at）を修正する
+- VDR は代理で操作できる
+- USR は、自分が法人（from）である就業情報のみ操作できる
+- 修正前後の値・修正者・理由は修正履歴（work_corrections）として記録され、削除されない
+- スタッフ token で操作した場合、スタッフの UsrID も記録される
+
+### パラメータについて
+- 省略した項目は現在の値のまま
+- 修正後の値で real_work_bgn_at <= real_work_end_at でなければならない
+- real_work_bgn_at が無いまま real_work_end_at のみを設定することはできない
+
+### ⚫︎ Request
+| KEY | TYPE | VALIDATION | DESCRIPTION |
+| --- | --- | --- | --- |
+| `work_id` | number | required, gte=1 | 就業ID |
+| `real_work_bgn_at` | string | datetime | 就業開始日時実績 |
+| `real_work_end_at` | string | datetime | 就業終了日時実績 |
+| `reason` | string | required, max=400 | 修正理由 |
+"#;
+#[utoipa::path(
+    tag = TAG,
+    patch,
+    security(("api_jwt_token" = [])),
+    path = "/works/{work_id}/correct",
+    summary = "打刻を修正する。",
+    description = CORRECT_DESC,
+    params(
+        ("work_id" = u32, Path),
+    ),
+    request_body = CorrectWorkReq,
+    responses(
+        (status = 200, description = "Success", body = CorrectWorkRes),
+        (status = 400, description = "Bad Request", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not Found", body = ApiError),
+        (status = 422, description = "Validation Error", body = ApiError),
+        (status = 500, description = "Internal Server Error", body = ApiError)
+    )
+)]
+pub async fn correct_work(
+    ju: JwtUsr,
+    ids: JwtIDs,
+    Extension(db): Extension<Arc<DbPools>>,
+    Path(work_id): Path<u32>,
+    Json(req): Json<CorrectWorkReq>,
+) -> Result<impl IntoResponse, ApiError> {
+    ju.allow_roles(&[JwtRole::VDR, JwtRole::USR])?;
+    req.validate().map_err(ApiError::from_garde)?;
+    let conn = db.get_rw_for_rt()?;
+    let res = crate::mode::rt::rtbl::works_bl::correct_work(conn, &ju, &ids, work_id, req).await?;
+    Ok(Json(res))
+}
@@ -0,0 +1,78 @@
+use serde::Deserialize;
+use garde::Validate;
+use utoipa::{IntoParams, ToSchema};
+use crate::mode::rt::rterr::rterr::*;
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Deserialize, IntoParams, Validate, ToSchema)]
+pub struct SearchBadgesReq {
+    #[schema(example = "接客")]
+    #[garde(custom(length_chars_err(0, 50)))]
+    pub name: String,
+
+    #[schema(example = 3)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub corp_id: Option<u32>,
+
+    #[schema(default = false)]
+    #[garde(skip)]
+    pub include_retired: bool,
+
+    #[schema(default = 10)]
+    #[garde(custom(range_err(Some(1u16), Some(25u16))))]
+    pub limit: u16,
+
+    #[schema(default = 0)]
+    #[garde(custom(range_err(Some(0u16), None)))]
+    pub offset: u16,
+}
+
+// ============================================================
+// Create
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct CreateBadgeReq {
+    #[schema(example = "接客マスター")]
+    #[garde(custom(required_simple_err(1, 400)))]
+    #[garde(custom(length_chars_err(0, 50)))]
+    pub name: String,
+
+    #[schema(example = "接客")]
+    #[garde(custom(required_simple_err(1, 400)))]
+    #[garde(custom(length_chars_err(0, 20)))]
+    pub short_name: String,
+
+    #[schema(example = "丁寧な接客で高い評価を得た方に授与します。")]
+    #[garde(custom(length_chars_err(0, 255)))]
+    pub description: String,
+
+    #[schema(example = 12)]
+    #[garde(inner(custom(range_err(Some(0u32), Some(120u32)))))]
+    pub valid_months: Option<u32>,
+}
+
+// ============================================================
+// Update
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct UpdateBadgeReq {
+    #[schema(example = "接客マスター")]
+    #[garde(inner(custom(required_simple_err(1, 400))))]
+    #[garde(inner(custom(length_chars_err(0, 50))))]
+    pub name: Option<String>,
+
+    #[schema(example = "接客")]
+    #[garde(inner(custom(required_simple_err(1, 400))))]
+    #[garde(inner(custom(length_chars_err(0, 20))))]
+    pub short_name: Option<String>,
+
+    #[schema(example = "丁寧な接客で高い評価を得た方に授与します。")]
+    #[garde(inner(custom(length_chars_err(0, 255))))]
+    pub description: Option<String>,
+
+    #[schema(example = 12)]
+    #[garde(inner(custom(range_err(Some(0u32), Some(120u32)))))]
+    pub valid_months: Option<u32>,
+}
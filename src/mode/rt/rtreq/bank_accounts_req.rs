@@ -0,0 +1,102 @@
+use serde::Deserialize;
+use garde::Validate;
+use utoipa::{IntoParams, ToSchema};
+use crate::mode::rt::rterr::rterr::*;
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Deserialize, IntoParams, Validate, ToSchema)]
+pub struct SearchBankAccountsReq {
+    #[schema(example = 5)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub usr_id: Option<u32>,
+
+    #[schema(default = 10)]
+    #[garde(custom(range_err(Some(1u16), Some(25u16))))]
+    pub limit: u16,
+
+    #[schema(default = 0)]
+    #[garde(custom(range_err(Some(0u16), None)))]
+    pub offset: u16,
+}
+
+// ============================================================
+// Create
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct CreateBankAccountReq {
+    #[schema(example = "0001")]
+    #[garde(custom(numeric_err))]
+    #[garde(custom(length_simple_err(4, 4)))]
+    pub bank_code: String,
+
+    #[schema(example = "ミズホ")]
+    #[garde(custom(required_simple_err(1, 400)))]
+    #[garde(custom(length_chars_err(0, 15)))]
+    pub bank_name: String,
+
+    #[schema(example = "001")]
+    #[garde(custom(numeric_err))]
+    #[garde(custom(length_simple_err(3, 3)))]
+    pub branch_code: String,
+
+    #[schema(example = "トウキヨウ")]
+    #[garde(custom(required_simple_err(1, 400)))]
+    #[garde(custom(length_chars_err(0, 15)))]
+    pub branch_name: String,
+
+    #[schema(example = 1)]
+    #[garde(custom(range_err(Some(1u8), Some(4u8))))]
+    pub account_type: u8,
+
+    #[schema(example = "1234567")]
+    #[garde(custom(numeric_err))]
+    #[garde(custom(length_simple_err(1, 7)))]
+    pub account_number: String,
+
+    #[schema(example = "ヤマダ タロウ")]
+    #[garde(custom(required_simple_err(1, 400)))]
+    #[garde(custom(length_chars_err(0, 30)))]
+    pub holder: String,
+}
+
+// ============================================================
+// Update
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct UpdateBankAccountReq {
+    #[schema(example = "0001")]
+    #[garde(inner(custom(numeric_err)))]
+    #[garde(inner(custom(length_simple_err(4, 4))))]
+    pub bank_code: Option<String>,
+
+    #[schema(example = "ミズホ")]
+    #[garde(inner(custom(required_simple_err(1, 400))))]
+    #[garde(inner(custom(length_chars_err(0, 15))))]
+    pub bank_name: Option<String>,
+
+    #[schema(example = "001")]
+    #[garde(inner(custom(numeric_err)))]
+    #[garde(inner(custom(length_simple_err(3, 3))))]
+    pub branch_code: Option<String>,
+
+    #[schema(example = "トウキヨウ")]
+    #[garde(inner(custom(required_simple_err(1, 400))))]
+    #[garde(inner(custom(length_chars_err(0, 15))))]
+    pub branch_name: Option<String>,
+
+    #[schema(example = 1)]
+    #[garde(inner(custom(range_err(Some(1u8), Some(4u8)))))]
+    pub account_type: Option<u8>,
+
+    #[schema(example = "1234567")]
+    #[garde(inner(custom(numeric_err)))]
+    #[garde(inner(custom(length_simple_err(1, 7))))]
+    pub account_number: Option<String>,
+
+    #[schema(example = "ヤマダ タロウ")]
+    #[garde(inner(custom(required_simple_err(1, 400))))]
+    #[garde(inner(custom(length_chars_err(0, 30))))]
+    pub holder: Option<String>,
+}
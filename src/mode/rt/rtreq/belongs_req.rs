@@ -0,0 +1,58 @@
+use serde::Deserialize;
+use garde::Validate;
+use utoipa::{IntoParams, ToSchema};
+use crate::mode::rt::rterr::rterr::*;
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Deserialize, IntoParams, Validate, ToSchema)]
+pub struct SearchBelongsReq {
+    #[schema(example = 3)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub corp_id: Option<u32>,
+
+    #[schema(example = 5)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub usr_id: Option<u32>,
+
+    #[schema(example = "2026-04-01T00:00:00")]
+    #[garde(inner(custom(datetime_err)))]
+    pub at: Option<String>,
+
+    #[schema(default = 10)]
+    #[garde(custom(range_err(Some(1u16), Some(25u16))))]
+    pub limit: u16,
+
+    #[schema(default = 0)]
+    #[garde(custom(range_err(Some(0u16), None)))]
+    pub offset: u16,
+}
+
+// ============================================================
+// Create
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct CreateBelongReq {
+    #[schema(example = 3)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub corp_id: Option<u32>,
+
+    #[schema(example = 5)]
+    #[garde(custom(range_err(Some(1u32), None)))]
+    pub usr_id: u32,
+
+    #[schema(example = "2026-04-01T00:00:00")]
+    #[garde(inner(custom(datetime_err)))]
+    pub open_at: Option<String>,
+}
+
+// ============================================================
+// Close
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct CloseBelongReq {
+    #[schema(example = "2026-09-30T23:59:59")]
+    #[garde(inner(custom(datetime_err)))]
+    pub close_at: Option<String>,
+}
@@ -0,0 +1,35 @@
+use serde::Deserialize;
+use garde::Validate;
+use utoipa::{IntoParams, ToSchema};
+use crate::mode::rt::rterr::rterr::*;
+
+// ============================================================
+// Report / Hold
+// ============================================================
+#[derive(Deserialize, IntoParams, Validate, ToSchema)]
+pub struct CollusionReportReq {
+    /// 分析対象とする期間（直近の日数、未指定の場合は 30）
+    #[schema(example = 30)]
+    #[garde(inner(custom(range_err(Some(1u32), Some(365u32)))))]
+    pub days: Option<u32>,
+
+    /// 相互授与とみなす各方向の最小授与数（未指定の場合は 1）
+    #[schema(example = 1)]
+    #[garde(inner(custom(range_err(Some(1u32), Some(100u32)))))]
+    pub min_reciprocal: Option<u32>,
+
+    /// 密なグループとみなす最小人数（未指定の場合は 3）
+    #[schema(example = 3)]
+    #[garde(inner(custom(range_err(Some(3u32), Some(10u32)))))]
+    pub min_clique: Option<u32>,
+
+    /// 集中とみなす時間幅（時間、未指定の場合は 24）
+    #[schema(example = 24)]
+    #[garde(inner(custom(range_err(Some(1u32), Some(168u32)))))]
+    pub burst_hours: Option<u32>,
+
+    /// 時間幅内の同じ授与者による授与がこの数以上で集中とみなす（未指定の場合は 10）
+    #[schema(example = 10)]
+    #[garde(inner(custom(range_err(Some(2u32), Some(1000u32)))))]
+    pub burst_count: Option<u32>,
+}
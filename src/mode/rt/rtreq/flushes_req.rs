@@ -0,0 +1,117 @@
+use serde::Deserialize;
+use garde::Validate;
+use utoipa::{IntoParams, ToSchema};
+use crate::mode::rt::rterr::rterr::*;
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Deserialize, IntoParams, Validate, ToSchema)]
+pub struct SearchFlushesReq {
+    #[schema(default = 10)]
+    #[garde(custom(range_err(Some(1u16), Some(25u16))))]
+    pub limit: u16,
+
+    #[schema(default = 0)]
+    #[garde(custom(range_err(Some(0u16), None)))]
+    pub offset: u16,
+}
+
+// ============================================================
+// Preview
+// ============================================================
+#[derive(Deserialize, IntoParams, Validate, ToSchema)]
+pub struct PreviewFlushReq {
+    /// 仮定するプール残高（未指定の場合は現在の pools.remain）
+    #[schema(example = 100000)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub total: Option<u32>,
+}
+
+// ============================================================
+// Search Payouts
+// ============================================================
+#[derive(Deserialize, IntoParams, Validate, ToSchema)]
+pub struct SearchPayoutsReq {
+    #[schema(example = 1)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub flush_id: Option<u32>,
+
+    #[schema(example = 5)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub usr_id: Option<u32>,
+
+    #[schema(default = 10)]
+    #[garde(custom(range_err(Some(1u16), Some(25u16))))]
+    pub limit: u16,
+
+    #[schema(default = 0)]
+    #[garde(custom(range_err(Some(0u16), None)))]
+    pub offset: u16,
+}
+
+// ============================================================
+// Withholding Summary
+// ============================================================
+#[derive(Deserialize, IntoParams, Validate, ToSchema)]
+pub struct SummarizeWithholdingReq {
+    /// 集計する暦年（1/1 〜 12/31、分配日時で判定）
+    #[schema(example = 2026)]
+    #[garde(custom(range_err(Some(2000i32), Some(2100i32))))]
+    pub year: i32,
+
+    #[schema(example = 5)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub usr_id: Option<u32>,
+}
+
+// ============================================================
+// Export Zengin
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct ExportFlushZenginReq {
+    /// 振込依頼人コード（金融機関との契約時に払い出される 10 桁）
+    #[schema(example = "1234567890")]
+    #[garde(custom(numeric_err))]
+    #[garde(custom(length_simple_err(10, 10)))]
+    pub consignor_code: String,
+
+    #[schema(example = "カブシキガイシヤ ビ-エスデイ-ア-ル")]
+    #[garde(custom(required_simple_err(1, 400)))]
+    #[garde(custom(length_chars_err(0, 40)))]
+    pub consignor_name: String,
+
+    /// 振込指定日
+    #[schema(example = "2026-10-25")]
+    #[garde(custom(date_err))]
+    pub transfer_date: String,
+
+    #[schema(example = "0001")]
+    #[garde(custom(numeric_err))]
+    #[garde(custom(length_simple_err(4, 4)))]
+    pub bank_code: String,
+
+    #[schema(example = "ミズホ")]
+    #[garde(custom(required_simple_err(1, 400)))]
+    #[garde(custom(length_chars_err(0, 15)))]
+    pub bank_name: String,
+
+    #[schema(example = "001")]
+    #[garde(custom(numeric_err))]
+    #[garde(custom(length_simple_err(3, 3)))]
+    pub branch_code: String,
+
+    #[schema(example = "トウキヨウ")]
+    #[garde(custom(required_simple_err(1, 400)))]
+    #[garde(custom(length_chars_err(0, 15)))]
+    pub branch_name: String,
+
+    #[schema(example = 1)]
+    #[garde(custom(range_err(Some(1u8), Some(4u8))))]
+    pub account_type: u8,
+
+    #[schema(example = "1234567")]
+    #[garde(custom(numeric_err))]
+    #[garde(custom(length_simple_err(1, 7)))]
+    pub account_number: String,
+}
@@ -0,0 +1,162 @@
+use serde::Deserialize;
+use garde::Validate;
+use utoipa::{IntoParams, ToSchema};
+use crate::mode::rt::rterr::rterr::*;
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Deserialize, IntoParams, Validate, ToSchema)]
+pub struct SearchJobsReq {
+    #[schema(example = "ホールスタッフ")]
+    #[garde(custom(length_chars_err(0, 100)))]
+    pub name: String,
+
+    #[schema(example = "渋谷区")]
+    #[garde(custom(length_chars_err(0, 128)))]
+    pub location: String,
+
+    #[schema(example = 3)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub corp_id: Option<u32>,
+
+    #[schema(example = "2026-01-01T00:00:00")]
+    #[garde(custom(required_simple_err(1, 100)))]
+    #[garde(custom(datetime_err))]
+    pub bgn_at: String,
+
+    #[schema(example = "2026-12-31T23:59:59")]
+    #[garde(custom(required_simple_err(1, 100)))]
+    #[garde(custom(datetime_err))]
+    pub end_at: String,
+
+    #[schema(default = 10)]
+    #[garde(custom(range_err(Some(1u16), Some(25u16))))]
+    pub limit: u16,
+
+    #[schema(default = 0)]
+    #[garde(custom(range_err(Some(0u16), None)))]
+    pub offset: u16,
+}
+
+// ============================================================
+// Create
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct CreateJobReq {
+    #[schema(example = 3)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub corp_id: Option<u32>,
+
+    #[schema(example = "ホールスタッフ")]
+    #[garde(custom(required_simple_err(1, 400)))]
+    #[garde(custom(length_chars_err(0, 100)))]
+    pub name: String,
+
+    #[schema(example = "飲食店でのホール業務全般をお願いします。")]
+    #[garde(custom(length_chars_err(0, 1000)))]
+    pub description: String,
+
+    #[schema(example = 5)]
+    #[garde(custom(range_err(Some(1u32), None)))]
+    pub max: u32,
+
+    #[schema(example = 1200)]
+    #[garde(custom(range_err(Some(0u32), None)))]
+    pub hour_price: u32,
+
+    #[schema(example = "土日に勤務できる方")]
+    #[garde(custom(length_chars_err(0, 1000)))]
+    pub requirements: String,
+
+    #[schema(example = "まかない付き")]
+    #[garde(custom(length_chars_err(0, 1000)))]
+    pub benefits: String,
+
+    #[schema(example = "東京都渋谷区道玄坂1-1-1")]
+    #[garde(custom(length_chars_err(0, 128)))]
+    pub location: String,
+
+    #[schema(example = "0312345678")]
+    #[garde(custom(length_simple_err(0, 15)))]
+    pub phone: String,
+
+    #[schema(example = 3)]
+    #[garde(custom(range_err(Some(0u32), None)))]
+    pub max_badges: u32,
+
+    #[schema(example = "2026-04-01T09:00:00")]
+    #[garde(inner(custom(datetime_err)))]
+    pub work_bgn_at: Option<String>,
+
+    #[schema(example = "2026-04-01T18:00:00")]
+    #[garde(inner(custom(datetime_err)))]
+    pub work_end_at: Option<String>,
+
+    #[schema(example = "2026-03-01T00:00:00")]
+    #[garde(inner(custom(datetime_err)))]
+    pub open_at: Option<String>,
+
+    #[schema(example = "2026-03-31T23:59:59")]
+    #[garde(inner(custom(datetime_err)))]
+    pub close_at: Option<String>,
+}
+
+// ============================================================
+// Update
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct UpdateJobReq {
+    #[schema(example = "ホールスタッフ")]
+    #[garde(inner(custom(required_simple_err(1, 400))))]
+    #[garde(inner(custom(length_chars_err(0, 100))))]
+    pub name: Option<String>,
+
+    #[schema(example = "飲食店でのホール業務全般をお願いします。")]
+    #[garde(inner(custom(length_chars_err(0, 1000))))]
+    pub description: Option<String>,
+
+    #[schema(example = 5)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub max: Option<u32>,
+
+    #[schema(example = 1200)]
+    #[garde(inner(custom(range_err(Some(0u32), None))))]
+    pub hour_price: Option<u32>,
+
+    #[schema(example = "土日に勤務できる方")]
+    #[garde(inner(custom(length_chars_err(0, 1000))))]
+    pub requirements: Option<String>,
+
+    #[schema(example = "まかない付き")]
+    #[garde(inner(custom(length_chars_err(0, 1000))))]
+    pub benefits: Option<String>,
+
+    #[schema(example = "東京都渋谷区道玄坂1-1-1")]
+    #[garde(inner(custom(length_chars_err(0, 128))))]
+    pub location: Option<String>,
+
+    #[schema(example = "0312345678")]
+    #[garde(inner(custom(length_simple_err(0, 15))))]
+    pub phone: Option<String>,
+
+    #[schema(example = 3)]
+    #[garde(inner(custom(range_err(Some(0u32), None))))]
+    pub max_badges: Option<u32>,
+
+    #[schema(example = "2026-04-01T09:00:00")]
+    #[garde(inner(custom(datetime_err)))]
+    pub work_bgn_at: Option<String>,
+
+    #[schema(example = "2026-04-01T18:00:00")]
+    #[garde(inner(custom(datetime_err)))]
+    pub work_end_at: Option<String>,
+
+    #[schema(example = "2026-03-01T00:00:00")]
+    #[garde(inner(custom(datetime_err)))]
+    pub open_at: Option<String>,
+
+    #[schema(example = "2026-03-31T23:59:59")]
+    #[garde(inner(custom(datetime_err)))]
+    pub close_at: Option<String>,
+}
@@ -0,0 +1,25 @@
+use serde::Deserialize;
+use garde::Validate;
+use utoipa::{IntoParams, ToSchema};
+use crate::mode::rt::rterr::rterr::*;
+
+// ============================================================
+// Export
+// ============================================================
+#[derive(Deserialize, IntoParams, Validate, ToSchema)]
+pub struct ExportJournalReq {
+    /// 対象の VDR（APX のみ指定する）
+    #[schema(example = 2)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub vdr_id: Option<u32>,
+
+    /// 期間の開始日（この日を含む）
+    #[schema(example = "2026-10-01")]
+    #[garde(custom(date_err))]
+    pub bgn_date: String,
+
+    /// 期間の終了日（この日を含む）
+    #[schema(example = "2026-10-31")]
+    #[garde(custom(date_err))]
+    pub end_date: String,
+}
@@ -0,0 +1,90 @@
+use serde::Deserialize;
+use garde::Validate;
+use utoipa::{IntoParams, ToSchema};
+use crate::mode::rt::rterr::rterr::*;
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Deserialize, IntoParams, Validate, ToSchema)]
+pub struct SearchMatchesReq {
+    #[schema(example = 1)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub job_id: Option<u32>,
+
+    #[schema(example = 3)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub from: Option<u32>,
+
+    #[schema(example = 5)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub to: Option<u32>,
+
+    #[schema(example = 1)]
+    #[garde(inner(custom(range_err(Some(1u8), Some(5u8)))))]
+    pub status: Option<u8>,
+
+    #[schema(default = 10)]
+    #[garde(custom(range_err(Some(1u16), Some(25u16))))]
+    pub limit: u16,
+
+    #[schema(default = 0)]
+    #[garde(custom(range_err(Some(0u16), None)))]
+    pub offset: u16,
+}
+
+// ============================================================
+// Create
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct CreateMatchReq {
+    #[schema(example = 1)]
+    #[garde(custom(range_err(Some(1u32), None)))]
+    pub job_id: u32,
+
+    #[schema(example = 5)]
+    #[garde(custom(range_err(Some(1u32), None)))]
+    pub to: u32,
+
+    #[schema(example = 1)]
+    #[garde(inner(custom(range_err(Some(0u8), Some(3u8)))))]
+    pub match_reason: Option<u8>,
+}
+
+// ============================================================
+// Accept
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct AcceptMatchReq {
+    #[schema(example = true)]
+    #[garde(skip)]
+    pub is_tmp: bool,
+}
+
+// ============================================================
+// Update Status
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct UpdateMatchStatusReq {
+    #[schema(example = 3)]
+    #[garde(custom(range_err(Some(3u8), Some(4u8))))]
+    pub status: u8,
+}
+
+// ============================================================
+// Candidates
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct SearchMatchCandidatesReq {
+    #[schema(example = 1)]
+    #[garde(custom(range_err(Some(1u32), None)))]
+    pub job_id: u32,
+
+    #[schema(example = 1)]
+    #[garde(custom(range_err(Some(1u8), Some(3u8))))]
+    pub match_reason: u8,
+
+    #[schema(default = 10)]
+    #[garde(custom(range_err(Some(1u16), Some(25u16))))]
+    pub limit: u16,
+}
@@ -1,3 +1,18 @@
 pub mod bds_req;
 pub mod usrs_req;
-pub mod cryptos_req;
\ No newline at end of file
+pub mod cryptos_req;
+pub mod jobs_req;
+pub mod matches_req;
+pub mod works_req;
+pub mod belongs_req;
+pub mod badges_req;
+pub mod usr_badges_req;
+pub mod points_req;
+pub mod payments_req;
+pub mod flushes_req;
+pub mod bank_accounts_req;
+pub mod withdrawals_req;
+pub mod journals_req;
+pub mod pools_req;
+pub mod point_adjustments_req;
+pub mod collusion_req;
\ No newline at end of file
@@ -0,0 +1,58 @@
+use serde::Deserialize;
+use garde::Validate;
+use utoipa::{IntoParams, ToSchema};
+use crate::mode::rt::rterr::rterr::*;
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Deserialize, IntoParams, Validate, ToSchema)]
+pub struct SearchPaymentsReq {
+    #[schema(example = 3)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub corp_id: Option<u32>,
+
+    #[schema(example = 1)]
+    #[garde(inner(custom(range_err(Some(1u8), Some(2u8)))))]
+    pub r#type: Option<u8>,
+
+    #[schema(default = 10)]
+    #[garde(custom(range_err(Some(1u16), Some(25u16))))]
+    pub limit: u16,
+
+    #[schema(default = 0)]
+    #[garde(custom(range_err(Some(0u16), None)))]
+    pub offset: u16,
+}
+
+// ============================================================
+// Create
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct CreatePaymentReq {
+    #[schema(example = 3)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub corp_id: Option<u32>,
+
+    #[schema(example = 1)]
+    #[garde(custom(range_err(Some(1u8), Some(2u8))))]
+    pub r#type: u8,
+
+    #[schema(example = 10000)]
+    #[garde(custom(range_err(Some(1u32), None)))]
+    pub amount: u32,
+
+    #[schema(example = "4月分 面談フィー")]
+    #[garde(custom(length_chars_err(0, 255)))]
+    pub note: String,
+}
+
+// ============================================================
+// Refund
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct RefundPaymentReq {
+    #[schema(example = "誤入金のため返金")]
+    #[garde(custom(length_chars_err(0, 255)))]
+    pub note: String,
+}
@@ -0,0 +1,52 @@
+use serde::Deserialize;
+use garde::Validate;
+use utoipa::{IntoParams, ToSchema};
+use crate::mode::rt::rterr::rterr::*;
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Deserialize, IntoParams, Validate, ToSchema)]
+pub struct SearchPointAdjustmentsReq {
+    #[schema(example = 5)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub usr_id: Option<u32>,
+
+    #[schema(example = 1)]
+    #[garde(inner(custom(range_err(Some(1u8), Some(4u8)))))]
+    pub reason: Option<u8>,
+
+    #[schema(example = 1)]
+    #[garde(inner(custom(range_err(Some(1u8), Some(3u8)))))]
+    pub status: Option<u8>,
+
+    #[schema(default = 10)]
+    #[garde(custom(range_err(Some(1u16), Some(25u16))))]
+    pub limit: u16,
+
+    #[schema(default = 0)]
+    #[garde(custom(range_err(Some(0u16), None)))]
+    pub offset: u16,
+}
+
+// ============================================================
+// Create
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct CreatePointAdjustmentReq {
+    #[schema(example = 5)]
+    #[garde(custom(range_err(Some(1u32), None)))]
+    pub usr_id: u32,
+
+    #[schema(example = -10)]
+    #[garde(custom(range_err(Some(-1_000_000i32), Some(1_000_000i32))))]
+    pub delta: i32,
+
+    #[schema(example = 1)]
+    #[garde(custom(range_err(Some(1u8), Some(4u8))))]
+    pub reason: u8,
+
+    #[schema(example = "")]
+    #[garde(custom(length_chars_err(0, 255)))]
+    pub note: String,
+}
@@ -0,0 +1,48 @@
+use serde::Deserialize;
+use garde::Validate;
+use utoipa::{IntoParams, ToSchema};
+use crate::mode::rt::rterr::rterr::*;
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Deserialize, IntoParams, Validate, ToSchema)]
+pub struct SearchPointsReq {
+    #[schema(example = 1)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub badge_id: Option<u32>,
+
+    #[schema(example = 3)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub corp_id: Option<u32>,
+
+    #[schema(example = 4)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub from: Option<u32>,
+
+    #[schema(example = 5)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub to: Option<u32>,
+
+    #[schema(default = 10)]
+    #[garde(custom(range_err(Some(1u16), Some(25u16))))]
+    pub limit: u16,
+
+    #[schema(default = 0)]
+    #[garde(custom(range_err(Some(0u16), None)))]
+    pub offset: u16,
+}
+
+// ============================================================
+// Expiring
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct ExpiringPointsReq {
+    #[schema(example = 5)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub usr_id: Option<u32>,
+
+    #[schema(default = 30)]
+    #[garde(custom(range_err(Some(1u16), Some(365u16))))]
+    pub days: u16,
+}
@@ -0,0 +1,44 @@
+use serde::Deserialize;
+use garde::Validate;
+use utoipa::{IntoParams, ToSchema};
+use crate::mode::rt::rterr::rterr::*;
+
+// ============================================================
+// Ledger
+// ============================================================
+#[derive(Deserialize, IntoParams, Validate, ToSchema)]
+pub struct SearchPoolLedgerReq {
+    /// 対象の VDR（APX のみ指定する）
+    #[schema(example = 2)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub vdr_id: Option<u32>,
+
+    /// 期間の開始日（この日を含む。未指定の場合は最初から）
+    #[schema(example = "2026-10-01")]
+    #[garde(inner(custom(date_err)))]
+    pub bgn_date: Option<String>,
+
+    /// 期間の終了日（この日を含む。未指定の場合は最後まで）
+    #[schema(example = "2026-10-31")]
+    #[garde(inner(custom(date_err)))]
+    pub end_date: Option<String>,
+
+    #[schema(default = 10)]
+    #[garde(custom(range_err(Some(1u16), Some(25u16))))]
+    pub limit: u16,
+
+    #[schema(default = 0)]
+    #[garde(custom(range_err(Some(0u16), None)))]
+    pub offset: u16,
+}
+
+// ============================================================
+// Check
+// ============================================================
+#[derive(Deserialize, IntoParams, Validate, ToSchema)]
+pub struct CheckPoolReq {
+    /// 対象の VDR（APX のみ指定する）
+    #[schema(example = 2)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub vdr_id: Option<u32>,
+}
@@ -0,0 +1,82 @@
+use serde::Deserialize;
+use garde::Validate;
+use utoipa::{IntoParams, ToSchema};
+use crate::mode::rt::rterr::rterr::*;
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Deserialize, IntoParams, Validate, ToSchema)]
+pub struct SearchUsrBadgesReq {
+    #[schema(example = 1)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub badge_id: Option<u32>,
+
+    #[schema(example = 3)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub corp_id: Option<u32>,
+
+    #[schema(example = 4)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub from: Option<u32>,
+
+    #[schema(example = 5)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub to: Option<u32>,
+
+    #[schema(example = 1)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub job_id: Option<u32>,
+
+    #[schema(example = true)]
+    #[garde(skip)]
+    pub active: Option<bool>,
+
+    #[schema(default = 10)]
+    #[garde(custom(range_err(Some(1u16), Some(25u16))))]
+    pub limit: u16,
+
+    #[schema(default = 0)]
+    #[garde(custom(range_err(Some(0u16), None)))]
+    pub offset: u16,
+}
+
+// ============================================================
+// Award
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct AwardUsrBadgeReq {
+    #[schema(example = 1)]
+    #[garde(custom(range_err(Some(1u32), None)))]
+    pub badge_id: u32,
+
+    #[schema(example = 5)]
+    #[garde(custom(range_err(Some(1u32), None)))]
+    pub to: u32,
+
+    #[schema(example = 1)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub job_id: Option<u32>,
+
+    #[schema(example = "いつもありがとう")]
+    #[garde(custom(length_chars_err(0, 100)))]
+    pub title: String,
+
+    #[schema(example = "丁寧な接客でお客様からも好評でした。")]
+    #[garde(custom(length_chars_err(0, 500)))]
+    pub message: String,
+}
+
+// ============================================================
+// Revoke
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct RevokeUsrBadgeReq {
+    #[schema(example = 1)]
+    #[garde(custom(range_err(Some(1u8), Some(3u8))))]
+    pub reason: u8,
+
+    #[schema(example = "別の個人と取り違えて授与したため")]
+    #[garde(custom(length_chars_err(0, 255)))]
+    pub note: String,
+}
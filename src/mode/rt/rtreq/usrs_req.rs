@@ -115,6 +115,26 @@ pub struct CreateUsrReq {
     #[schema(example = 0.05)]
     #[garde(inner(custom(range_err(Some(0.0f64), None))))]
     pub flush_fee_rate: Option<f64>,
+
+    #[schema(example = 0)]
+    #[garde(inner(custom(range_err(Some(0u32), None))))]
+    pub withholding_threshold: Option<u32>,
+
+    #[schema(example = 0.2042)]
+    #[garde(inner(custom(range_err(Some(0.0f64), Some(0.99999f64)))))]
+    pub withholding_rate: Option<f64>,
+
+    #[schema(example = 0.1021)]
+    #[garde(inner(custom(range_err(Some(0.0f64), Some(0.99999f64)))))]
+    pub withholding_reduced_rate: Option<f64>,
+
+    #[schema(example = 1000000)]
+    #[garde(inner(custom(range_err(Some(0u32), None))))]
+    pub withholding_reduced_limit: Option<u32>,
+
+    #[schema(example = 6)]
+    #[garde(inner(custom(range_err(Some(0u32), Some(120u32)))))]
+    pub point_expire_months: Option<u32>,
 }
 
 // ============================================================
@@ -171,4 +191,24 @@ pub struct UpdateUsrReq {
     #[schema(example = 0.05)]
     #[garde(inner(custom(range_err(Some(0.0f64), None))))]
     pub flush_fee_rate: Option<f64>,
+
+    #[schema(example = 0)]
+    #[garde(inner(custom(range_err(Some(0u32), None))))]
+    pub withholding_threshold: Option<u32>,
+
+    #[schema(example = 0.2042)]
+    #[garde(inner(custom(range_err(Some(0.0f64), Some(0.99999f64)))))]
+    pub withholding_rate: Option<f64>,
+
+    #[schema(example = 0.1021)]
+    #[garde(inner(custom(range_err(Some(0.0f64), Some(0.99999f64)))))]
+    pub withholding_reduced_rate: Option<f64>,
+
+    #[schema(example = 1000000)]
+    #[garde(inner(custom(range_err(Some(0u32), None))))]
+    pub withholding_reduced_limit: Option<u32>,
+
+    #[schema(example = 6)]
+    #[garde(inner(custom(range_err(Some(0u32), Some(120u32)))))]
+    pub point_expire_months: Option<u32>,
 }
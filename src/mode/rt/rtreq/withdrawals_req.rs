@@ -0,0 +1,50 @@
+use serde::Deserialize;
+use garde::Validate;
+use utoipa::{IntoParams, ToSchema};
+use crate::mode::rt::rterr::rterr::*;
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Deserialize, IntoParams, Validate, ToSchema)]
+pub struct SearchWithdrawalsReq {
+    #[schema(example = 5)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub usr_id: Option<u32>,
+
+    #[schema(example = 1)]
+    #[garde(inner(custom(range_err(Some(1u8), Some(4u8)))))]
+    pub status: Option<u8>,
+
+    #[schema(default = 10)]
+    #[garde(custom(range_err(Some(1u16), Some(25u16))))]
+    pub limit: u16,
+
+    #[schema(default = 0)]
+    #[garde(custom(range_err(Some(0u16), None)))]
+    pub offset: u16,
+}
+
+// ============================================================
+// Create
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct CreateWithdrawalReq {
+    #[schema(example = 10000)]
+    #[garde(custom(range_err(Some(1u32), None)))]
+    pub amount: u32,
+
+    #[schema(example = "")]
+    #[garde(custom(length_chars_err(0, 255)))]
+    pub note: String,
+}
+
+// ============================================================
+// Review (Approve / Reject / Pay)
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct ReviewWithdrawalReq {
+    #[schema(example = "")]
+    #[garde(custom(length_chars_err(0, 255)))]
+    pub note: String,
+}
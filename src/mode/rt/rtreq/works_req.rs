@@ -0,0 +1,49 @@
+use serde::Deserialize;
+use garde::Validate;
+use utoipa::{IntoParams, ToSchema};
+use crate::mode::rt::rterr::rterr::*;
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Deserialize, IntoParams, Validate, ToSchema)]
+pub struct SearchWorksReq {
+    #[schema(example = 1)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub job_id: Option<u32>,
+
+    #[schema(example = 3)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub from: Option<u32>,
+
+    #[schema(example = 5)]
+    #[garde(inner(custom(range_err(Some(1u32), None))))]
+    pub to: Option<u32>,
+
+    #[schema(default = 10)]
+    #[garde(custom(range_err(Some(1u16), Some(25u16))))]
+    pub limit: u16,
+
+    #[schema(default = 0)]
+    #[garde(custom(range_err(Some(0u16), None)))]
+    pub offset: u16,
+}
+
+// ============================================================
+// Correct
+// ============================================================
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct CorrectWorkReq {
+    #[schema(example = "2026-04-01T09:00:00")]
+    #[garde(inner(custom(datetime_err)))]
+    pub real_work_bgn_at: Option<String>,
+
+    #[schema(example = "2026-04-01T18:00:00")]
+    #[garde(inner(custom(datetime_err)))]
+    pub real_work_end_at: Option<String>,
+
+    #[schema(example = "退勤打刻漏れのため修正")]
+    #[garde(custom(required_simple_err(1, 1200)))]
+    #[garde(custom(length_chars_err(0, 400)))]
+    pub reason: String,
+}
@@ -0,0 +1,101 @@
+use utoipa::ToSchema;
+use serde::Serialize;
+use crate::entities::badges;
+use crate::utils::db::{datetime_to_str, opt_datetime_to_str};
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct SearchBadgesRes {
+    pub badges: Vec<SearchBadgesResItem>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SearchBadgesResItem {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub corp_id: u32,
+    pub name: String,
+    pub short_name: String,
+    pub description: String,
+    pub valid_months: u32,
+    pub retired_at: Option<String>,
+}
+
+impl From<badges::Model> for SearchBadgesResItem {
+    fn from(m: badges::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            corp_id: m.corp_id,
+            name: m.name,
+            short_name: m.short_name,
+            description: m.description,
+            valid_months: m.valid_months,
+            retired_at: opt_datetime_to_str(m.retired_at),
+        }
+    }
+}
+
+// ============================================================
+// Get
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct GetBadgeRes {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub corp_id: u32,
+    pub name: String,
+    pub short_name: String,
+    pub description: String,
+    pub valid_months: u32,
+    pub retired_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<badges::Model> for GetBadgeRes {
+    fn from(m: badges::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            corp_id: m.corp_id,
+            name: m.name,
+            short_name: m.short_name,
+            description: m.description,
+            valid_months: m.valid_months,
+            retired_at: opt_datetime_to_str(m.retired_at),
+            created_at: datetime_to_str(m.created_at),
+            updated_at: datetime_to_str(m.updated_at),
+        }
+    }
+}
+
+// ============================================================
+// Create
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct CreateBadgeRes {
+    pub id: u32,
+}
+
+// ============================================================
+// Update
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct UpdateBadgeRes {
+    pub id: u32,
+}
+
+// ============================================================
+// Retire
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct RetireBadgeRes {
+    pub id: u32,
+}
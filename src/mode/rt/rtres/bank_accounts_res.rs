@@ -0,0 +1,104 @@
+use utoipa::ToSchema;
+use serde::Serialize;
+use crate::entities::bank_accounts;
+use crate::utils::db::datetime_to_str;
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct SearchBankAccountsRes {
+    pub bank_accounts: Vec<SearchBankAccountsResItem>,
+}
+
+/// 口座番号は下 4 桁以外をマスクし、口座名義は返さない
+#[derive(Serialize, ToSchema)]
+pub struct SearchBankAccountsResItem {
+    pub id: u32,
+    pub usr_id: u32,
+    pub bank_code: String,
+    pub bank_name: String,
+    pub branch_code: String,
+    pub branch_name: String,
+    pub account_type: u8,
+    /// マスク済み口座番号（例: ***4567）
+    pub account_number: String,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<bank_accounts::Model> for SearchBankAccountsResItem {
+    fn from(m: bank_accounts::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            usr_id: m.usr_id,
+            bank_code: m.bank_code,
+            bank_name: m.bank_name,
+            branch_code: m.branch_code,
+            branch_name: m.branch_name,
+            account_type: m.account_type,
+            account_number: format!("***{}", m.account_last4),
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            created_at: datetime_to_str(m.created_at),
+            updated_at: datetime_to_str(m.updated_at),
+        }
+    }
+}
+
+// ============================================================
+// Get
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct GetBankAccountRes {
+    pub id: u32,
+    pub usr_id: u32,
+    pub bank_code: String,
+    pub bank_name: String,
+    pub branch_code: String,
+    pub branch_name: String,
+    pub account_type: u8,
+    /// マスク済み口座番号（例: ***4567）
+    pub account_number: String,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<bank_accounts::Model> for GetBankAccountRes {
+    fn from(m: bank_accounts::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            usr_id: m.usr_id,
+            bank_code: m.bank_code,
+            bank_name: m.bank_name,
+            branch_code: m.branch_code,
+            branch_name: m.branch_name,
+            account_type: m.account_type,
+            account_number: format!("***{}", m.account_last4),
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            created_at: datetime_to_str(m.created_at),
+            updated_at: datetime_to_str(m.updated_at),
+        }
+    }
+}
+
+// ============================================================
+// Create
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct CreateBankAccountRes {
+    pub id: u32,
+}
+
+// ============================================================
+// Update
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct UpdateBankAccountRes {
+    pub id: u32,
+}
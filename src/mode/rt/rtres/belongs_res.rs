@@ -0,0 +1,83 @@
+use utoipa::ToSchema;
+use serde::Serialize;
+use crate::entities::belongs;
+use crate::utils::db::{datetime_to_str, opt_datetime_to_str};
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct SearchBelongsRes {
+    pub belongs: Vec<SearchBelongsResItem>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SearchBelongsResItem {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub corp_id: u32,
+    pub usr_id: u32,
+    pub open_at: Option<String>,
+    pub close_at: Option<String>,
+}
+
+impl From<belongs::Model> for SearchBelongsResItem {
+    fn from(m: belongs::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            corp_id: m.corp_id,
+            usr_id: m.usr_id,
+            open_at: opt_datetime_to_str(m.open_at),
+            close_at: opt_datetime_to_str(m.close_at),
+        }
+    }
+}
+
+// ============================================================
+// Get
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct GetBelongRes {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub corp_id: u32,
+    pub usr_id: u32,
+    pub open_at: Option<String>,
+    pub close_at: Option<String>,
+    pub created_at: String,
+}
+
+impl From<belongs::Model> for GetBelongRes {
+    fn from(m: belongs::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            corp_id: m.corp_id,
+            usr_id: m.usr_id,
+            open_at: opt_datetime_to_str(m.open_at),
+            close_at: opt_datetime_to_str(m.close_at),
+            created_at: datetime_to_str(m.created_at),
+        }
+    }
+}
+
+// ============================================================
+// Create
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct CreateBelongRes {
+    pub id: u32,
+}
+
+// ============================================================
+// Close
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct CloseBelongRes {
+    pub id: u32,
+}
@@ -0,0 +1,123 @@
+use utoipa::ToSchema;
+use serde::Serialize;
+use crate::utils::collusion::{Burst, Clique, ReciprocalPair, UsrRisk};
+use crate::utils::db::datetime_to_str;
+
+// ============================================================
+// Report
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct CollusionReportRes {
+    /// 分析対象の期間の開始日時
+    pub since: String,
+    /// 分析対象とした個人間の授与数
+    pub awards: u32,
+    pub pairs: Vec<CollusionReportResPair>,
+    pub cliques: Vec<CollusionReportResClique>,
+    pub bursts: Vec<CollusionReportResBurst>,
+    pub usrs: Vec<CollusionReportResUsr>,
+    /// 疑わしい授与の UsrBadgeID
+    pub flagged: Vec<u32>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct CollusionReportResPair {
+    pub a: u32,
+    pub b: u32,
+    pub a_to_b: u32,
+    pub b_to_a: u32,
+    pub points: u64,
+}
+
+impl From<ReciprocalPair> for CollusionReportResPair {
+    fn from(p: ReciprocalPair) -> Self {
+        Self {
+            a: p.a,
+            b: p.b,
+            a_to_b: p.a_to_b,
+            b_to_a: p.b_to_a,
+            points: p.points,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct CollusionReportResClique {
+    pub members: Vec<u32>,
+    pub awards: u32,
+    pub points: u64,
+}
+
+impl From<Clique> for CollusionReportResClique {
+    fn from(c: Clique) -> Self {
+        Self {
+            members: c.members,
+            awards: c.awards,
+            points: c.points,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct CollusionReportResBurst {
+    pub usr_id: u32,
+    pub count: u32,
+    pub first_at: String,
+    pub last_at: String,
+}
+
+impl From<Burst> for CollusionReportResBurst {
+    fn from(b: Burst) -> Self {
+        Self {
+            usr_id: b.usr_id,
+            count: b.count,
+            first_at: datetime_to_str(b.first_at),
+            last_at: datetime_to_str(b.last_at),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct CollusionReportResUsr {
+    pub usr_id: u32,
+    pub pairs: u32,
+    pub cliques: u32,
+    pub bursts: u32,
+    pub flagged_awards: u32,
+    pub flagged_points: u64,
+}
+
+impl From<UsrRisk> for CollusionReportResUsr {
+    fn from(r: UsrRisk) -> Self {
+        Self {
+            usr_id: r.usr_id,
+            pairs: r.pairs,
+            cliques: r.cliques,
+            bursts: r.bursts,
+            flagged_awards: r.flagged_awards,
+            flagged_points: r.flagged_points,
+        }
+    }
+}
+
+// ============================================================
+// Hold
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct HoldCollusionAwardsRes {
+    /// 分析対象の期間の開始日時
+    pub since: String,
+    /// 疑わしい授与の UsrBadgeID
+    pub flagged: Vec<u32>,
+    /// 今回保留した授与の UsrBadgeID
+    pub held: Vec<u32>,
+}
+
+// ============================================================
+// Release
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct ReleaseCollusionHoldRes {
+    pub id: u32,
+    pub held_points: u32,
+}
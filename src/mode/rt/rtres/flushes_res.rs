@@ -0,0 +1,249 @@
+use utoipa::ToSchema;
+use serde::Serialize;
+use rust_decimal::prelude::ToPrimitive;
+use crate::entities::{flushes, payouts};
+use crate::utils::db::{datetime_to_str, opt_datetime_to_str};
+use crate::utils::flush::{FlushPlan, calc_flush_fee};
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct SearchFlushesRes {
+    pub flushes: Vec<SearchFlushesResItem>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SearchFlushesResItem {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub pool_id: u32,
+    pub total: u32,
+    pub flush_fee_rate: f64,
+    /// 事務費用として控除された額（floor(total × flush_fee_rate)）
+    pub fee: u32,
+    pub points: u32,
+    pub created_at: String,
+}
+
+impl From<flushes::Model> for SearchFlushesResItem {
+    fn from(m: flushes::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            pool_id: m.pool_id,
+            total: m.total,
+            flush_fee_rate: m.flush_fee_rate.to_f64().unwrap_or(0.0),
+            fee: calc_flush_fee(m.total, m.flush_fee_rate),
+            points: m.points,
+            created_at: datetime_to_str(m.created_at),
+        }
+    }
+}
+
+// ============================================================
+// Get
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct GetFlushRes {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub pool_id: u32,
+    pub total: u32,
+    pub flush_fee_rate: f64,
+    pub fee: u32,
+    pub points: u32,
+    pub payouts: Vec<SearchPayoutsResItem>,
+    pub created_at: String,
+}
+
+impl GetFlushRes {
+    pub fn new(m: flushes::Model, payouts: Vec<payouts::Model>) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            pool_id: m.pool_id,
+            total: m.total,
+            flush_fee_rate: m.flush_fee_rate.to_f64().unwrap_or(0.0),
+            fee: calc_flush_fee(m.total, m.flush_fee_rate),
+            points: m.points,
+            payouts: payouts.into_iter().map(SearchPayoutsResItem::from).collect(),
+            created_at: datetime_to_str(m.created_at),
+        }
+    }
+}
+
+// ============================================================
+// Create
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct CreateFlushRes {
+    pub id: u32,
+    pub total: u32,
+    pub fee: u32,
+    pub distributable: u32,
+    pub points: u32,
+    /// 源泉徴収額の合計
+    pub withheld: u32,
+    /// 作成された payouts の件数
+    pub payouts: u32,
+}
+
+impl CreateFlushRes {
+    pub fn new(m: &flushes::Model, plan: &FlushPlan) -> Self {
+        Self {
+            id: m.id as u32,
+            total: plan.total,
+            fee: plan.fee,
+            distributable: plan.distributable,
+            points: plan.points,
+            withheld: plan.withheld,
+            payouts: plan.lines.len() as u32,
+        }
+    }
+}
+
+// ============================================================
+// Preview
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct PreviewFlushRes {
+    pub total: u32,
+    pub flush_fee_rate: f64,
+    pub fee: u32,
+    pub distributable: u32,
+    pub points: u32,
+    /// 比例按分の切り捨てで生じ、端数調整で配られた額
+    pub remainder: u32,
+    /// 源泉徴収額の合計
+    pub withheld: u32,
+    pub payouts: Vec<PreviewFlushResItem>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct PreviewFlushResItem {
+    pub usr_id: u32,
+    pub points: u32,
+    pub share: f64,
+    /// 分配金額（源泉徴収前の総額）
+    pub amount: u32,
+    /// 端数調整で加算された額（0 または 1）
+    pub adjust: u32,
+    /// 源泉徴収額
+    pub withheld: u32,
+    /// 差引支払額（amount - withheld）
+    pub net: u32,
+}
+
+impl From<FlushPlan> for PreviewFlushRes {
+    fn from(plan: FlushPlan) -> Self {
+        Self {
+            total: plan.total,
+            flush_fee_rate: plan.flush_fee_rate.to_f64().unwrap_or(0.0),
+            fee: plan.fee,
+            distributable: plan.distributable,
+            points: plan.points,
+            remainder: plan.remainder,
+            withheld: plan.withheld,
+            payouts: plan.lines.into_iter().map(|l| PreviewFlushResItem {
+                usr_id: l.usr_id,
+                points: l.points,
+                share: l.share.to_f64().unwrap_or(0.0),
+                amount: l.amount,
+                adjust: l.adjust,
+                withheld: l.withheld,
+                net: l.net,
+            }).collect(),
+        }
+    }
+}
+
+// ============================================================
+// Search Payouts
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct SearchPayoutsRes {
+    pub payouts: Vec<SearchPayoutsResItem>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SearchPayoutsResItem {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub pool_id: u32,
+    pub flush_id: u32,
+    pub usr_id: u32,
+    pub points: u32,
+    pub share: f64,
+    /// 分配金額（源泉徴収前の総額）
+    pub amount: u32,
+    /// 源泉徴収額
+    pub withheld: u32,
+    /// 差引支払額（amount - withheld）
+    pub net: u32,
+    /// 振込ファイルへ出力した日時（未出力は null）
+    pub exported_at: Option<String>,
+    pub created_at: String,
+}
+
+impl From<payouts::Model> for SearchPayoutsResItem {
+    fn from(m: payouts::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            pool_id: m.pool_id,
+            flush_id: m.flush_id,
+            usr_id: m.usr_id,
+            points: m.points,
+            share: m.share.to_f64().unwrap_or(0.0),
+            amount: m.amount,
+            withheld: m.withheld,
+            net: m.net,
+            exported_at: opt_datetime_to_str(m.exported_at),
+            created_at: datetime_to_str(m.created_at),
+        }
+    }
+}
+
+// ============================================================
+// Withholding Summary
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct SummarizeWithholdingRes {
+    pub year: i32,
+    /// 分配金額（源泉徴収前の総額）の合計
+    pub gross: u64,
+    /// 源泉徴収額の合計
+    pub withheld: u64,
+    /// 差引支払額の合計
+    pub net: u64,
+    pub usrs: Vec<SummarizeWithholdingResItem>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SummarizeWithholdingResItem {
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub usr_id: u32,
+    pub name: String,
+    /// 対象年の分配（payouts）の件数
+    pub payouts: u32,
+    pub gross: u64,
+    pub withheld: u64,
+    pub net: u64,
+}
+
+// ============================================================
+// Export Zengin
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct ExportFlushZenginRes {
+    /// アップロード先のキー
+    pub key: String,
+}
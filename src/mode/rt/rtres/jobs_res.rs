@@ -0,0 +1,127 @@
+use utoipa::ToSchema;
+use serde::Serialize;
+use crate::entities::jobs;
+use crate::utils::db::{datetime_to_str, opt_datetime_to_str};
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct SearchJobsRes {
+    pub jobs: Vec<SearchJobsResItem>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SearchJobsResItem {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub corp_id: u32,
+    pub name: String,
+    pub max: u32,
+    pub filled: u32,
+    pub hour_price: u32,
+    pub location: String,
+    pub max_badges: u32,
+    pub work_bgn_at: Option<String>,
+    pub work_end_at: Option<String>,
+    pub open_at: Option<String>,
+    pub close_at: Option<String>,
+}
+
+impl From<jobs::Model> for SearchJobsResItem {
+    fn from(m: jobs::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            corp_id: m.corp_id,
+            name: m.name,
+            max: m.max,
+            filled: m.filled,
+            hour_price: m.hour_price,
+            location: m.location,
+            max_badges: m.max_badges,
+            work_bgn_at: opt_datetime_to_str(m.work_bgn_at),
+            work_end_at: opt_datetime_to_str(m.work_end_at),
+            open_at: opt_datetime_to_str(m.open_at),
+            close_at: opt_datetime_to_str(m.close_at),
+        }
+    }
+}
+
+// ============================================================
+// Get
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct GetJobRes {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub corp_id: u32,
+    pub name: String,
+    pub description: String,
+    pub max: u32,
+    pub filled: u32,
+    pub hour_price: u32,
+    pub requirements: String,
+    pub benefits: String,
+    pub location: String,
+    pub phone: String,
+    pub max_badges: u32,
+    pub work_bgn_at: Option<String>,
+    pub work_end_at: Option<String>,
+    pub open_at: Option<String>,
+    pub close_at: Option<String>,
+    pub created_at: String,
+}
+
+impl From<jobs::Model> for GetJobRes {
+    fn from(m: jobs::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            corp_id: m.corp_id,
+            name: m.name,
+            description: m.description,
+            max: m.max,
+            filled: m.filled,
+            hour_price: m.hour_price,
+            requirements: m.requirements,
+            benefits: m.benefits,
+            location: m.location,
+            phone: m.phone,
+            max_badges: m.max_badges,
+            work_bgn_at: opt_datetime_to_str(m.work_bgn_at),
+            work_end_at: opt_datetime_to_str(m.work_end_at),
+            open_at: opt_datetime_to_str(m.open_at),
+            close_at: opt_datetime_to_str(m.close_at),
+            created_at: datetime_to_str(m.created_at),
+        }
+    }
+}
+
+// ============================================================
+// Create
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct CreateJobRes {
+    pub id: u32,
+}
+
+// ============================================================
+// Update
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct UpdateJobRes {
+    pub id: u32,
+}
+
+// ============================================================
+// Delete
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct DeleteJobRes {
+    pub id: u32,
+}
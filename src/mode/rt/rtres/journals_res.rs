@@ -0,0 +1,63 @@
+use utoipa::ToSchema;
+use serde::Serialize;
+use crate::utils::db::datetime_to_str;
+use crate::utils::journal::JournalEntry;
+
+// ============================================================
+// Export
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct ExportJournalRes {
+    pub vdr_id: u32,
+    pub bgn_date: String,
+    pub end_date: String,
+    /// 借方合計（必ず貸方合計と一致する）
+    pub debit: u64,
+    /// 貸方合計
+    pub credit: u64,
+    pub entries: Vec<ExportJournalResEntry>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ExportJournalResEntry {
+    /// 期間内の通番（1 始まり）
+    pub entry_no: u32,
+    pub date: String,
+    /// 仕訳の元となったテーブル（payments / flushes / payouts / withdrawals）
+    pub source: String,
+    pub source_id: u32,
+    pub description: String,
+    pub lines: Vec<ExportJournalResLine>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ExportJournalResLine {
+    /// 勘定科目
+    pub account: String,
+    pub debit: u64,
+    pub credit: u64,
+}
+
+impl ExportJournalRes {
+    pub fn new(vdr_id: u32, bgn_date: String, end_date: String, entries: Vec<JournalEntry>) -> Self {
+        Self {
+            vdr_id,
+            bgn_date,
+            end_date,
+            debit: entries.iter().map(|e| e.debit_total()).sum(),
+            credit: entries.iter().map(|e| e.credit_total()).sum(),
+            entries: entries.into_iter().enumerate().map(|(i, e)| ExportJournalResEntry {
+                entry_no: i as u32 + 1,
+                date: datetime_to_str(e.date),
+                source: e.source.to_string(),
+                source_id: e.source_id,
+                description: e.description,
+                lines: e.lines.into_iter().map(|l| ExportJournalResLine {
+                    account: l.account.to_string(),
+                    debit: l.debit,
+                    credit: l.credit,
+                }).collect(),
+            }).collect(),
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use utoipa::ToSchema;
+use serde::Serialize;
+use rust_decimal::prelude::ToPrimitive;
+use crate::entities::{matches, match_statuses};
+use crate::utils::db::datetime_to_str;
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct SearchMatchesRes {
+    pub matches: Vec<SearchMatchesResItem>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SearchMatchesResItem {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub job_id: u32,
+    pub from: u32,
+    pub to: u32,
+    pub status: u8,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<matches::Model> for SearchMatchesResItem {
+    fn from(m: matches::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            job_id: m.job_id,
+            from: m.from,
+            to: m.to,
+            status: m.status,
+            created_at: datetime_to_str(m.created_at),
+            updated_at: datetime_to_str(m.updated_at),
+        }
+    }
+}
+
+// ============================================================
+// Get
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct GetMatchRes {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub job_id: u32,
+    pub from: u32,
+    pub to: u32,
+    pub status: u8,
+    pub is_tmp: bool,
+    pub priority_score: f64,
+    pub badge_count: u32,
+    pub match_reason: u8,
+    pub histories: Vec<GetMatchResHistory>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct GetMatchResHistory {
+    pub status: u8,
+    pub is_tmp: bool,
+    pub created_at: String,
+}
+
+impl From<match_statuses::Model> for GetMatchResHistory {
+    fn from(m: match_statuses::Model) -> Self {
+        Self {
+            status: m.status,
+            is_tmp: m.is_tmp != 0,
+            created_at: datetime_to_str(m.created_at),
+        }
+    }
+}
+
+impl GetMatchRes {
+    pub fn new(m: matches::Model, histories: Vec<match_statuses::Model>) -> Self {
+        let is_tmp = histories.last().map(|h| h.is_tmp != 0).unwrap_or(false);
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            job_id: m.job_id,
+            from: m.from,
+            to: m.to,
+            status: m.status,
+            is_tmp,
+            priority_score: m.priority_score.to_f64().unwrap_or(0.0),
+            badge_count: m.badge_count,
+            match_reason: m.match_reason,
+            histories: histories.into_iter().map(GetMatchResHistory::from).collect(),
+            created_at: datetime_to_str(m.created_at),
+            updated_at: datetime_to_str(m.updated_at),
+        }
+    }
+}
+
+// ============================================================
+// Create
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct CreateMatchRes {
+    pub id: u32,
+}
+
+// ============================================================
+// Accept
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct AcceptMatchRes {
+    pub id: u32,
+}
+
+// ============================================================
+// Decline
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct DeclineMatchRes {
+    pub id: u32,
+}
+
+// ============================================================
+// Confirm
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct ConfirmMatchRes {
+    pub id: u32,
+}
+
+// ============================================================
+// Update Status
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct UpdateMatchStatusRes {
+    pub id: u32,
+}
+
+// ============================================================
+// Candidates
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct SearchMatchCandidatesRes {
+    pub candidates: Vec<SearchMatchCandidatesResItem>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SearchMatchCandidatesResItem {
+    pub usr_id: u32,
+    pub name: String,
+    pub badge_count: u32,
+    pub priority_score: f64,
+    pub match_reason: u8,
+}
@@ -1,4 +1,19 @@
 pub mod errs_res;
 pub mod bds_res;
 pub mod usrs_res;
-pub mod cryptos_res;
\ No newline at end of file
+pub mod cryptos_res;
+pub mod jobs_res;
+pub mod matches_res;
+pub mod works_res;
+pub mod belongs_res;
+pub mod badges_res;
+pub mod usr_badges_res;
+pub mod points_res;
+pub mod payments_res;
+pub mod flushes_res;
+pub mod bank_accounts_res;
+pub mod withdrawals_res;
+pub mod journals_res;
+pub mod pools_res;
+pub mod point_adjustments_res;
+pub mod collusion_res;
\ No newline at end of file
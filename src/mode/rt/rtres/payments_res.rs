@@ -0,0 +1,112 @@
+use utoipa::ToSchema;
+use serde::Serialize;
+use crate::entities::payments;
+use crate::utils::db::datetime_to_str;
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct SearchPaymentsRes {
+    pub payments: Vec<SearchPaymentsResItem>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SearchPaymentsResItem {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub corp_id: u32,
+    pub r#type: u8,
+    pub amount: u32,
+    pub fee: u32,
+    pub net: u32,
+    pub note: String,
+    /// 取り消した元の入金 ID（返金の場合のみ）
+    pub reversal_of: Option<u32>,
+    pub created_at: String,
+}
+
+impl From<payments::Model> for SearchPaymentsResItem {
+    fn from(m: payments::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            corp_id: m.corp_id,
+            r#type: m.r#type,
+            amount: m.amount,
+            fee: m.fee,
+            net: m.net,
+            note: m.note,
+            reversal_of: m.reversal_of,
+            created_at: datetime_to_str(m.created_at),
+        }
+    }
+}
+
+// ============================================================
+// Get
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct GetPaymentRes {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub corp_id: u32,
+    pub r#type: u8,
+    pub amount: u32,
+    pub fee: u32,
+    pub net: u32,
+    pub note: String,
+    /// 取り消した元の入金 ID（返金の場合のみ）
+    pub reversal_of: Option<u32>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<payments::Model> for GetPaymentRes {
+    fn from(m: payments::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            corp_id: m.corp_id,
+            r#type: m.r#type,
+            amount: m.amount,
+            fee: m.fee,
+            net: m.net,
+            note: m.note,
+            reversal_of: m.reversal_of,
+            created_at: datetime_to_str(m.created_at),
+            updated_at: datetime_to_str(m.updated_at),
+        }
+    }
+}
+
+// ============================================================
+// Create
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct CreatePaymentRes {
+    pub id: u32,
+    pub fee: u32,
+    pub net: u32,
+}
+
+// ============================================================
+// Refund
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct RefundPaymentRes {
+    /// 作成された返金（取消）の入金 ID
+    pub id: u32,
+    /// 取り消した元の入金 ID
+    pub reversal_of: u32,
+    pub amount: u32,
+    pub fee: u32,
+    /// プールから差し引かれた額
+    pub net: u32,
+    /// 返金後のプール残高
+    pub remain: u32,
+}
@@ -0,0 +1,146 @@
+use utoipa::ToSchema;
+use serde::Serialize;
+use crate::entities::point_adjustments;
+use crate::utils::db::{datetime_to_str, opt_datetime_to_str};
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct SearchPointAdjustmentsRes {
+    pub point_adjustments: Vec<SearchPointAdjustmentsResItem>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SearchPointAdjustmentsResItem {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub usr_id: u32,
+    /// 調整量（負の値は減算）
+    pub delta: i32,
+    /// 承認時点の調整前後の保有ポイント（承認済み以外は 0）
+    pub bef_points: u32,
+    pub aft_points: u32,
+    /// 1: 誤ったバッジ付与, 2: 不正, 3: 訂正, 4: その他
+    pub reason: u8,
+    pub note: String,
+    /// 調整を申請した UsrID（VDR または スタッフ）
+    pub actor_id: u32,
+    /// 調整を申請したスタッフの UsrID（スタッフ token でない場合は 0）
+    pub staff_id: u32,
+    /// 1: 承認待ち, 2: 承認済み, 3: 却下
+    pub status: u8,
+    /// 承認・却下を行った UsrID（VDR または スタッフ、未審査は 0）
+    pub reviewer_id: u32,
+    /// 承認・却下を行ったスタッフの UsrID（スタッフ token でない場合は 0）
+    pub reviewer_staff_id: u32,
+    pub reviewed_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<point_adjustments::Model> for SearchPointAdjustmentsResItem {
+    fn from(m: point_adjustments::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            usr_id: m.usr_id,
+            delta: m.delta,
+            bef_points: m.bef_points,
+            aft_points: m.aft_points,
+            reason: m.reason,
+            note: m.note,
+            actor_id: m.actor_id,
+            staff_id: m.staff_id,
+            status: m.status,
+            reviewer_id: m.reviewer_id,
+            reviewer_staff_id: m.reviewer_staff_id,
+            reviewed_at: opt_datetime_to_str(m.reviewed_at),
+            created_at: datetime_to_str(m.created_at),
+            updated_at: datetime_to_str(m.updated_at),
+        }
+    }
+}
+
+// ============================================================
+// Get
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct GetPointAdjustmentRes {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub usr_id: u32,
+    /// 調整量（負の値は減算）
+    pub delta: i32,
+    /// 承認時点の調整前後の保有ポイント（承認済み以外は 0）
+    pub bef_points: u32,
+    pub aft_points: u32,
+    /// 1: 誤ったバッジ付与, 2: 不正, 3: 訂正, 4: その他
+    pub reason: u8,
+    pub note: String,
+    /// 調整を申請した UsrID（VDR または スタッフ）
+    pub actor_id: u32,
+    /// 調整を申請したスタッフの UsrID（スタッフ token でない場合は 0）
+    pub staff_id: u32,
+    /// 1: 承認待ち, 2: 承認済み, 3: 却下
+    pub status: u8,
+    /// 承認・却下を行った UsrID（VDR または スタッフ、未審査は 0）
+    pub reviewer_id: u32,
+    /// 承認・却下を行ったスタッフの UsrID（スタッフ token でない場合は 0）
+    pub reviewer_staff_id: u32,
+    pub reviewed_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<point_adjustments::Model> for GetPointAdjustmentRes {
+    fn from(m: point_adjustments::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            usr_id: m.usr_id,
+            delta: m.delta,
+            bef_points: m.bef_points,
+            aft_points: m.aft_points,
+            reason: m.reason,
+            note: m.note,
+            actor_id: m.actor_id,
+            staff_id: m.staff_id,
+            status: m.status,
+            reviewer_id: m.reviewer_id,
+            reviewer_staff_id: m.reviewer_staff_id,
+            reviewed_at: opt_datetime_to_str(m.reviewed_at),
+            created_at: datetime_to_str(m.created_at),
+            updated_at: datetime_to_str(m.updated_at),
+        }
+    }
+}
+
+// ============================================================
+// Create
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct CreatePointAdjustmentRes {
+    pub id: u32,
+    pub usr_id: u32,
+    /// 1: 承認待ち
+    pub status: u8,
+}
+
+// ============================================================
+// Approve / Reject
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct ReviewPointAdjustmentRes {
+    pub id: u32,
+    pub usr_id: u32,
+    /// 2: 承認済み, 3: 却下
+    pub status: u8,
+    /// 承認時点の調整前後の保有ポイント（却下は 0）
+    pub bef_points: u32,
+    pub aft_points: u32,
+}
@@ -0,0 +1,135 @@
+use utoipa::ToSchema;
+use serde::Serialize;
+use crate::entities::points;
+use crate::utils::db::datetime_to_str;
+use crate::utils::point_expiry::PointLot;
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct SearchPointsRes {
+    pub points: Vec<SearchPointsResItem>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SearchPointsResItem {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub badge_id: u32,
+    pub usr_badge_id: u32,
+    pub corp_id: u32,
+    pub from: u32,
+    pub to: u32,
+    pub point: u32,
+    pub extra: u32,
+    /// 1: 授与, 2: 失効, 3: 取り消し
+    pub kind: u8,
+    /// 受領者の保有ポイントに対する増減（授与は point + extra、失効・取り消しは負の値）
+    pub delta: i32,
+    pub created_at: String,
+}
+
+impl From<points::Model> for SearchPointsResItem {
+    fn from(m: points::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            badge_id: m.badge_id,
+            usr_badge_id: m.usr_badge_id,
+            corp_id: m.corp_id,
+            from: m.from,
+            to: m.to,
+            point: m.point,
+            extra: m.extra,
+            kind: m.kind,
+            delta: m.delta,
+            created_at: datetime_to_str(m.created_at),
+        }
+    }
+}
+
+// ============================================================
+// Get
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct GetPointRes {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub badge_id: u32,
+    pub usr_badge_id: u32,
+    pub corp_id: u32,
+    pub from: u32,
+    pub to: u32,
+    pub point: u32,
+    pub extra: u32,
+    /// 1: 授与, 2: 失効, 3: 取り消し
+    pub kind: u8,
+    /// 受領者の保有ポイントに対する増減（授与は point + extra、失効・取り消しは負の値）
+    pub delta: i32,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<points::Model> for GetPointRes {
+    fn from(m: points::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            badge_id: m.badge_id,
+            usr_badge_id: m.usr_badge_id,
+            corp_id: m.corp_id,
+            from: m.from,
+            to: m.to,
+            point: m.point,
+            extra: m.extra,
+            kind: m.kind,
+            delta: m.delta,
+            created_at: datetime_to_str(m.created_at),
+            updated_at: datetime_to_str(m.updated_at),
+        }
+    }
+}
+
+
+// ============================================================
+// Expiring
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct ExpiringPointsRes {
+    pub usr_id: u32,
+    /// 現在の保有ポイント
+    pub points: u32,
+    /// VDR のポイント失効ポリシー（0 は失効しない）
+    pub point_expire_months: u32,
+    /// 期間内に失効するポイントの合計
+    pub expiring: u32,
+    /// 期間内に失効するポイントの内訳（失効日時の昇順）
+    pub lots: Vec<ExpiringPointsResLot>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ExpiringPointsResLot {
+    /// 付与日時
+    pub granted_at: String,
+    /// 失効日時
+    pub expires_at: String,
+    pub points: u32,
+    /// 付与元のバッジ授与ID（手動調整は 0）
+    pub usr_badge_id: u32,
+}
+
+impl From<PointLot> for ExpiringPointsResLot {
+    fn from(l: PointLot) -> Self {
+        Self {
+            granted_at: datetime_to_str(l.granted_at),
+            expires_at: datetime_to_str(l.expires_at),
+            points: l.points,
+            usr_badge_id: l.usr_badge_id,
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use utoipa::ToSchema;
+use serde::Serialize;
+use crate::mode::rt::rtutils::pool_ledger_for_rt::{PoolCheck, PoolMove};
+use crate::utils::db::datetime_to_str;
+
+// ============================================================
+// Ledger
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct SearchPoolLedgerRes {
+    pub vdr_id: u32,
+    /// 期間開始時点の残高
+    pub opening_balance: i64,
+    /// 期間終了時点の残高
+    pub closing_balance: i64,
+    pub lines: Vec<SearchPoolLedgerResItem>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SearchPoolLedgerResItem {
+    pub date: String,
+    /// 1: 入金, 2: 返金, 3: 分配
+    pub kind: u8,
+    /// kind が 1, 2 の場合は payments.id、3 の場合は flushes.id
+    pub source_id: u32,
+    pub amount_in: u32,
+    pub amount_out: u32,
+    /// この増減を反映した後の残高
+    pub balance: i64,
+}
+
+impl From<PoolMove> for SearchPoolLedgerResItem {
+    fn from(m: PoolMove) -> Self {
+        Self {
+            date: datetime_to_str(m.at),
+            kind: m.kind as u8,
+            source_id: m.source_id,
+            amount_in: m.amount_in,
+            amount_out: m.amount_out,
+            balance: m.balance,
+        }
+    }
+}
+
+// ============================================================
+// Check
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct CheckPoolRes {
+    pub vdr_id: u32,
+    /// 保存されている pools の値
+    pub remain: i64,
+    pub total_in: i64,
+    pub total_out: i64,
+    /// 入金・返金・分配から再計算した値
+    pub computed_remain: i64,
+    pub computed_total_in: i64,
+    pub computed_total_out: i64,
+    /// 全て一致していれば true
+    pub is_consistent: bool,
+    /// 一致しない項目の説明
+    pub mismatches: Vec<String>,
+}
+
+impl CheckPoolRes {
+    pub fn new(vdr_id: u32, check: PoolCheck) -> Self {
+        Self {
+            vdr_id,
+            remain: check.stored.remain,
+            total_in: check.stored.total_in,
+            total_out: check.stored.total_out,
+            computed_remain: check.computed.remain,
+            computed_total_in: check.computed.total_in,
+            computed_total_out: check.computed.total_out,
+            is_consistent: check.mismatches.is_empty(),
+            mismatches: check.mismatches,
+        }
+    }
+}
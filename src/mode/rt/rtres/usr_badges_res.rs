@@ -0,0 +1,132 @@
+use utoipa::ToSchema;
+use serde::Serialize;
+use crate::entities::usr_badges;
+use crate::utils::db::{datetime_to_str, opt_datetime_to_str};
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct SearchUsrBadgesRes {
+    pub usr_badges: Vec<SearchUsrBadgesResItem>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SearchUsrBadgesResItem {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub badge_id: u32,
+    pub job_id: u32,
+    pub corp_id: u32,
+    pub from: u32,
+    pub to: u32,
+    pub title: String,
+    pub r#type: u8,
+    pub expires_at: Option<String>,
+    pub revoked_at: Option<String>,
+    pub held_at: Option<String>,
+    pub created_at: String,
+}
+
+impl From<usr_badges::Model> for SearchUsrBadgesResItem {
+    fn from(m: usr_badges::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            badge_id: m.badge_id,
+            job_id: m.job_id,
+            corp_id: m.corp_id,
+            from: m.from,
+            to: m.to,
+            title: m.title,
+            r#type: m.r#type,
+            expires_at: opt_datetime_to_str(m.expires_at),
+            revoked_at: opt_datetime_to_str(m.revoked_at),
+            held_at: opt_datetime_to_str(m.held_at),
+            created_at: datetime_to_str(m.created_at),
+        }
+    }
+}
+
+// ============================================================
+// Get
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct GetUsrBadgeRes {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub badge_id: u32,
+    pub job_id: u32,
+    pub corp_id: u32,
+    pub from: u32,
+    pub to: u32,
+    pub title: String,
+    pub message: String,
+    pub r#type: u8,
+    pub expires_at: Option<String>,
+    pub revoked_at: Option<String>,
+    pub revoke_reason: u8,
+    pub revoke_note: String,
+    pub revoked_by: u32,
+    pub revoked_staff_id: u32,
+    pub revoked_points: u32,
+    pub held_at: Option<String>,
+    pub held_by: u32,
+    pub held_staff_id: u32,
+    pub held_points: u32,
+    pub flush_id: u32,
+    pub flushed_at: Option<String>,
+    pub created_at: String,
+}
+
+impl From<usr_badges::Model> for GetUsrBadgeRes {
+    fn from(m: usr_badges::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            badge_id: m.badge_id,
+            job_id: m.job_id,
+            corp_id: m.corp_id,
+            from: m.from,
+            to: m.to,
+            title: m.title,
+            message: m.message,
+            r#type: m.r#type,
+            expires_at: opt_datetime_to_str(m.expires_at),
+            revoked_at: opt_datetime_to_str(m.revoked_at),
+            revoke_reason: m.revoke_reason,
+            revoke_note: m.revoke_note,
+            revoked_by: m.revoked_by,
+            revoked_staff_id: m.revoked_staff_id,
+            revoked_points: m.revoked_points,
+            held_at: opt_datetime_to_str(m.held_at),
+            held_by: m.held_by,
+            held_staff_id: m.held_staff_id,
+            held_points: m.held_points,
+            flush_id: m.flush_id,
+            flushed_at: opt_datetime_to_str(m.flushed_at),
+            created_at: datetime_to_str(m.created_at),
+        }
+    }
+}
+
+// ============================================================
+// Award
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct AwardUsrBadgeRes {
+    pub id: u32,
+}
+
+// ============================================================
+// Revoke
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct RevokeUsrBadgeRes {
+    pub id: u32,
+    pub revoked_points: u32,
+}
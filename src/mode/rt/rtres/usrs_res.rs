@@ -33,6 +33,11 @@ pub struct SearchUsrsResItem {
     pub flush_days: u32,
     pub rate: f64,
     pub flush_fee_rate: f64,
+    pub withholding_threshold: u32,
+    pub withholding_rate: f64,
+    pub withholding_reduced_rate: f64,
+    pub withholding_reduced_limit: u32,
+    pub point_expire_months: u32,
 }
 
 impl From<usrs::Model> for SearchUsrsResItem {
@@ -52,6 +57,11 @@ impl From<usrs::Model> for SearchUsrsResItem {
             flush_days: m.flush_days,
             rate: m.rate.to_f64().unwrap_or(0.0),
             flush_fee_rate: m.flush_fee_rate.to_f64().unwrap_or(0.0),
+            withholding_threshold: m.withholding_threshold,
+            withholding_rate: m.withholding_rate.to_f64().unwrap_or(0.0),
+            withholding_reduced_rate: m.withholding_reduced_rate.to_f64().unwrap_or(0.0),
+            withholding_reduced_limit: m.withholding_reduced_limit,
+            point_expire_months: m.point_expire_months,
         }
     }
 }
@@ -75,6 +85,11 @@ pub struct GetUsrRes {
     pub flush_days: u32,
     pub rate: f64,
     pub flush_fee_rate: f64,
+    pub withholding_threshold: u32,
+    pub withholding_rate: f64,
+    pub withholding_reduced_rate: f64,
+    pub withholding_reduced_limit: u32,
+    pub point_expire_months: u32,
 }
 
 impl From<usrs::Model> for GetUsrRes {
@@ -94,6 +109,11 @@ impl From<usrs::Model> for GetUsrRes {
             flush_days: m.flush_days,
             rate: m.rate.to_f64().unwrap_or(0.0),
             flush_fee_rate: m.flush_fee_rate.to_f64().unwrap_or(0.0),
+            withholding_threshold: m.withholding_threshold,
+            withholding_rate: m.withholding_rate.to_f64().unwrap_or(0.0),
+            withholding_reduced_rate: m.withholding_reduced_rate.to_f64().unwrap_or(0.0),
+            withholding_reduced_limit: m.withholding_reduced_limit,
+            point_expire_months: m.point_expire_months,
         }
     }
 }
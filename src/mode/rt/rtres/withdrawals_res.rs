@@ -0,0 +1,119 @@
+use utoipa::ToSchema;
+use serde::Serialize;
+use crate::entities::{withdrawals, withdrawal_statuses};
+use crate::utils::db::{datetime_to_str, opt_datetime_to_str};
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct SearchWithdrawalsRes {
+    pub withdrawals: Vec<SearchWithdrawalsResItem>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SearchWithdrawalsResItem {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub usr_id: u32,
+    pub amount: u32,
+    /// 1: 申請, 2: 承認, 3: 却下, 4: 支払済
+    pub status: u8,
+    pub note: String,
+    pub reviewed_at: Option<String>,
+    pub paid_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<withdrawals::Model> for SearchWithdrawalsResItem {
+    fn from(m: withdrawals::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            usr_id: m.usr_id,
+            amount: m.amount,
+            status: m.status,
+            note: m.note,
+            reviewed_at: opt_datetime_to_str(m.reviewed_at),
+            paid_at: opt_datetime_to_str(m.paid_at),
+            created_at: datetime_to_str(m.created_at),
+            updated_at: datetime_to_str(m.updated_at),
+        }
+    }
+}
+
+// ============================================================
+// Get
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct GetWithdrawalRes {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub usr_id: u32,
+    pub amount: u32,
+    pub status: u8,
+    pub note: String,
+    pub reviewed_at: Option<String>,
+    pub paid_at: Option<String>,
+    /// ステータス遷移履歴（古い順）
+    pub statuses: Vec<GetWithdrawalResStatus>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct GetWithdrawalResStatus {
+    pub id: u32,
+    pub status: u8,
+    pub amount: u32,
+    /// 遷移を行った UsrID（個人 または VDR）
+    pub actor_id: u32,
+    pub note: String,
+    pub created_at: String,
+}
+
+impl GetWithdrawalRes {
+    pub fn new(m: withdrawals::Model, statuses: Vec<withdrawal_statuses::Model>) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            usr_id: m.usr_id,
+            amount: m.amount,
+            status: m.status,
+            note: m.note,
+            reviewed_at: opt_datetime_to_str(m.reviewed_at),
+            paid_at: opt_datetime_to_str(m.paid_at),
+            statuses: statuses.into_iter().map(|s| GetWithdrawalResStatus {
+                id: s.id as u32,
+                status: s.status,
+                amount: s.amount,
+                actor_id: s.actor_id,
+                note: s.note,
+                created_at: datetime_to_str(s.created_at),
+            }).collect(),
+            created_at: datetime_to_str(m.created_at),
+            updated_at: datetime_to_str(m.updated_at),
+        }
+    }
+}
+
+// ============================================================
+// Create
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct CreateWithdrawalRes {
+    pub id: u32,
+}
+
+// ============================================================
+// Review (Approve / Reject / Pay)
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct ReviewWithdrawalRes {
+    pub id: u32,
+}
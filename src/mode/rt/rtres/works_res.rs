@@ -0,0 +1,136 @@
+use utoipa::ToSchema;
+use serde::Serialize;
+use crate::entities::{works, work_corrections};
+use crate::utils::db::{datetime_to_str, opt_datetime_to_str};
+
+// ============================================================
+// Search
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct SearchWorksRes {
+    pub works: Vec<SearchWorksResItem>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SearchWorksResItem {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub job_id: u32,
+    pub match_id: u32,
+    pub from: u32,
+    pub to: u32,
+    pub work_bgn_at: Option<String>,
+    pub work_end_at: Option<String>,
+    pub real_work_bgn_at: Option<String>,
+    pub real_work_end_at: Option<String>,
+}
+
+impl From<works::Model> for SearchWorksResItem {
+    fn from(m: works::Model) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            job_id: m.job_id,
+            match_id: m.match_id,
+            from: m.from,
+            to: m.to,
+            work_bgn_at: opt_datetime_to_str(m.work_bgn_at),
+            work_end_at: opt_datetime_to_str(m.work_end_at),
+            real_work_bgn_at: opt_datetime_to_str(m.real_work_bgn_at),
+            real_work_end_at: opt_datetime_to_str(m.real_work_end_at),
+        }
+    }
+}
+
+// ============================================================
+// Get
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct GetWorkRes {
+    pub id: u32,
+    pub apx_id: u32,
+    pub vdr_id: u32,
+    pub job_id: u32,
+    pub match_id: u32,
+    pub from: u32,
+    pub to: u32,
+    pub work_bgn_at: Option<String>,
+    pub work_end_at: Option<String>,
+    pub real_work_bgn_at: Option<String>,
+    pub real_work_end_at: Option<String>,
+    pub corrections: Vec<GetWorkResCorrection>,
+    pub created_at: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct GetWorkResCorrection {
+    pub usr_id: u32,
+    pub staff_id: u32,
+    pub bef_real_work_bgn_at: Option<String>,
+    pub bef_real_work_end_at: Option<String>,
+    pub aft_real_work_bgn_at: Option<String>,
+    pub aft_real_work_end_at: Option<String>,
+    pub reason: String,
+    pub created_at: String,
+}
+
+impl From<work_corrections::Model> for GetWorkResCorrection {
+    fn from(m: work_corrections::Model) -> Self {
+        Self {
+            usr_id: m.usr_id,
+            staff_id: m.staff_id,
+            bef_real_work_bgn_at: opt_datetime_to_str(m.bef_real_work_bgn_at),
+            bef_real_work_end_at: opt_datetime_to_str(m.bef_real_work_end_at),
+            aft_real_work_bgn_at: opt_datetime_to_str(m.aft_real_work_bgn_at),
+            aft_real_work_end_at: opt_datetime_to_str(m.aft_real_work_end_at),
+            reason: m.reason,
+            created_at: datetime_to_str(m.created_at),
+        }
+    }
+}
+
+impl GetWorkRes {
+    pub fn new(m: works::Model, corrections: Vec<work_corrections::Model>) -> Self {
+        Self {
+            id: m.id as u32,
+            apx_id: m.apx_id,
+            vdr_id: m.vdr_id,
+            job_id: m.job_id,
+            match_id: m.match_id,
+            from: m.from,
+            to: m.to,
+            work_bgn_at: opt_datetime_to_str(m.work_bgn_at),
+            work_end_at: opt_datetime_to_str(m.work_end_at),
+            real_work_bgn_at: opt_datetime_to_str(m.real_work_bgn_at),
+            real_work_end_at: opt_datetime_to_str(m.real_work_end_at),
+            corrections: corrections.into_iter().map(GetWorkResCorrection::from).collect(),
+            created_at: datetime_to_str(m.created_at),
+        }
+    }
+}
+
+// ============================================================
+// Clock In
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct ClockInWorkRes {
+    pub id: u32,
+}
+
+// ============================================================
+// Clock Out
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct ClockOutWorkRes {
+    pub id: u32,
+}
+
+// ============================================================
+// Correct
+// ============================================================
+#[derive(Serialize, ToSchema)]
+pub struct CorrectWorkRes {
+    pub id: u32,
+}
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+use axum::http::StatusCode;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use crate::entities::bank_accounts;
+use crate::mode::rt::{rterr::rterr, rtres::errs_res::ApiError};
+use crate::utils::crypto::decrypt;
+use crate::utils::zengin::ZenginAccount;
+
+/// 振込先口座を復号して UsrID ごとに取得する
+/// 口座番号と口座名義の復号はこの関数でのみ行い、振込ファイルの出力にのみ用いる
+pub async fn load_zengin_accounts_for_rt<C: ConnectionTrait>(
+    conn: &C,
+    crypto_key: &str,
+    apx_id: u32,
+    vdr_id: u32,
+    usr_ids: &[u32],
+) -> Result<HashMap<u32, ZenginAccount>, ApiError> {
+    if usr_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let models = bank_accounts::Entity::find()
+        .filter(bank_accounts::Column::ApxId.eq(apx_id))
+        .filter(bank_accounts::Column::VdrId.eq(vdr_id))
+        .filter(bank_accounts::Column::UsrId.is_in(usr_ids.iter().copied()))
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch bank accounts error: {}", e)))?;
+    let mut accounts = HashMap::with_capacity(models.len());
+    for m in models {
+        let account_number = decrypt(&m.account_number, crypto_key)
+            .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_UNEXPECTED, format!("Decrypt account_number error (usr: {}): {}", m.usr_id, e)))?;
+        let holder = decrypt(&m.holder, crypto_key)
+            .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_UNEXPECTED, format!("Decrypt holder error (usr: {}): {}", m.usr_id, e)))?;
+        accounts.insert(m.usr_id, ZenginAccount {
+            bank_code: m.bank_code,
+            bank_name: m.bank_name,
+            branch_code: m.branch_code,
+            branch_name: m.branch_name,
+            account_type: m.account_type,
+            account_number,
+            holder,
+        });
+    }
+    Ok(accounts)
+}
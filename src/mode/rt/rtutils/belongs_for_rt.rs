@@ -0,0 +1,53 @@
+use axum::http::StatusCode;
+use chrono::NaiveDateTime;
+use rust_decimal::Decimal;
+use sea_orm::{ConnectionTrait, EntityTrait, QueryFilter, ColumnTrait, Condition, PaginatorTrait};
+use crate::entities::belongs;
+use crate::mode::rt::{rterr::rterr, rtres::errs_res::ApiError};
+use crate::mode::rt::rtutils::usrs_for_rt::find_vdr_for_rt;
+
+/// 指定日時に有効な所属であることを表す条件
+/// 所属期間は [open_at, close_at) とし、NULL は無期限として扱う
+pub fn belong_active_at(at: NaiveDateTime) -> Condition {
+    Condition::all()
+        .add(Condition::any().add(belongs::Column::OpenAt.is_null()).add(belongs::Column::OpenAt.lte(at)))
+        .add(Condition::any().add(belongs::Column::CloseAt.is_null()).add(belongs::Column::CloseAt.gt(at)))
+}
+
+/// 個人が指定日時に法人へ所属しているかを判定する
+pub async fn is_belonging_at_for_rt<C: ConnectionTrait>(
+    conn: &C,
+    apx_id: u32,
+    vdr_id: u32,
+    corp_id: u32,
+    usr_id: u32,
+    at: NaiveDateTime,
+) -> Result<bool, ApiError> {
+    let count = belongs::Entity::find()
+        .filter(belongs::Column::ApxId.eq(apx_id))
+        .filter(belongs::Column::VdrId.eq(vdr_id))
+        .filter(belongs::Column::CorpId.eq(corp_id))
+        .filter(belongs::Column::UsrId.eq(usr_id))
+        .filter(belong_active_at(at))
+        .count(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Count belongs error: {}", e)))?;
+    Ok(count > 0)
+}
+
+/// 指定日時に適用される所属割増率を返す
+/// 個人が法人へ所属していれば VDR の belong_rate を、所属していなければ 0 を返す
+pub async fn belong_rate_at_for_rt<C: ConnectionTrait>(
+    conn: &C,
+    apx_id: u32,
+    vdr_id: u32,
+    corp_id: u32,
+    usr_id: u32,
+    at: NaiveDateTime,
+) -> Result<Decimal, ApiError> {
+    if !is_belonging_at_for_rt(conn, apx_id, vdr_id, corp_id, usr_id, at).await? {
+        return Ok(Decimal::ZERO);
+    }
+    let vdr = find_vdr_for_rt(conn, apx_id, vdr_id).await?;
+    Ok(vdr.belong_rate)
+}
@@ -0,0 +1,10 @@
+use rust_decimal::Decimal;
+
+/// rt モードの業務設定
+/// 環境変数から収集し、Extension として各ハンドラへ渡す
+pub struct RtConfig {
+    /// 出勤打刻を許容する、就業開始日時予定の前後の幅（分）
+    pub clock_tolerance_min: u32,
+    /// 入金（payments）の金額から運営費として控除する割合（0〜1）
+    pub payment_fee_rate: Decimal,
+}
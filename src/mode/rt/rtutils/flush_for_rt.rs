@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+use axum::http::StatusCode;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use sea_orm::{ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect, ColumnTrait, ActiveModelTrait, IntoActiveModel, Set, TransactionTrait};
+use sea_orm::sea_query::Expr;
+use crate::entities::{flushes, payouts, pools, usr_badges, usrs};
+use crate::enums::usrtype::UsrType;
+use crate::mode::rt::{rterr::rterr, rtres::errs_res::ApiError};
+use crate::mode::rt::rtutils::usrs_for_rt::find_vdr_for_rt;
+use crate::utils::flush::{FlushPlan, Holder, plan_flush};
+use crate::utils::withholding::WithholdingRule;
+
+/// VDR の現金プールを取得する（lock が true の場合は行ロックする）
+pub async fn find_pool_for_rt<C: ConnectionTrait>(
+    conn: &C,
+    apx_id: u32,
+    vdr_id: u32,
+    lock: bool,
+) -> Result<pools::Model, ApiError> {
+    let mut query = pools::Entity::find()
+        .filter(pools::Column::ApxId.eq(apx_id))
+        .filter(pools::Column::VdrId.eq(vdr_id));
+    if lock {
+        query = query.lock_exclusive();
+    }
+    query
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch pool error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Pool not found."))
+}
+
+/// 個人ごとの保留中のポイント（保留中かつ取り消されていない授与の held_points の合計）を取得する
+pub async fn load_held_points_for_rt<C: ConnectionTrait>(
+    conn: &C,
+    apx_id: u32,
+    vdr_id: u32,
+) -> Result<HashMap<u32, u32>, ApiError> {
+    let rows: Vec<(u32, Option<Decimal>)> = usr_badges::Entity::find()
+        .select_only()
+        .column(usr_badges::Column::To)
+        .column_as(usr_badges::Column::HeldPoints.sum(), "held")
+        .filter(usr_badges::Column::ApxId.eq(apx_id))
+        .filter(usr_badges::Column::VdrId.eq(vdr_id))
+        .filter(usr_badges::Column::HeldAt.is_not_null())
+        .filter(usr_badges::Column::RevokedAt.is_null())
+        .group_by(usr_badges::Column::To)
+        .into_tuple()
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Sum held points error: {}", e)))?;
+    Ok(rows.into_iter().map(|(to, held)| (to, held.and_then(|d| d.to_u32()).unwrap_or(u32::MAX))).collect())
+}
+
+/// 分配対象（VDR 内でポイントを保有する個人）を取得する（lock が true の場合は行ロックする）
+/// 保留中の授与のポイントは保有ポイントから除き、分配されずに残す
+pub async fn load_holders_for_rt<C: ConnectionTrait>(
+    conn: &C,
+    apx_id: u32,
+    vdr_id: u32,
+    lock: bool,
+) -> Result<Vec<Holder>, ApiError> {
+    let mut query = usrs::Entity::find()
+        .filter(usrs::Column::ApxId.eq(apx_id))
+        .filter(usrs::Column::VdrId.eq(vdr_id))
+        .filter(usrs::Column::Type.eq(UsrType::Indi as u8))
+        .filter(usrs::Column::Points.gt(0))
+        .order_by_asc(usrs::Column::Id);
+    if lock {
+        query = query.lock_exclusive();
+    }
+    let models = query
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch holders error: {}", e)))?;
+    let held_of = load_held_points_for_rt(conn, apx_id, vdr_id).await?;
+    Ok(models
+        .into_iter()
+        .map(|m| {
+            let held = held_of.get(&(m.id as u32)).copied().unwrap_or(0);
+            Holder { usr_id: m.id as u32, points: m.points.saturating_sub(held) }
+        })
+        .filter(|h| h.points > 0)
+        .collect())
+}
+
+/// VDR の源泉徴収ルールを取得する
+pub fn withholding_rule_of(vdr: &usrs::Model) -> WithholdingRule {
+    WithholdingRule {
+        threshold: vdr.withholding_threshold,
+        rate: vdr.withholding_rate,
+        reduced_rate: vdr.withholding_reduced_rate,
+        reduced_limit: vdr.withholding_reduced_limit,
+    }
+}
+
+/// 分配計画を作成する（DB への書き込みは行わない）
+/// total を指定した場合はプールの remain の代わりにその額で計算する
+/// 実際の分配と同じ plan_flush を用いるため、同じ状態であれば結果は必ず一致する
+pub async fn plan_flush_for_rt(
+    conn: &DatabaseConnection,
+    apx_id: u32,
+    vdr_id: u32,
+    total: Option<u32>,
+) -> Result<FlushPlan, ApiError> {
+    let vdr = find_vdr_for_rt(conn, apx_id, vdr_id).await?;
+    let total = match total {
+        Some(total) => total,
+        None => find_pool_for_rt(conn, apx_id, vdr_id, false).await?.remain,
+    };
+    let holders = load_holders_for_rt(conn, apx_id, vdr_id, false).await?;
+    Ok(plan_flush(total, vdr.flush_fee_rate, &holders, &withholding_rule_of(&vdr)))
+}
+
+/// 現金プールを個人へ分配する
+/// 個人ごとの分配金額（総額）・源泉徴収額・差引支払額は payouts に保存する
+/// プールと分配対象の個人を行ロックした上で、flushes / payouts の作成、個人の sum_p / sum_c の加算と points の消し込み、
+/// 分配した個人の未分配の授与（保留中を除く）への flush_id / flushed_at の記録、プールの remain から total_out への移動を 1 トランザクションで行う
+pub async fn execute_flush_for_rt(
+    conn: &DatabaseConnection,
+    apx_id: u32,
+    vdr_id: u32,
+) -> Result<(flushes::Model, FlushPlan), ApiError> {
+    let vdr = find_vdr_for_rt(conn, apx_id, vdr_id).await?;
+    let flush_fee_rate = vdr.flush_fee_rate;
+    let withholding = withholding_rule_of(&vdr);
+    let res = conn.transaction::<_, (flushes::Model, FlushPlan), ApiError>(|tx| {
+        Box::pin(async move {
+            // --------------------------------
+            // 1. プールと分配対象をロックして分配計画を作成
+            // --------------------------------
+            let pool = find_pool_for_rt(tx, apx_id, vdr_id, true).await?;
+            if pool.remain == 0 {
+                return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Pool is empty."));
+            }
+            let holders = load_holders_for_rt(tx, apx_id, vdr_id, true).await?;
+            let plan = plan_flush(pool.remain, flush_fee_rate, &holders, &withholding);
+            if plan.points == 0 {
+                return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "No points to distribute."));
+            }
+            if plan.distributable == 0 {
+                return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Nothing to distribute after fee."));
+            }
+            log::debug!("<FlushForRt> execute_flush_for_rt: vdr: {}, total: {}, fee: {}, distributable: {}, points: {}, remainder: {}, withheld: {}", vdr_id, plan.total, plan.fee, plan.distributable, plan.points, plan.remainder, plan.withheld);
+            // --------------------------------
+            // 2. 分配記録の作成
+            // --------------------------------
+            let flush = flushes::ActiveModel {
+                pool_id: Set(pool.id as u32),
+                total: Set(plan.total),
+                flush_fee_rate: Set(plan.flush_fee_rate),
+                points: Set(plan.points),
+                apx_id: Set(apx_id),
+                vdr_id: Set(vdr_id),
+                ..Default::default()
+            };
+            let flush = flush.insert(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Insert flush error: {}", e)))?;
+            // --------------------------------
+            // 3. 個人ごとの分配と残高の更新
+            // --------------------------------
+            for line in &plan.lines {
+                let payout = payouts::ActiveModel {
+                    pool_id: Set(pool.id as u32),
+                    flush_id: Set(flush.id as u32),
+                    usr_id: Set(line.usr_id),
+                    points: Set(line.points),
+                    share: Set(line.share),
+                    amount: Set(line.amount),
+                    withheld: Set(line.withheld),
+                    net: Set(line.net),
+                    apx_id: Set(apx_id),
+                    vdr_id: Set(vdr_id),
+                    ..Default::default()
+                };
+                payout.insert(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Insert payout error: {}", e)))?;
+                usrs::Entity::update_many()
+                    .col_expr(usrs::Column::Points, Expr::col(usrs::Column::Points).sub(line.points))
+                    .col_expr(usrs::Column::SumP, Expr::col(usrs::Column::SumP).add(line.points))
+                    .col_expr(usrs::Column::SumC, Expr::col(usrs::Column::SumC).add(line.amount))
+                    .filter(usrs::Column::Id.eq(line.usr_id))
+                    .exec(tx)
+                    .await
+                    .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update usr error: {}", e)))?;
+            }
+            // --------------------------------
+            // 4. 分配した個人の未分配の授与を分配済みとして記録（保留中の授与は除く）
+            // --------------------------------
+            let paid_usr_ids: Vec<u32> = plan.lines.iter().filter(|l| l.points > 0).map(|l| l.usr_id).collect();
+            usr_badges::Entity::update_many()
+                .col_expr(usr_badges::Column::FlushId, Expr::value(flush.id as u32))
+                .col_expr(usr_badges::Column::FlushedAt, Expr::value(flush.created_at))
+                .filter(usr_badges::Column::ApxId.eq(apx_id))
+                .filter(usr_badges::Column::VdrId.eq(vdr_id))
+                .filter(usr_badges::Column::To.is_in(paid_usr_ids))
+                .filter(usr_badges::Column::FlushId.eq(0))
+                .filter(usr_badges::Column::HeldAt.is_null())
+                .filter(usr_badges::Column::RevokedAt.is_null())
+                .exec(tx)
+                .await
+                .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update usr_badges error: {}", e)))?;
+            // --------------------------------
+            // 5. プールの remain を total_out へ移動
+            // --------------------------------
+            let total_out = pool.total_out.checked_add(pool.remain)
+                .ok_or_else(|| ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "Pool total_out overflow."))?;
+            let mut active_pool: pools::ActiveModel = pool.into_active_model();
+            active_pool.remain = Set(0);
+            active_pool.total_out = Set(total_out);
+            active_pool.update(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update pool error: {}", e)))?;
+            Ok((flush, plan))
+        })
+    }).await?;
+    Ok(res)
+}
@@ -0,0 +1,141 @@
+use axum::http::StatusCode;
+use chrono::NaiveDateTime;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
+use crate::entities::{flushes, payments, payouts, withdrawals};
+use crate::enums::withdrawalstatus::WithdrawalStatus;
+use crate::mode::rt::{rterr::rterr, rtres::errs_res::ApiError};
+use crate::utils::flush::calc_flush_fee;
+use crate::utils::journal::{
+    ACCT_CASH, ACCT_FEE_INCOME, ACCT_PAYABLE, ACCT_PAYOUT_CLEARING, ACCT_POOL, ACCT_WITHHOLDING,
+    JournalEntry, JournalLine, check_balanced, check_settled,
+};
+
+/// VDR の期間内（bgn 以上 end 未満）の資金移動から仕訳を作成する
+/// - payments: 普通預金 / 受取手数料（fee）・預り金（現金プール）（net）。返金（reversal_of あり）はその逆仕訳
+/// - flushes: 預り金（現金プール） / 受取手数料（事務費用）・未払分配金（distributable）
+/// - payouts: 未払分配金 / 預り金（源泉所得税）（withheld）・未払金（個人）（net）
+/// - withdrawals（支払済）: 未払金（個人） / 普通預金
+/// - exports（全銀ファイルで振り込んだ payouts）: 未払金（個人） / 普通預金（net）。exported_at で期間を判定する
+///
+/// payouts は期間内の flushes に属するものを対象とするため、未払分配金は期間内で必ず相殺される
+/// 振り込んだ payouts は、計上と振込が共に期間内にあれば未払金（個人）が相殺されることも検証する
+/// 貸借が一致しない場合は 409 とし、仕訳は返さない
+pub async fn build_journal_for_rt<C: ConnectionTrait>(
+    conn: &C,
+    apx_id: u32,
+    vdr_id: u32,
+    bgn: NaiveDateTime,
+    end: NaiveDateTime,
+) -> Result<Vec<JournalEntry>, ApiError> {
+    let mut entries: Vec<JournalEntry> = Vec::new();
+    // --------------------------------
+    // 1. 入金（payments）
+    // --------------------------------
+    let payments = payments::Entity::find()
+        .filter(payments::Column::ApxId.eq(apx_id))
+        .filter(payments::Column::VdrId.eq(vdr_id))
+        .filter(payments::Column::CreatedAt.gte(bgn))
+        .filter(payments::Column::CreatedAt.lt(end))
+        .order_by_asc(payments::Column::Id)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch payments error: {}", e)))?;
+    for p in payments {
+        let entry = match p.reversal_of {
+            // 返金（取消）は元の入金の逆仕訳
+            Some(original_id) => JournalEntry::new(p.created_at, "payments", p.id as u32, format!("返金 法人ID:{} 元入金ID:{}", p.corp_id, original_id), vec![
+                JournalLine::debit(ACCT_FEE_INCOME, p.fee as u64),
+                JournalLine::debit(ACCT_POOL, p.net as u64),
+                JournalLine::credit(ACCT_CASH, p.amount as u64),
+            ]),
+            None => JournalEntry::new(p.created_at, "payments", p.id as u32, format!("入金 法人ID:{}", p.corp_id), vec![
+                JournalLine::debit(ACCT_CASH, p.amount as u64),
+                JournalLine::credit(ACCT_FEE_INCOME, p.fee as u64),
+                JournalLine::credit(ACCT_POOL, p.net as u64),
+            ]),
+        };
+        entries.push(entry);
+    }
+    // --------------------------------
+    // 2. 分配（flushes）と個人への分配（payouts）
+    // --------------------------------
+    let flushes = flushes::Entity::find()
+        .filter(flushes::Column::ApxId.eq(apx_id))
+        .filter(flushes::Column::VdrId.eq(vdr_id))
+        .filter(flushes::Column::CreatedAt.gte(bgn))
+        .filter(flushes::Column::CreatedAt.lt(end))
+        .order_by_asc(flushes::Column::Id)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch flushes error: {}", e)))?;
+    let flush_ids: Vec<u32> = flushes.iter().map(|f| f.id as u32).collect();
+    let payouts = payouts::Entity::find()
+        .filter(payouts::Column::FlushId.is_in(flush_ids))
+        .order_by_asc(payouts::Column::Id)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch payouts error: {}", e)))?;
+    for f in &flushes {
+        let fee = calc_flush_fee(f.total, f.flush_fee_rate);
+        entries.push(JournalEntry::new(f.created_at, "flushes", f.id as u32, format!("分配 分配ID:{}", f.id), vec![
+            JournalLine::debit(ACCT_POOL, f.total as u64),
+            JournalLine::credit(ACCT_FEE_INCOME, fee as u64),
+            JournalLine::credit(ACCT_PAYOUT_CLEARING, (f.total - fee) as u64),
+        ]));
+        for p in payouts.iter().filter(|p| p.flush_id == f.id as u32) {
+            entries.push(JournalEntry::new(p.created_at, "payouts", p.id as u32, format!("個人への分配 分配ID:{} 個人ID:{}", p.flush_id, p.usr_id), vec![
+                JournalLine::debit(ACCT_PAYOUT_CLEARING, p.amount as u64),
+                JournalLine::credit(ACCT_WITHHOLDING, p.withheld as u64),
+                JournalLine::credit(ACCT_PAYABLE, p.net as u64),
+            ]));
+        }
+    }
+    // --------------------------------
+    // 3. 出金（支払済の withdrawals）
+    // --------------------------------
+    let withdrawals = withdrawals::Entity::find()
+        .filter(withdrawals::Column::ApxId.eq(apx_id))
+        .filter(withdrawals::Column::VdrId.eq(vdr_id))
+        .filter(withdrawals::Column::Status.eq(WithdrawalStatus::Paid as u8))
+        .filter(withdrawals::Column::PaidAt.gte(bgn))
+        .filter(withdrawals::Column::PaidAt.lt(end))
+        .order_by_asc(withdrawals::Column::Id)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch withdrawals error: {}", e)))?;
+    for w in withdrawals {
+        let date = w.paid_at.unwrap_or(w.updated_at);
+        entries.push(JournalEntry::new(date, "withdrawals", w.id as u32, format!("出金 個人ID:{}", w.usr_id), vec![
+            JournalLine::debit(ACCT_PAYABLE, w.amount as u64),
+            JournalLine::credit(ACCT_CASH, w.amount as u64),
+        ]));
+    }
+    // --------------------------------
+    // 4. 全銀ファイルでの振込（exported_at のある payouts）
+    // --------------------------------
+    let exported = payouts::Entity::find()
+        .filter(payouts::Column::ApxId.eq(apx_id))
+        .filter(payouts::Column::VdrId.eq(vdr_id))
+        .filter(payouts::Column::ExportedAt.gte(bgn))
+        .filter(payouts::Column::ExportedAt.lt(end))
+        .order_by_asc(payouts::Column::Id)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch exported payouts error: {}", e)))?;
+    for p in exported {
+        let date = p.exported_at.unwrap_or(p.updated_at);
+        entries.push(JournalEntry::new(date, "exports", p.id as u32, format!("振込 分配ID:{} 個人ID:{}", p.flush_id, p.usr_id), vec![
+            JournalLine::debit(ACCT_PAYABLE, p.net as u64),
+            JournalLine::credit(ACCT_CASH, p.net as u64),
+        ]));
+    }
+    // --------------------------------
+    // 5. 日時順に並べて貸借を検証
+    // --------------------------------
+    entries.sort_by(|a, b| a.date.cmp(&b.date));
+    check_balanced(&entries, &[ACCT_PAYOUT_CLEARING])
+        .and_then(|_| check_settled(&entries, ACCT_PAYABLE, "payouts", "exports"))
+        .map_err(|e| ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, format!("Journal is unbalanced: {}", e)))?;
+    log::debug!("<JournalForRt> build_journal_for_rt: vdr: {}, entries: {}", vdr_id, entries.len());
+    Ok(entries)
+}
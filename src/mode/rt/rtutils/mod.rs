@@ -1 +1,12 @@
+pub mod bank_accounts_for_rt;
+pub mod belongs_for_rt;
+pub mod config_for_rt;
 pub mod db_for_rt;
+pub mod flush_for_rt;
+pub mod journals_for_rt;
+pub mod point_expiry_for_rt;
+pub mod points_for_rt;
+pub mod pool_ledger_for_rt;
+pub mod usrs_for_rt;
+pub mod withdrawals_for_rt;
+pub mod zengin_for_rt;
@@ -0,0 +1,191 @@
+use std::collections::{HashMap, HashSet};
+use axum::http::StatusCode;
+use chrono::NaiveDateTime;
+use sea_orm::{ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait};
+use crate::entities::{point_adjustments, points, usr_badges, usrs};
+use crate::enums::pointadjuststatus::PointAdjustStatus;
+use crate::enums::pointkind::PointKind;
+use crate::enums::usrtype::UsrType;
+use crate::mode::rt::{rterr::rterr, rtres::errs_res::ApiError};
+use crate::mode::rt::rtutils::points_for_rt::insert_point_for_rt;
+use crate::utils::point_expiry::{PointCredit, PointLot, allocate_lots, expiring_by_award};
+
+/// 個人への付与の履歴を取得する
+/// バッジ授与によるポイント（point + extra、取り消しで戻した分と授与ごとに失効済みの分を除く）と、承認済みの加算の手動調整（承認日時で付与）を対象とする
+/// held に含まれる授与（保留中）は対象としない
+async fn load_point_credits_for_rt<C: ConnectionTrait>(
+    conn: &C,
+    apx_id: u32,
+    vdr_id: u32,
+    usr_id: u32,
+    held: &HashSet<u32>,
+) -> Result<Vec<PointCredit>, ApiError> {
+    let awards = points::Entity::find()
+        .filter(points::Column::ApxId.eq(apx_id))
+        .filter(points::Column::VdrId.eq(vdr_id))
+        .filter(points::Column::To.eq(usr_id))
+        .filter(points::Column::Kind.eq(PointKind::Award as u8))
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch points error: {}", e)))?;
+    let reversals = points::Entity::find()
+        .filter(points::Column::ApxId.eq(apx_id))
+        .filter(points::Column::VdrId.eq(vdr_id))
+        .filter(points::Column::To.eq(usr_id))
+        .filter(points::Column::Kind.is_in([PointKind::Expiry as u8, PointKind::Revocation as u8]))
+        .filter(points::Column::UsrBadgeId.gt(0))
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch points error: {}", e)))?;
+    let mut reversed_of: HashMap<u32, u32> = HashMap::new();
+    for p in reversals {
+        *reversed_of.entry(p.usr_badge_id).or_default() += p.delta.unsigned_abs();
+    }
+    let adjustments = point_adjustments::Entity::find()
+        .filter(point_adjustments::Column::ApxId.eq(apx_id))
+        .filter(point_adjustments::Column::VdrId.eq(vdr_id))
+        .filter(point_adjustments::Column::UsrId.eq(usr_id))
+        .filter(point_adjustments::Column::Status.eq(PointAdjustStatus::Approved as u8))
+        .filter(point_adjustments::Column::Delta.gt(0))
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch point_adjustments error: {}", e)))?;
+    let mut credits: Vec<PointCredit> = awards
+        .into_iter()
+        .filter(|p| !held.contains(&p.usr_badge_id))
+        .map(|p| PointCredit {
+            granted_at: p.created_at,
+            points: (p.point + p.extra).saturating_sub(reversed_of.get(&p.usr_badge_id).copied().unwrap_or(0)),
+            usr_badge_id: p.usr_badge_id,
+        })
+        .filter(|c| c.points > 0)
+        .collect();
+    credits.extend(adjustments.into_iter().map(|a| PointCredit { granted_at: a.reviewed_at.unwrap_or(a.created_at), points: a.delta as u32, usr_badge_id: 0 }));
+    Ok(credits)
+}
+
+/// 個人の保有ポイント（balance）を付与日時ごとの残りに割り当てる
+/// 共謀の疑いで保留中の授与のポイント（held_points）は、保留の解除・取り消しまで扱いが確定しないため失効の対象から除く
+pub async fn load_point_lots_for_rt<C: ConnectionTrait>(
+    conn: &C,
+    apx_id: u32,
+    vdr_id: u32,
+    usr_id: u32,
+    balance: u32,
+    months: u32,
+) -> Result<Vec<PointLot>, ApiError> {
+    if balance == 0 || months == 0 {
+        return Ok(Vec::new());
+    }
+    let held_awards = usr_badges::Entity::find()
+        .filter(usr_badges::Column::ApxId.eq(apx_id))
+        .filter(usr_badges::Column::VdrId.eq(vdr_id))
+        .filter(usr_badges::Column::To.eq(usr_id))
+        .filter(usr_badges::Column::HeldAt.is_not_null())
+        .filter(usr_badges::Column::RevokedAt.is_null())
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch held usr_badges error: {}", e)))?;
+    let held_points: u32 = held_awards.iter().map(|b| b.held_points).sum();
+    let held: HashSet<u32> = held_awards.iter().map(|b| b.id as u32).collect();
+    let balance = balance.saturating_sub(held_points);
+    if balance == 0 {
+        return Ok(Vec::new());
+    }
+    let credits = load_point_credits_for_rt(conn, apx_id, vdr_id, usr_id, &held).await?;
+    Ok(allocate_lots(balance, &credits, months))
+}
+
+/// 個人の失効日時を迎えたポイントを失効させる
+/// 個人の行をロックした上で再計算し、失効の行（delta は負の値）をポイント履歴へ追記して保有ポイント（usrs.points）から減算する
+/// 失効の行は付与元のバッジ授与ごとに作成し（usr_badge_id、手動調整の分は 0）、取り消し時に授与ごとの失効済みの分を求められるようにする
+/// 保留中の授与のポイントは失効させない
+/// 同一の個人に対して重複して実行されても、失効済みの分は保有ポイントに含まれないため二重に失効することはない
+pub async fn expire_usr_points_for_rt(
+    conn: &DatabaseConnection,
+    apx_id: u32,
+    vdr_id: u32,
+    usr_id: u32,
+    months: u32,
+    now: NaiveDateTime,
+) -> Result<u32, ApiError> {
+    let expired = conn.transaction::<_, u32, ApiError>(|tx| {
+        Box::pin(async move {
+            let usr = usrs::Entity::find_by_id(usr_id as i32)
+                .lock_exclusive()
+                .one(tx)
+                .await
+                .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock usr error: {}", e)))?
+                .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "USR not found."))?;
+            let lots = load_point_lots_for_rt(tx, apx_id, vdr_id, usr_id, usr.points, months).await?;
+            let mut remaining = usr.points;
+            let mut expired = 0;
+            for (usr_badge_id, points) in expiring_by_award(&lots, now) {
+                let points = points.min(remaining);
+                if points == 0 {
+                    continue;
+                }
+                let active = points::ActiveModel {
+                    badge_id: Set(0),
+                    usr_badge_id: Set(usr_badge_id),
+                    corp_id: Set(0),
+                    from: Set(0),
+                    to: Set(usr_id),
+                    point: Set(0),
+                    extra: Set(0),
+                    kind: Set(PointKind::Expiry as u8),
+                    delta: Set(-(points as i32)),
+                    apx_id: Set(apx_id),
+                    vdr_id: Set(vdr_id),
+                    ..Default::default()
+                };
+                insert_point_for_rt(tx, active).await?;
+                remaining -= points;
+                expired += points;
+            }
+            if expired == 0 {
+                return Ok(0);
+            }
+            log::debug!("<PointExpiryForRt> expire_usr_points_for_rt: usr: {}, points: {}, expired: {}", usr_id, usr.points, expired);
+            Ok(expired)
+        })
+    }).await?;
+    Ok(expired)
+}
+
+/// VDR 配下でポイントを保有する全ての個人について、失効日時を迎えたポイントを失効させる
+/// 戻り値は（失効が発生した個人の数, 失効したポイントの合計）
+pub async fn expire_vdr_points_for_rt(
+    conn: &DatabaseConnection,
+    vdr: &usrs::Model,
+    now: NaiveDateTime,
+) -> Result<(usize, u64), ApiError> {
+    let apx_id = vdr.apx_id.unwrap_or(0);
+    let vdr_id = vdr.id as u32;
+    if vdr.point_expire_months == 0 {
+        return Ok((0, 0));
+    }
+    let usr_ids: Vec<i32> = usrs::Entity::find()
+        .select_only()
+        .column(usrs::Column::Id)
+        .filter(usrs::Column::ApxId.eq(apx_id))
+        .filter(usrs::Column::VdrId.eq(vdr_id))
+        .filter(usrs::Column::Type.eq(UsrType::Indi as u8))
+        .filter(usrs::Column::Points.gt(0))
+        .order_by_asc(usrs::Column::Id)
+        .into_tuple()
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch holders error: {}", e)))?;
+    let mut usrs_expired = 0;
+    let mut total = 0u64;
+    for usr_id in usr_ids {
+        let expired = expire_usr_points_for_rt(conn, apx_id, vdr_id, usr_id as u32, vdr.point_expire_months, now).await?;
+        if expired > 0 {
+            usrs_expired += 1;
+            total += expired as u64;
+        }
+    }
+    log::debug!("<PointExpiryForRt> expire_vdr_points_for_rt: vdr: {}, usrs: {}, expired: {}", vdr_id, usrs_expired, total);
+    Ok((usrs_expired, total))
+}
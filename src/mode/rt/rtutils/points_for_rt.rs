@@ -0,0 +1,71 @@
+use axum::http::StatusCode;
+use chrono::NaiveDateTime;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use sea_orm::{ConnectionTrait, EntityTrait, QueryFilter, ColumnTrait, ActiveModelTrait};
+use sea_orm::sea_query::Expr;
+use crate::entities::{points, usrs};
+use crate::mode::rt::{rterr::rterr, rtres::errs_res::ApiError};
+use crate::mode::rt::rtutils::usrs_for_rt::{find_vdr_for_rt, find_usr_by_type_for_rt};
+use crate::mode::rt::rtutils::belongs_for_rt::belong_rate_at_for_rt;
+use crate::enums::usrtype::UsrType;
+
+/// バッジ授与 1 件あたりのポイント内訳
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AwardPoint {
+    /// VDR の base_point による基本ポイント
+    pub point: u32,
+    /// 法人の rate と所属割増 belong_rate による割増ポイント
+    pub extra: u32,
+}
+
+/// 割増ポイントを算出する
+/// extra = floor(point × (corp_rate + belong_rate))
+/// 積は rust_decimal で誤差なく求め、保有ポイントが整数のため 1 ポイント未満は切り捨てる（端数は繰り越さない）
+pub fn calc_extra(point: u32, corp_rate: Decimal, belong_rate: Decimal) -> u32 {
+    let rate = corp_rate + belong_rate;
+    if rate <= Decimal::ZERO {
+        return 0;
+    }
+    (Decimal::from(point) * rate).floor().to_u32().unwrap_or(u32::MAX)
+}
+
+/// バッジ授与時に受領者へ付与するポイントを算出する
+/// 基本ポイントは VDR の base_point、割増は授与法人の rate と（授与時点で所属していれば）VDR の belong_rate
+pub async fn calc_award_point_for_rt<C: ConnectionTrait>(
+    conn: &C,
+    apx_id: u32,
+    vdr_id: u32,
+    corp_id: u32,
+    to: u32,
+    at: NaiveDateTime,
+) -> Result<AwardPoint, ApiError> {
+    let vdr = find_vdr_for_rt(conn, apx_id, vdr_id).await?;
+    let corp = find_usr_by_type_for_rt(conn, apx_id, vdr_id, corp_id, UsrType::Corp).await?;
+    let belong_rate = belong_rate_at_for_rt(conn, apx_id, vdr_id, corp_id, to, at).await?;
+    let extra = calc_extra(vdr.base_point, corp.rate, belong_rate);
+    log::debug!("<PointsForRt> calc_award_point_for_rt: base: {}, corp_rate: {}, belong_rate: {}, extra: {}", vdr.base_point, corp.rate, belong_rate, extra);
+    Ok(AwardPoint { point: vdr.base_point, extra })
+}
+
+/// ポイント履歴を追記し、受領者の保有ポイント（usrs.points）へ delta（符号付き）を同時に反映する
+/// 呼び出し側のトランザクション内で使用すること
+pub async fn insert_point_for_rt<C: ConnectionTrait>(
+    tx: &C,
+    active: points::ActiveModel,
+) -> Result<points::Model, ApiError> {
+    let model = active.insert(tx).await.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Insert point error: {}", e)))?;
+    let amount = model.delta.unsigned_abs();
+    let expr = if model.delta >= 0 {
+        Expr::col(usrs::Column::Points).add(amount)
+    } else {
+        Expr::col(usrs::Column::Points).sub(amount)
+    };
+    usrs::Entity::update_many()
+        .col_expr(usrs::Column::Points, expr)
+        .filter(usrs::Column::Id.eq(model.to))
+        .exec(tx)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update usr points error: {}", e)))?;
+    Ok(model)
+}
@@ -0,0 +1,265 @@
+use axum::http::StatusCode;
+use chrono::NaiveDateTime;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect, Select};
+use crate::entities::{flushes, payments};
+use crate::enums::poolmovekind::PoolMoveKind;
+use crate::mode::rt::{rterr::rterr, rtres::errs_res::ApiError};
+use crate::mode::rt::rtutils::flush_for_rt::find_pool_for_rt;
+
+/// 現金プールの 1 件の増減と、その時点の残高
+#[derive(Debug, Clone)]
+pub struct PoolMove {
+    pub at: NaiveDateTime,
+    pub kind: PoolMoveKind,
+    /// payments.id または flushes.id
+    pub source_id: u32,
+    pub amount_in: u32,
+    pub amount_out: u32,
+    /// この増減を反映した後の残高
+    pub balance: i64,
+}
+
+/// 現金プールの残高と累計
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolTotals {
+    pub remain: i64,
+    pub total_in: i64,
+    pub total_out: i64,
+}
+
+/// 保存されている pools の値と、台帳から再計算した値の比較結果
+#[derive(Debug, Clone)]
+pub struct PoolCheck {
+    pub stored: PoolTotals,
+    pub computed: PoolTotals,
+    /// 一致しない項目の説明（空であれば整合している）
+    pub mismatches: Vec<String>,
+}
+
+/// VDR の現金プールの全ての増減を時系列に並べ、残高を再計算する
+/// - 入金（payments）: remain と total_in に net を加算
+/// - 返金（reversal_of を持つ payments）: remain と total_in から net を減算
+/// - 分配（flushes）: remain から total を減算し、total_out に加算
+///
+/// 同一日時の増減は 入金 → 返金 → 分配 の順、さらに ID の昇順とする
+pub async fn load_pool_ledger_for_rt<C: ConnectionTrait>(
+    conn: &C,
+    apx_id: u32,
+    vdr_id: u32,
+) -> Result<(Vec<PoolMove>, PoolTotals), ApiError> {
+    // --------------------------------
+    // 1. 入金・返金・分配の取得
+    // --------------------------------
+    let payments = payments::Entity::find()
+        .filter(payments::Column::ApxId.eq(apx_id))
+        .filter(payments::Column::VdrId.eq(vdr_id))
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch payments error: {}", e)))?;
+    let flushes = flushes::Entity::find()
+        .filter(flushes::Column::ApxId.eq(apx_id))
+        .filter(flushes::Column::VdrId.eq(vdr_id))
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch flushes error: {}", e)))?;
+    let mut moves: Vec<PoolMove> = Vec::with_capacity(payments.len() + flushes.len());
+    for p in payments {
+        let (kind, amount_in, amount_out) = match p.reversal_of {
+            Some(_) => (PoolMoveKind::Refund, 0, p.net),
+            None => (PoolMoveKind::Payment, p.net, 0),
+        };
+        moves.push(PoolMove { at: p.created_at, kind, source_id: p.id as u32, amount_in, amount_out, balance: 0 });
+    }
+    for f in flushes {
+        moves.push(PoolMove { at: f.created_at, kind: PoolMoveKind::Flush, source_id: f.id as u32, amount_in: 0, amount_out: f.total, balance: 0 });
+    }
+    // --------------------------------
+    // 2. 時系列に並べて残高と累計を再計算
+    // --------------------------------
+    moves.sort_by(|a, b| a.at.cmp(&b.at).then(a.kind.cmp(&b.kind)).then(a.source_id.cmp(&b.source_id)));
+    let mut totals = PoolTotals::default();
+    for m in moves.iter_mut() {
+        match m.kind {
+            PoolMoveKind::Payment => totals.total_in += m.amount_in as i64,
+            PoolMoveKind::Refund => totals.total_in -= m.amount_out as i64,
+            PoolMoveKind::Flush => totals.total_out += m.amount_out as i64,
+        }
+        totals.remain += m.amount_in as i64 - m.amount_out as i64;
+        m.balance = totals.remain;
+    }
+    log::debug!("<PoolLedgerForRt> load_pool_ledger_for_rt: vdr: {}, moves: {}, totals: {:?}", vdr_id, moves.len(), totals);
+    Ok((moves, totals))
+}
+
+/// 台帳の 1 ページ分の増減と、期間開始・終了時点の残高
+#[derive(Debug, Clone)]
+pub struct PoolLedgerPage {
+    /// 期間開始時点の残高
+    pub opening_balance: i64,
+    /// 期間終了時点の残高
+    pub closing_balance: i64,
+    /// balance は期間開始時点の残高から積み上げた値
+    pub moves: Vec<PoolMove>,
+}
+
+/// before より前（None は全期間）の増減を集計した残高を算出する
+/// 入金の net の合計 - 返金の net の合計 - 分配の total の合計
+pub async fn sum_pool_balance_for_rt<C: ConnectionTrait>(
+    conn: &C,
+    apx_id: u32,
+    vdr_id: u32,
+    before: Option<NaiveDateTime>,
+) -> Result<i64, ApiError> {
+    let sum_payments = |reversal: bool| {
+        let query = payments::Entity::find()
+            .select_only()
+            .column_as(payments::Column::Net.sum(), "total")
+            .filter(payments::Column::ApxId.eq(apx_id))
+            .filter(payments::Column::VdrId.eq(vdr_id));
+        let query = if reversal { query.filter(payments::Column::ReversalOf.is_not_null()) } else { query.filter(payments::Column::ReversalOf.is_null()) };
+        match before {
+            Some(before) => query.filter(payments::Column::CreatedAt.lt(before)),
+            None => query,
+        }
+    };
+    let paid: Option<Decimal> = sum_payments(false).into_tuple().one(conn).await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Sum payments error: {}", e)))?
+        .flatten();
+    let refunded: Option<Decimal> = sum_payments(true).into_tuple().one(conn).await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Sum refunds error: {}", e)))?
+        .flatten();
+    let mut flushed = flushes::Entity::find()
+        .select_only()
+        .column_as(flushes::Column::Total.sum(), "total")
+        .filter(flushes::Column::ApxId.eq(apx_id))
+        .filter(flushes::Column::VdrId.eq(vdr_id));
+    if let Some(before) = before {
+        flushed = flushed.filter(flushes::Column::CreatedAt.lt(before));
+    }
+    let flushed: Option<Decimal> = flushed.into_tuple().one(conn).await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Sum flushes error: {}", e)))?
+        .flatten();
+    let to_i64 = |v: Option<Decimal>| v.and_then(|d| d.to_i64()).unwrap_or(0);
+    Ok(to_i64(paid) - to_i64(refunded) - to_i64(flushed))
+}
+
+/// 期間 [bgn, end) の台帳を offset / limit で取得する
+/// 期間開始時点の残高は集計クエリで求め、ページより前の期間内の増減は
+/// 入金・返金・分配のそれぞれを先頭から offset + limit 件まで取得して積み上げるため、全期間を読み込まない
+pub async fn load_pool_ledger_page_for_rt<C: ConnectionTrait>(
+    conn: &C,
+    apx_id: u32,
+    vdr_id: u32,
+    bgn: Option<NaiveDateTime>,
+    end: Option<NaiveDateTime>,
+    offset: u64,
+    limit: u64,
+) -> Result<PoolLedgerPage, ApiError> {
+    // --------------------------------
+    // 1. 期間開始・終了時点の残高
+    // --------------------------------
+    let opening_balance = match bgn {
+        Some(_) => sum_pool_balance_for_rt(conn, apx_id, vdr_id, bgn).await?,
+        None => 0,
+    };
+    let closing_balance = sum_pool_balance_for_rt(conn, apx_id, vdr_id, end).await?;
+    // --------------------------------
+    // 2. 期間内の増減を種別ごとに先頭から offset + limit 件まで取得
+    // --------------------------------
+    let take = offset + limit;
+    let in_period_payments = |query: Select<payments::Entity>| {
+        let query = query
+            .filter(payments::Column::ApxId.eq(apx_id))
+            .filter(payments::Column::VdrId.eq(vdr_id));
+        let query = match bgn {
+            Some(bgn) => query.filter(payments::Column::CreatedAt.gte(bgn)),
+            None => query,
+        };
+        let query = match end {
+            Some(end) => query.filter(payments::Column::CreatedAt.lt(end)),
+            None => query,
+        };
+        query.order_by_asc(payments::Column::CreatedAt).order_by_asc(payments::Column::Id).limit(take)
+    };
+    let paid = in_period_payments(payments::Entity::find().filter(payments::Column::ReversalOf.is_null()))
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch payments error: {}", e)))?;
+    let refunded = in_period_payments(payments::Entity::find().filter(payments::Column::ReversalOf.is_not_null()))
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch refunds error: {}", e)))?;
+    let mut flushes = flushes::Entity::find()
+        .filter(flushes::Column::ApxId.eq(apx_id))
+        .filter(flushes::Column::VdrId.eq(vdr_id));
+    if let Some(bgn) = bgn {
+        flushes = flushes.filter(flushes::Column::CreatedAt.gte(bgn));
+    }
+    if let Some(end) = end {
+        flushes = flushes.filter(flushes::Column::CreatedAt.lt(end));
+    }
+    let flushes = flushes
+        .order_by_asc(flushes::Column::CreatedAt)
+        .order_by_asc(flushes::Column::Id)
+        .limit(take)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch flushes error: {}", e)))?;
+    // --------------------------------
+    // 3. 時系列に並べ、期間開始時点の残高から積み上げてページを切り出す
+    // --------------------------------
+    let mut moves: Vec<PoolMove> = Vec::with_capacity(paid.len() + refunded.len() + flushes.len());
+    for p in paid {
+        moves.push(PoolMove { at: p.created_at, kind: PoolMoveKind::Payment, source_id: p.id as u32, amount_in: p.net, amount_out: 0, balance: 0 });
+    }
+    for p in refunded {
+        moves.push(PoolMove { at: p.created_at, kind: PoolMoveKind::Refund, source_id: p.id as u32, amount_in: 0, amount_out: p.net, balance: 0 });
+    }
+    for f in flushes {
+        moves.push(PoolMove { at: f.created_at, kind: PoolMoveKind::Flush, source_id: f.id as u32, amount_in: 0, amount_out: f.total, balance: 0 });
+    }
+    moves.sort_by(|a, b| a.at.cmp(&b.at).then(a.kind.cmp(&b.kind)).then(a.source_id.cmp(&b.source_id)));
+    moves.truncate(take as usize);
+    let mut balance = opening_balance;
+    for m in moves.iter_mut() {
+        balance += m.amount_in as i64 - m.amount_out as i64;
+        m.balance = balance;
+    }
+    let moves: Vec<PoolMove> = moves.into_iter().skip(offset as usize).collect();
+    log::debug!("<PoolLedgerForRt> load_pool_ledger_page_for_rt: vdr: {}, opening: {}, closing: {}, moves: {}", vdr_id, opening_balance, closing_balance, moves.len());
+    Ok(PoolLedgerPage { opening_balance, closing_balance, moves })
+}
+
+/// 台帳から再計算した残高・累計と、保存されている pools の値を比較する
+/// 再計算した残高が途中で負になった場合も不整合として報告する
+pub async fn check_pool_for_rt<C: ConnectionTrait>(
+    conn: &C,
+    apx_id: u32,
+    vdr_id: u32,
+) -> Result<PoolCheck, ApiError> {
+    let pool = find_pool_for_rt(conn, apx_id, vdr_id, false).await?;
+    let (moves, computed) = load_pool_ledger_for_rt(conn, apx_id, vdr_id).await?;
+    let stored = PoolTotals { remain: pool.remain as i64, total_in: pool.total_in as i64, total_out: pool.total_out as i64 };
+    let mut mismatches = Vec::new();
+    if stored.remain != computed.remain {
+        mismatches.push(format!("remain: stored {} != computed {}", stored.remain, computed.remain));
+    }
+    if stored.total_in != computed.total_in {
+        mismatches.push(format!("total_in: stored {} != computed {}", stored.total_in, computed.total_in));
+    }
+    if stored.total_out != computed.total_out {
+        mismatches.push(format!("total_out: stored {} != computed {}", stored.total_out, computed.total_out));
+    }
+    if stored.remain != stored.total_in - stored.total_out {
+        mismatches.push(format!("remain: stored {} != stored total_in {} - total_out {}", stored.remain, stored.total_in, stored.total_out));
+    }
+    if let Some(m) = moves.iter().find(|m| m.balance < 0) {
+        mismatches.push(format!("balance: negative ({}) after {:?} {}", m.balance, m.kind, m.source_id));
+    }
+    if !mismatches.is_empty() {
+        log::warn!("<PoolLedgerForRt> check_pool_for_rt: vdr: {}, mismatches: {:?}", vdr_id, mismatches);
+    }
+    Ok(PoolCheck { stored, computed, mismatches })
+}
@@ -0,0 +1,47 @@
+use axum::http::StatusCode;
+use sea_orm::{ConnectionTrait, EntityTrait, QueryFilter, ColumnTrait};
+use crate::entities::usrs;
+use crate::enums::usrtype::UsrType;
+use crate::mode::rt::{rterr::rterr, rtres::errs_res::ApiError};
+
+/// VDR 本体のレコードを取得する
+/// VDR は apx_id を持ち、vdr_id を持たない USR レコードである
+pub async fn find_vdr_for_rt<C: ConnectionTrait>(
+    conn: &C,
+    apx_id: u32,
+    vdr_id: u32,
+) -> Result<usrs::Model, ApiError> {
+    usrs::Entity::find()
+        .filter(usrs::Column::Id.eq(vdr_id))
+        .filter(usrs::Column::ApxId.eq(apx_id))
+        .filter(usrs::Column::VdrId.is_null())
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch VDR error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "VDR not found."))
+}
+
+/// VDR パーティション内の USR を種別（法人 / 個人）を指定して取得する
+/// 存在しない場合、または種別が一致しない場合は 404 とする
+pub async fn find_usr_by_type_for_rt<C: ConnectionTrait>(
+    conn: &C,
+    apx_id: u32,
+    vdr_id: u32,
+    usr_id: u32,
+    usr_type: UsrType,
+) -> Result<usrs::Model, ApiError> {
+    let label = match usr_type {
+        UsrType::Corp => "Corporate USR",
+        UsrType::Indi => "Personal USR",
+        UsrType::Other => "USR",
+    };
+    usrs::Entity::find()
+        .filter(usrs::Column::Id.eq(usr_id))
+        .filter(usrs::Column::ApxId.eq(apx_id))
+        .filter(usrs::Column::VdrId.eq(vdr_id))
+        .filter(usrs::Column::Type.eq(usr_type as u8))
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch {} error: {}", label, e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, format!("{} not found.", label)))
+}
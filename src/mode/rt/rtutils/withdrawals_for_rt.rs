@@ -0,0 +1,77 @@
+use axum::http::StatusCode;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QuerySelect};
+use crate::entities::{payouts, withdrawals};
+use crate::enums::withdrawalstatus::WithdrawalStatus;
+use crate::mode::rt::{rterr::rterr, rtres::errs_res::ApiError};
+
+/// 個人の出金可能残高
+#[derive(Debug, Clone, Copy)]
+pub struct WithdrawableBalance {
+    /// payouts による差引支払額（源泉徴収後）の累計（振込ファイルへ出力済みの payout を除く）
+    pub paid_out: u64,
+    /// 承認済み・支払済みの出金額の累計
+    pub committed: u64,
+    /// 審査待ち（申請中）の出金額の累計
+    pub pending: u64,
+}
+
+impl WithdrawableBalance {
+    /// 出金可能残高（差引支払額の累計 - 確定済みの出金額）
+    pub fn available(&self) -> u64 {
+        self.paid_out.saturating_sub(self.committed)
+    }
+    /// 新たに申請できる額（出金可能残高 - 申請中の出金額）
+    pub fn requestable(&self) -> u64 {
+        self.available().saturating_sub(self.pending)
+    }
+}
+
+/// 個人の出金可能残高を算出する
+/// 振込ファイルへ出力済み（exported_at あり）の payout は振込で支払われるため、出金の原資に含めない
+/// 同時実行による超過を防ぐため、呼び出し側で個人の行をロックしたトランザクション内で呼ぶこと
+pub async fn calc_withdrawable_for_rt<C: ConnectionTrait>(
+    conn: &C,
+    apx_id: u32,
+    vdr_id: u32,
+    usr_id: u32,
+) -> Result<WithdrawableBalance, ApiError> {
+    let paid_out: Option<Decimal> = payouts::Entity::find()
+        .select_only()
+        .column_as(payouts::Column::Net.sum(), "total")
+        .filter(payouts::Column::ApxId.eq(apx_id))
+        .filter(payouts::Column::VdrId.eq(vdr_id))
+        .filter(payouts::Column::UsrId.eq(usr_id))
+        .filter(payouts::Column::ExportedAt.is_null())
+        .into_tuple()
+        .one(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Sum payouts error: {}", e)))?
+        .flatten();
+    let rows: Vec<(u8, Option<Decimal>)> = withdrawals::Entity::find()
+        .select_only()
+        .column(withdrawals::Column::Status)
+        .column_as(withdrawals::Column::Amount.sum(), "total")
+        .filter(withdrawals::Column::ApxId.eq(apx_id))
+        .filter(withdrawals::Column::VdrId.eq(vdr_id))
+        .filter(withdrawals::Column::UsrId.eq(usr_id))
+        .group_by(withdrawals::Column::Status)
+        .into_tuple()
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Sum withdrawals error: {}", e)))?;
+    let mut balance = WithdrawableBalance { paid_out: to_u64(paid_out), committed: 0, pending: 0 };
+    for (status, total) in rows {
+        match WithdrawalStatus::from_u8(status) {
+            Some(s) if s.is_committed() => balance.committed += to_u64(total),
+            Some(WithdrawalStatus::Requested) => balance.pending += to_u64(total),
+            _ => {}
+        }
+    }
+    Ok(balance)
+}
+
+fn to_u64(v: Option<Decimal>) -> u64 {
+    v.and_then(|d| d.to_u64()).unwrap_or(0)
+}
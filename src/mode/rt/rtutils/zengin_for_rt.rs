@@ -0,0 +1,117 @@
+use axum::http::StatusCode;
+use std::collections::BTreeMap;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait};
+use sea_orm::sea_query::Expr;
+use crate::entities::{flushes, payouts, usrs};
+use crate::mode::rt::{rterr::rterr, rtres::errs_res::ApiError};
+use crate::mode::rt::rtutils::bank_accounts_for_rt::load_zengin_accounts_for_rt;
+use crate::mode::rt::rtutils::withdrawals_for_rt::calc_withdrawable_for_rt;
+use crate::utils::s3client::S3Client;
+use crate::utils::zengin::{ZenginHeader, ZenginRecord, build_zengin};
+
+/// 分配（flush）の payouts を全銀フォーマットの振込ファイルとして出力し、S3Client::up でアップロードする
+/// 振込額は源泉徴収後の差引支払額（net）とし、振込先口座は bank_accounts から復号して用い、net が 0 の payout は出力しない
+/// 出力した payout には exported_at を記録し、出金可能残高（calc_withdrawable_for_rt）から除外するため、
+/// 同じ payout が振込ファイルと出金申請（withdrawals）の両方で支払われることはない
+/// 出力対象の payout が無い（全て出力済み）場合、振込先口座が登録されていない個人が含まれる場合、
+/// 出力すると承認・支払済みの出金額が出金可能残高を超える（出金で支払済みとみなす）個人が含まれる場合は 409 とする
+/// 戻り値はアップロード先のキー
+pub async fn export_flush_zengin_for_rt(
+    conn: &DatabaseConnection,
+    s3c: &S3Client,
+    crypto_key: &str,
+    apx_id: u32,
+    vdr_id: u32,
+    flush_id: u32,
+    header: &ZenginHeader,
+) -> Result<String, ApiError> {
+    let tx = conn.begin().await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Begin transaction error: {}", e)))?;
+    // --------------------------------
+    // 1. 分配と未出力の payouts をロックして取得
+    // --------------------------------
+    flushes::Entity::find()
+        .filter(flushes::Column::Id.eq(flush_id))
+        .filter(flushes::Column::ApxId.eq(apx_id))
+        .filter(flushes::Column::VdrId.eq(vdr_id))
+        .one(&tx)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch flush error: {}", e)))?
+        .ok_or_else(|| ApiError::new_system(StatusCode::NOT_FOUND, rterr::ERR_NOT_FOUND, "Flush not found."))?;
+    let payouts = payouts::Entity::find()
+        .filter(payouts::Column::FlushId.eq(flush_id))
+        .filter(payouts::Column::Net.gt(0))
+        .filter(payouts::Column::ExportedAt.is_null())
+        .order_by_asc(payouts::Column::UsrId)
+        .lock_exclusive()
+        .all(&tx)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Fetch payouts error: {}", e)))?;
+    if payouts.is_empty() {
+        return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, "No payouts to export."));
+    }
+    // --------------------------------
+    // 2. 個人をロックし、出金で支払済みの payout が含まれないか検証
+    // --------------------------------
+    let usr_ids: Vec<u32> = payouts.iter().map(|p| p.usr_id).collect();
+    usrs::Entity::find()
+        .filter(usrs::Column::Id.is_in(usr_ids.clone()))
+        .order_by_asc(usrs::Column::Id)
+        .lock_exclusive()
+        .all(&tx)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Lock usrs error: {}", e)))?;
+    let mut exporting: BTreeMap<u32, u64> = BTreeMap::new();
+    for p in &payouts {
+        *exporting.entry(p.usr_id).or_default() += p.net as u64;
+    }
+    for (&usr_id, &net) in exporting.iter() {
+        let balance = calc_withdrawable_for_rt(&tx, apx_id, vdr_id, usr_id).await?;
+        if balance.available() < net {
+            return Err(ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, format!("Payouts already withdrawn for usr: {}", usr_id)));
+        }
+    }
+    // --------------------------------
+    // 3. 振込先口座の復号とデータレコードの作成
+    // --------------------------------
+    let accounts = load_zengin_accounts_for_rt(&tx, crypto_key, apx_id, vdr_id, &usr_ids).await?;
+    let mut records = Vec::with_capacity(payouts.len());
+    for p in &payouts {
+        let account = accounts.get(&p.usr_id).ok_or_else(|| {
+            ApiError::new_system(StatusCode::CONFLICT, rterr::ERR_CONFLICT, format!("Bank account not registered for usr: {}", p.usr_id))
+        })?;
+        records.push(ZenginRecord { account: account.clone(), amount: p.net, customer_code: format!("{:010}", p.usr_id) });
+    }
+    let bytes = build_zengin(header, &records)
+        .map_err(|e| ApiError::new_system(StatusCode::BAD_REQUEST, rterr::ERR_INVALID_REQUEST, format!("Zengin format error: {}", e)))?;
+    log::debug!("<ZenginForRt> export_flush_zengin_for_rt: flush: {}, records: {}, bytes: {}", flush_id, records.len(), bytes.len());
+    // --------------------------------
+    // 4. 一時ファイルへ書き出してアップロード
+    // --------------------------------
+    let now = chrono::Local::now().naive_local();
+    let file_name = format!("zengin_{}_{}_{}.txt", vdr_id, flush_id, now.format("%Y%m%d%H%M%S"));
+    let tmp_path = std::env::temp_dir().join(&file_name);
+    let tmp_str = tmp_path.to_string_lossy().to_string();
+    tokio::fs::write(&tmp_path, &bytes)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_UNEXPECTED, format!("Write zengin file error: {}", e)))?;
+    let res = s3c.up(&tmp_str).await;
+    if let Err(e) = tokio::fs::remove_file(&tmp_path).await {
+        log::warn!("<ZenginForRt> export_flush_zengin_for_rt: Failed to remove temp file {}: {}", tmp_str, e);
+    }
+    let key = res.map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_UNEXPECTED, format!("Upload zengin file error: {}", e)))?;
+    // --------------------------------
+    // 5. 出力済みの記録
+    // アップロードに失敗した場合はロールバックされ、再度出力できる
+    // --------------------------------
+    let payout_ids: Vec<i32> = payouts.iter().map(|p| p.id).collect();
+    payouts::Entity::update_many()
+        .col_expr(payouts::Column::ExportedAt, Expr::value(now))
+        .filter(payouts::Column::Id.is_in(payout_ids))
+        .exec(&tx)
+        .await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Update payouts error: {}", e)))?;
+    tx.commit().await
+        .map_err(|e| ApiError::new_system(StatusCode::INTERNAL_SERVER_ERROR, rterr::ERR_DATABASE, format!("Commit error: {}", e)))?;
+    Ok(key)
+}
@@ -0,0 +1,389 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use chrono::{Duration, NaiveDateTime};
+
+/// 個人間のバッジ授与（from → to）
+#[derive(Debug, Clone, Copy)]
+pub struct AwardEdge {
+    pub usr_badge_id: u32,
+    pub from: u32,
+    pub to: u32,
+    /// 授与により付与されたポイント（point + extra）
+    pub points: u32,
+    pub at: NaiveDateTime,
+}
+
+/// 検出の閾値
+#[derive(Debug, Clone, Copy)]
+pub struct CollusionRule {
+    /// 相互授与とみなす、各方向の最小授与数
+    pub min_reciprocal: u32,
+    /// 密なグループとみなす最小人数
+    pub min_clique: usize,
+    /// 集中とみなす時間幅（時間）
+    pub burst_hours: u32,
+    /// 時間幅内に同じ授与者からこの数以上の授与があれば集中とみなす
+    pub burst_count: u32,
+}
+
+/// 互いにバッジを授与し合っている 2 人（a < b）
+#[derive(Debug, Clone)]
+pub struct ReciprocalPair {
+    pub a: u32,
+    pub b: u32,
+    pub a_to_b: u32,
+    pub b_to_a: u32,
+    /// 2 人の間の授与で付与されたポイントの合計
+    pub points: u64,
+}
+
+/// 全ての 2 人の間に授与（方向は問わない）があるグループ
+#[derive(Debug, Clone)]
+pub struct Clique {
+    /// メンバーの UsrID（昇順）
+    pub members: Vec<u32>,
+    /// メンバー間の授与数
+    pub awards: u32,
+    /// メンバー間の授与で付与されたポイントの合計
+    pub points: u64,
+}
+
+/// 同じ授与者による短時間の集中的な授与
+#[derive(Debug, Clone)]
+pub struct Burst {
+    pub usr_id: u32,
+    /// 時間幅内の最大授与数
+    pub count: u32,
+    /// 集中に含まれる最初と最後の授与日時
+    pub first_at: NaiveDateTime,
+    pub last_at: NaiveDateTime,
+}
+
+/// 個人ごとの検出結果
+#[derive(Debug, Clone, Default)]
+pub struct UsrRisk {
+    pub usr_id: u32,
+    /// 相互授与の相手の数
+    pub pairs: u32,
+    /// 所属するグループの数
+    pub cliques: u32,
+    /// 授与者として集中が検出された場合は 1
+    pub bursts: u32,
+    /// 受領した授与のうち疑わしいものの数
+    pub flagged_awards: u32,
+    /// 受領した授与のうち疑わしいもので付与されたポイントの合計
+    pub flagged_points: u64,
+}
+
+/// 検出結果
+#[derive(Debug, Clone, Default)]
+pub struct CollusionReport {
+    pub pairs: Vec<ReciprocalPair>,
+    pub cliques: Vec<Clique>,
+    pub bursts: Vec<Burst>,
+    /// 疑わしいポイントの多い順（同数なら UsrID の昇順）
+    pub usrs: Vec<UsrRisk>,
+    /// 疑わしい授与の UsrBadgeID（昇順）
+    /// 相互授与の 2 人の間、グループのメンバー間、集中に含まれる授与が対象となる
+    pub flagged: Vec<u32>,
+}
+
+/// 授与の有向グラフから相互授与・密なグループ・集中を検出する
+pub fn detect_collusion(edges: &[AwardEdge], rule: &CollusionRule) -> CollusionReport {
+    let mut flagged: BTreeSet<u32> = BTreeSet::new();
+    let mut risks: BTreeMap<u32, UsrRisk> = BTreeMap::new();
+    // --------------------------------
+    // 1. 相互授与
+    // --------------------------------
+    let mut directed: HashMap<(u32, u32), (u32, u64)> = HashMap::new();
+    for e in edges.iter().filter(|e| e.from != e.to) {
+        let entry = directed.entry((e.from, e.to)).or_default();
+        entry.0 += 1;
+        entry.1 += e.points as u64;
+    }
+    let mut pairs = Vec::new();
+    for (&(from, to), &(count, points)) in directed.iter() {
+        if from > to || count < rule.min_reciprocal {
+            continue;
+        }
+        if let Some(&(back, back_points)) = directed.get(&(to, from)) && back >= rule.min_reciprocal {
+            pairs.push(ReciprocalPair { a: from, b: to, a_to_b: count, b_to_a: back, points: points + back_points });
+        }
+    }
+    pairs.sort_by(|x, y| y.points.cmp(&x.points).then(x.a.cmp(&y.a)).then(x.b.cmp(&y.b)));
+    for p in pairs.iter() {
+        risk_of(&mut risks, p.a).pairs += 1;
+        risk_of(&mut risks, p.b).pairs += 1;
+    }
+    let paired: BTreeSet<(u32, u32)> = pairs.iter().map(|p| (p.a, p.b)).collect();
+    for e in edges.iter() {
+        if paired.contains(&(e.from.min(e.to), e.from.max(e.to))) {
+            flagged.insert(e.usr_badge_id);
+        }
+    }
+    // --------------------------------
+    // 2. 密なグループ（無向グラフの極大クリーク）
+    // --------------------------------
+    let mut adjacency: BTreeMap<u32, BTreeSet<u32>> = BTreeMap::new();
+    for &(from, to) in directed.keys() {
+        adjacency.entry(from).or_default().insert(to);
+        adjacency.entry(to).or_default().insert(from);
+    }
+    let mut found = Vec::new();
+    let candidates: BTreeSet<u32> = adjacency.keys().copied().collect();
+    bron_kerbosch(&adjacency, BTreeSet::new(), candidates, BTreeSet::new(), rule.min_clique.max(3), &mut found);
+    let mut cliques = Vec::new();
+    for members in found {
+        let inside: Vec<&AwardEdge> = edges.iter().filter(|e| e.from != e.to && members.contains(&e.from) && members.contains(&e.to)).collect();
+        for e in inside.iter() {
+            flagged.insert(e.usr_badge_id);
+        }
+        for m in members.iter() {
+            risk_of(&mut risks, *m).cliques += 1;
+        }
+        cliques.push(Clique {
+            members: members.into_iter().collect(),
+            awards: inside.len() as u32,
+            points: inside.iter().map(|e| e.points as u64).sum(),
+        });
+    }
+    cliques.sort_by(|x, y| y.members.len().cmp(&x.members.len()).then(y.points.cmp(&x.points)).then(x.members.cmp(&y.members)));
+    // --------------------------------
+    // 3. 集中（授与者ごとのスライディングウィンドウ）
+    // --------------------------------
+    let window = Duration::hours(rule.burst_hours as i64);
+    let mut by_giver: BTreeMap<u32, Vec<&AwardEdge>> = BTreeMap::new();
+    for e in edges.iter() {
+        by_giver.entry(e.from).or_default().push(e);
+    }
+    let mut bursts = Vec::new();
+    for (usr_id, mut given) in by_giver {
+        given.sort_by(|x, y| x.at.cmp(&y.at).then(x.usr_badge_id.cmp(&y.usr_badge_id)));
+        let mut start = 0;
+        let mut max_count = 0;
+        let mut in_burst: Vec<bool> = vec![false; given.len()];
+        for end in 0..given.len() {
+            while given[end].at - given[start].at > window {
+                start += 1;
+            }
+            let count = (end - start + 1) as u32;
+            if rule.burst_count > 0 && count >= rule.burst_count {
+                max_count = max_count.max(count);
+                in_burst[start..=end].iter_mut().for_each(|b| *b = true);
+            }
+        }
+        if max_count == 0 {
+            continue;
+        }
+        let hits: Vec<&&AwardEdge> = given.iter().zip(in_burst.iter()).filter(|(_, b)| **b).map(|(e, _)| e).collect();
+        for e in hits.iter() {
+            flagged.insert(e.usr_badge_id);
+        }
+        risk_of(&mut risks, usr_id).bursts += 1;
+        bursts.push(Burst { usr_id, count: max_count, first_at: hits[0].at, last_at: hits[hits.len() - 1].at });
+    }
+    bursts.sort_by(|x, y| y.count.cmp(&x.count).then(x.usr_id.cmp(&y.usr_id)));
+    // --------------------------------
+    // 4. 個人ごとの集計
+    // --------------------------------
+    for e in edges.iter().filter(|e| flagged.contains(&e.usr_badge_id)) {
+        let risk = risk_of(&mut risks, e.to);
+        risk.flagged_awards += 1;
+        risk.flagged_points += e.points as u64;
+    }
+    let mut usrs: Vec<UsrRisk> = risks.into_values().collect();
+    usrs.sort_by(|x, y| y.flagged_points.cmp(&x.flagged_points).then(x.usr_id.cmp(&y.usr_id)));
+    CollusionReport { pairs, cliques, bursts, usrs, flagged: flagged.into_iter().collect() }
+}
+
+fn risk_of(risks: &mut BTreeMap<u32, UsrRisk>, usr_id: u32) -> &mut UsrRisk {
+    risks.entry(usr_id).or_insert_with(|| UsrRisk { usr_id, ..Default::default() })
+}
+
+/// ピボット選択付きの Bron–Kerbosch 法で、min_size 人以上の極大クリークを列挙する
+fn bron_kerbosch(
+    adjacency: &BTreeMap<u32, BTreeSet<u32>>,
+    r: BTreeSet<u32>,
+    mut p: BTreeSet<u32>,
+    mut x: BTreeSet<u32>,
+    min_size: usize,
+    found: &mut Vec<BTreeSet<u32>>,
+) {
+    if p.is_empty() && x.is_empty() {
+        if r.len() >= min_size {
+            found.push(r);
+        }
+        return;
+    }
+    if r.len() + p.len() < min_size {
+        return;
+    }
+    let empty = BTreeSet::new();
+    let neighbors = |v: &u32| adjacency.get(v).unwrap_or(&empty);
+    let pivot = p.union(&x).max_by_key(|v| neighbors(v).intersection(&p).count()).copied();
+    let pivot_neighbors = pivot.map(|v| neighbors(&v).clone()).unwrap_or_default();
+    let targets: Vec<u32> = p.difference(&pivot_neighbors).copied().collect();
+    for v in targets {
+        let nv = neighbors(&v);
+        let mut next_r = r.clone();
+        next_r.insert(v);
+        bron_kerbosch(
+            adjacency,
+            next_r,
+            p.intersection(nv).copied().collect(),
+            x.intersection(nv).copied().collect(),
+            min_size,
+            found,
+        );
+        p.remove(&v);
+        x.insert(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(minutes: i64) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 10, 1).unwrap().and_hms_opt(0, 0, 0).unwrap() + Duration::minutes(minutes)
+    }
+
+    fn edge(usr_badge_id: u32, from: u32, to: u32, points: u32, minutes: i64) -> AwardEdge {
+        AwardEdge { usr_badge_id, from, to, points, at: at(minutes) }
+    }
+
+    fn rule(min_reciprocal: u32, min_clique: usize) -> CollusionRule {
+        CollusionRule { min_reciprocal, min_clique, burst_hours: 24, burst_count: 0 }
+    }
+
+    fn burst_rule(burst_hours: u32, burst_count: u32) -> CollusionRule {
+        CollusionRule { min_reciprocal: 1, min_clique: 3, burst_hours, burst_count }
+    }
+
+    #[test]
+    fn reciprocal_pair_is_detected() {
+        let edges = vec![edge(1, 1, 2, 10, 0), edge(2, 2, 1, 20, 10), edge(3, 1, 3, 5, 20)];
+        let report = detect_collusion(&edges, &rule(1, 3));
+        assert_eq!(report.pairs.len(), 1);
+        let p = &report.pairs[0];
+        assert_eq!((p.a, p.b, p.a_to_b, p.b_to_a, p.points), (1, 2, 1, 1, 30));
+        assert!(report.cliques.is_empty());
+        assert_eq!(report.flagged, vec![1, 2]);
+        // 受領した疑わしいポイントの多い順
+        assert_eq!(report.usrs[0].usr_id, 1);
+        assert_eq!((report.usrs[0].flagged_awards, report.usrs[0].flagged_points), (1, 20));
+        assert_eq!((report.usrs[1].usr_id, report.usrs[1].flagged_points), (2, 10));
+    }
+
+    #[test]
+    fn reciprocal_pair_needs_min_reciprocal_each_way() {
+        let edges = vec![edge(1, 1, 2, 10, 0), edge(2, 1, 2, 10, 10), edge(3, 2, 1, 10, 20)];
+        let report = detect_collusion(&edges, &rule(2, 3));
+        assert!(report.pairs.is_empty());
+        assert!(report.flagged.is_empty());
+        let report = detect_collusion(&edges, &rule(1, 3));
+        assert_eq!((report.pairs[0].a_to_b, report.pairs[0].b_to_a), (2, 1));
+        assert_eq!(report.flagged, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn self_awards_are_not_pairs() {
+        let edges = vec![edge(1, 1, 1, 10, 0), edge(2, 1, 1, 10, 10)];
+        let report = detect_collusion(&edges, &rule(1, 3));
+        assert!(report.pairs.is_empty());
+        assert!(report.flagged.is_empty());
+    }
+
+    #[test]
+    fn clique_ignores_direction() {
+        // 10 ↔ 11、11 → 12、12 → 10 は方向を問わず 3 人の全員がつながる
+        let edges = vec![
+            edge(1, 10, 11, 10, 0),
+            edge(2, 11, 10, 10, 10),
+            edge(3, 11, 12, 10, 20),
+            edge(4, 12, 10, 10, 30),
+            edge(5, 12, 13, 10, 40),
+        ];
+        let report = detect_collusion(&edges, &rule(1, 3));
+        assert_eq!(report.cliques.len(), 1);
+        let c = &report.cliques[0];
+        assert_eq!(c.members, vec![10, 11, 12]);
+        assert_eq!((c.awards, c.points), (4, 40));
+        assert_eq!(report.flagged, vec![1, 2, 3, 4]);
+        let risk_12 = report.usrs.iter().find(|r| r.usr_id == 12).unwrap();
+        assert_eq!((risk_12.pairs, risk_12.cliques), (0, 1));
+    }
+
+    #[test]
+    fn only_maximal_cliques_of_min_size_are_reported() {
+        // 1〜4 の完全グラフと、4・5・6 の三角形
+        let edges = vec![
+            edge(1, 1, 2, 10, 0),
+            edge(2, 1, 3, 10, 0),
+            edge(3, 1, 4, 10, 0),
+            edge(4, 2, 3, 10, 0),
+            edge(5, 2, 4, 10, 0),
+            edge(6, 3, 4, 10, 0),
+            edge(7, 4, 5, 10, 0),
+            edge(8, 5, 6, 10, 0),
+            edge(9, 6, 4, 10, 0),
+        ];
+        let report = detect_collusion(&edges, &rule(1, 3));
+        let members: Vec<Vec<u32>> = report.cliques.iter().map(|c| c.members.clone()).collect();
+        assert_eq!(members, vec![vec![1, 2, 3, 4], vec![4, 5, 6]]);
+        assert_eq!(report.cliques[0].awards, 6);
+        let report = detect_collusion(&edges, &rule(1, 4));
+        let members: Vec<Vec<u32>> = report.cliques.iter().map(|c| c.members.clone()).collect();
+        assert_eq!(members, vec![vec![1, 2, 3, 4]]);
+        assert_eq!(report.flagged, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn burst_counts_awards_within_the_window() {
+        // 授与者 1 から別々の受領者へ。最初の 3 件は 2 時間以内、残りは 30 時間後以降
+        let edges = vec![
+            edge(1, 1, 2, 10, 0),
+            edge(2, 1, 3, 10, 60),
+            edge(3, 1, 4, 10, 120),
+            edge(4, 1, 5, 10, 30 * 60),
+            edge(5, 1, 6, 10, 31 * 60),
+        ];
+        let report = detect_collusion(&edges, &burst_rule(24, 3));
+        assert_eq!(report.bursts.len(), 1);
+        let b = &report.bursts[0];
+        assert_eq!((b.usr_id, b.count), (1, 3));
+        assert_eq!((b.first_at, b.last_at), (at(0), at(120)));
+        assert_eq!(report.flagged, vec![1, 2, 3]);
+        assert_eq!(report.usrs.iter().find(|r| r.usr_id == 1).unwrap().bursts, 1);
+    }
+
+    #[test]
+    fn burst_window_slides_and_includes_its_edge() {
+        // ちょうど 24 時間の幅は含み、1 分でも超えると外れる
+        let edges = vec![edge(1, 1, 2, 10, 0), edge(2, 1, 3, 10, 12 * 60), edge(3, 1, 4, 10, 24 * 60)];
+        let report = detect_collusion(&edges, &burst_rule(24, 3));
+        assert_eq!(report.bursts[0].count, 3);
+        let edges = vec![edge(1, 1, 2, 10, 0), edge(2, 1, 3, 10, 12 * 60), edge(3, 1, 4, 10, 24 * 60 + 1)];
+        let report = detect_collusion(&edges, &burst_rule(24, 3));
+        assert!(report.bursts.is_empty());
+        // 窓が後ろへずれた先で 3 件に達した場合は、その 3 件だけが対象となる
+        let edges = vec![
+            edge(1, 1, 2, 10, 0),
+            edge(2, 1, 3, 10, 20 * 60),
+            edge(3, 1, 4, 10, 30 * 60),
+            edge(4, 1, 5, 10, 40 * 60),
+        ];
+        let report = detect_collusion(&edges, &burst_rule(24, 3));
+        assert_eq!(report.bursts[0].count, 3);
+        assert_eq!((report.bursts[0].first_at, report.bursts[0].last_at), (at(20 * 60), at(40 * 60)));
+        assert_eq!(report.flagged, vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn burst_count_zero_disables_bursts() {
+        let edges: Vec<AwardEdge> = (0..20).map(|i| edge(i + 1, 1, i + 2, 10, i as i64)).collect();
+        let report = detect_collusion(&edges, &burst_rule(24, 0));
+        assert!(report.bursts.is_empty());
+        assert!(report.flagged.is_empty());
+    }
+}
@@ -129,3 +129,14 @@ pub fn str_to_datetime(date_str: &str) -> anyhow::Result<ActiveValue<NaiveDateTi
 pub fn datetime_to_str(dt: NaiveDateTime) -> String {
     dt.format("%Y-%m-%dT%H:%M:%S").to_string()
 }
+
+/// YYYY-MM-DDThh:mm:ss 形式の文字列（JST想定）を NaiveDateTime に変換する
+pub fn str_to_naive_datetime(date_str: &str) -> anyhow::Result<NaiveDateTime> {
+    let format = "%Y-%m-%dT%H:%M:%S";
+    NaiveDateTime::parse_from_str(date_str, format).map_err(|e| anyhow::anyhow!("Failed to parse date string: {}", e))
+}
+
+/// NULL 許容の NaiveDateTime を YYYY-MM-DDThh:mm:ss 形式の文字列に変換する
+pub fn opt_datetime_to_str(dt: Option<NaiveDateTime>) -> Option<String> {
+    dt.map(datetime_to_str)
+}
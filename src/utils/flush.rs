@@ -0,0 +1,198 @@
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use crate::utils::withholding::WithholdingRule;
+
+/// 分配対象となる個人（分配時点のポイント残高）
+#[derive(Debug, Clone, Copy)]
+pub struct Holder {
+    pub usr_id: u32,
+    pub points: u32,
+}
+
+/// 個人ごとの分配結果
+#[derive(Debug, Clone)]
+pub struct PayoutLine {
+    pub usr_id: u32,
+    pub points: u32,
+    /// 取り分割合（points / 総ポイント、小数第 5 位で四捨五入）
+    pub share: Decimal,
+    /// 分配金額（端数調整後、源泉徴収前の総額）
+    pub amount: u32,
+    /// 端数調整で加算された額（0 または 1）
+    pub adjust: u32,
+    /// 源泉徴収額
+    pub withheld: u32,
+    /// 差引支払額（amount - withheld）
+    pub net: u32,
+}
+
+/// 分配計画
+/// 実際の分配（flush）とプレビューは必ずこの計算結果を用いる
+#[derive(Debug, Clone)]
+pub struct FlushPlan {
+    /// 分配元の金額（多くの場合、その時点の pools.remain）
+    pub total: u32,
+    /// 事務費用割引率（VDR の flush_fee_rate）
+    pub flush_fee_rate: Decimal,
+    /// 事務費用として控除される額
+    pub fee: u32,
+    /// 個人へ分配される額（total - fee）
+    pub distributable: u32,
+    /// 分配対象の総ポイント
+    pub points: u32,
+    /// 比例按分の切り捨てで生じ、端数調整で配られた額
+    pub remainder: u32,
+    /// 源泉徴収額の合計
+    pub withheld: u32,
+    pub lines: Vec<PayoutLine>,
+}
+
+/// 事務費用を算出する（1 円未満は切り捨て）
+pub fn calc_flush_fee(total: u32, rate: Decimal) -> u32 {
+    if rate <= Decimal::ZERO {
+        return 0;
+    }
+    (Decimal::from(total) * rate).floor().to_u32().unwrap_or(total).min(total)
+}
+
+/// 分配計画を作成する
+/// 各個人へは floor(distributable × points / 総ポイント) を配り、
+/// 切り捨てで余った額は最大剰余法（剰余の大きい順、同率ならポイントの多い順、さらに UsrID の昇順）で 1 円ずつ配るため、
+/// 分配金額の合計は常に distributable と一致する
+/// 源泉徴収は端数調整後の個人ごとの分配金額に対して withholding を適用する
+pub fn plan_flush(total: u32, flush_fee_rate: Decimal, holders: &[Holder], withholding: &WithholdingRule) -> FlushPlan {
+    let fee = calc_flush_fee(total, flush_fee_rate);
+    let distributable = total - fee;
+    let holders: Vec<Holder> = holders.iter().copied().filter(|h| h.points > 0).collect();
+    let points: u64 = holders.iter().map(|h| h.points as u64).sum();
+    if points == 0 || distributable == 0 {
+        let lines = holders
+            .iter()
+            .map(|h| PayoutLine { usr_id: h.usr_id, points: h.points, share: share_of(h.points as u64, points), amount: 0, adjust: 0, withheld: 0, net: 0 })
+            .collect();
+        return FlushPlan { total, flush_fee_rate, fee, distributable, points: u32::try_from(points).unwrap_or(u32::MAX), remainder: 0, withheld: 0, lines };
+    }
+    // --------------------------------
+    // 1. 比例按分（切り捨て）と剰余の算出
+    // --------------------------------
+    let mut rows: Vec<(Holder, u32, u64)> = holders
+        .iter()
+        .map(|h| {
+            let num = distributable as u64 * h.points as u64;
+            (*h, (num / points) as u32, num % points)
+        })
+        .collect();
+    let floored: u64 = rows.iter().map(|r| r.1 as u64).sum();
+    let remainder = (distributable as u64 - floored) as u32;
+    // --------------------------------
+    // 2. 最大剰余法による端数調整
+    // --------------------------------
+    rows.sort_by(|a, b| {
+        b.2.cmp(&a.2)
+            .then(b.0.points.cmp(&a.0.points))
+            .then(a.0.usr_id.cmp(&b.0.usr_id))
+    });
+    let mut lines: Vec<PayoutLine> = rows
+        .into_iter()
+        .enumerate()
+        .map(|(i, (h, amount, _))| {
+            let adjust = if (i as u32) < remainder { 1 } else { 0 };
+            let amount = amount + adjust;
+            let withheld = withholding.calc(amount);
+            PayoutLine { usr_id: h.usr_id, points: h.points, share: share_of(h.points as u64, points), amount, adjust, withheld, net: amount - withheld }
+        })
+        .collect();
+    lines.sort_by(|a, b| a.usr_id.cmp(&b.usr_id));
+    let withheld = lines.iter().map(|l| l.withheld).sum();
+    FlushPlan { total, flush_fee_rate, fee, distributable, points: u32::try_from(points).unwrap_or(u32::MAX), remainder, withheld, lines }
+}
+
+/// 取り分割合を小数第 5 位までで算出する
+fn share_of(points: u64, total: u64) -> Decimal {
+    if total == 0 {
+        return Decimal::ZERO;
+    }
+    (Decimal::from(points) / Decimal::from(total)).round_dp(5)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn holders(points: &[u32]) -> Vec<Holder> {
+        points.iter().enumerate().map(|(i, p)| Holder { usr_id: i as u32 + 1, points: *p }).collect()
+    }
+
+    #[test]
+    fn amounts_sum_to_distributable() {
+        let no_tax = WithholdingRule::default();
+        for (total, rate, points) in [
+            (10_000u32, dec("0"), vec![1u32, 1, 1]),
+            (10_001, dec("0.03"), vec![7, 13, 29, 101]),
+            (1, dec("0"), vec![5, 5]),
+            (999_999, dec("0.1"), vec![3, 3, 3, 3, 3, 3, 3]),
+        ] {
+            let plan = plan_flush(total, rate, &holders(&points), &no_tax);
+            assert_eq!(plan.fee + plan.distributable, total);
+            assert_eq!(plan.lines.iter().map(|l| l.amount).sum::<u32>(), plan.distributable);
+            assert_eq!(plan.lines.iter().map(|l| l.adjust).sum::<u32>(), plan.remainder);
+            assert!(plan.lines.iter().all(|l| l.adjust <= 1));
+        }
+    }
+
+    #[test]
+    fn remainder_goes_to_largest_remainders() {
+        // 100 を 1:1:1 で分けると 33 ずつで 1 余り、同率のため UsrID の昇順で配られる
+        let plan = plan_flush(100, dec("0"), &holders(&[1, 1, 1]), &WithholdingRule::default());
+        let amounts: Vec<u32> = plan.lines.iter().map(|l| l.amount).collect();
+        assert_eq!(amounts, vec![34, 33, 33]);
+        assert_eq!(plan.remainder, 1);
+    }
+
+    #[test]
+    fn fee_is_floored() {
+        let plan = plan_flush(1_005, dec("0.01"), &holders(&[1]), &WithholdingRule::default());
+        assert_eq!(plan.fee, 10);
+        assert_eq!(plan.distributable, 995);
+    }
+
+    #[test]
+    fn withholding_is_applied_per_line() {
+        let rule = WithholdingRule { threshold: 0, rate: dec("0.2042"), reduced_rate: dec("0.1021"), reduced_limit: 1_000_000 };
+        let plan = plan_flush(3_000_000, dec("0"), &holders(&[1, 2]), &rule);
+        let line1 = plan.lines.iter().find(|l| l.usr_id == 1).unwrap();
+        let line2 = plan.lines.iter().find(|l| l.usr_id == 2).unwrap();
+        assert_eq!(line1.amount, 1_000_000);
+        assert_eq!(line2.amount, 2_000_000);
+        // 1,000,000 × 0.1021
+        assert_eq!(line1.withheld, 102_100);
+        // 1,000,000 × 0.1021 + 1,000,000 × 0.2042
+        assert_eq!(line2.withheld, 306_300);
+        for l in plan.lines.iter() {
+            assert_eq!(l.withheld, rule.calc(l.amount));
+            assert_eq!(l.net, l.amount - l.withheld);
+        }
+        // 合計額に一括で適用した場合（3,000,000 → 510,500）とは異なる
+        assert_eq!(plan.withheld, 408_400);
+    }
+
+    #[test]
+    fn withholding_threshold_is_per_line() {
+        let rule = WithholdingRule { threshold: 1_000, rate: dec("0.1"), reduced_rate: dec("0"), reduced_limit: 0 };
+        let plan = plan_flush(1_500, dec("0"), &holders(&[1, 2]), &rule);
+        let amounts: Vec<(u32, u32)> = plan.lines.iter().map(|l| (l.amount, l.withheld)).collect();
+        assert_eq!(amounts, vec![(500, 0), (1_000, 100)]);
+    }
+
+    #[test]
+    fn zero_distributable_pays_nothing() {
+        let plan = plan_flush(0, dec("0"), &holders(&[1, 2]), &WithholdingRule::default());
+        assert_eq!(plan.distributable, 0);
+        assert!(plan.lines.iter().all(|l| l.amount == 0 && l.net == 0));
+    }
+}
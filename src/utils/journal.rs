@@ -0,0 +1,126 @@
+use anyhow::{anyhow, Result};
+use chrono::NaiveDateTime;
+use std::collections::BTreeMap;
+
+/// 勘定科目
+pub const ACCT_CASH: &str = "普通預金";
+pub const ACCT_POOL: &str = "預り金（現金プール）";
+pub const ACCT_FEE_INCOME: &str = "受取手数料";
+pub const ACCT_PAYOUT_CLEARING: &str = "未払分配金";
+pub const ACCT_WITHHOLDING: &str = "預り金（源泉所得税）";
+pub const ACCT_PAYABLE: &str = "未払金（個人）";
+
+/// 仕訳の明細行（借方・貸方のいずれか一方のみ 0 以外）
+#[derive(Debug, Clone)]
+pub struct JournalLine {
+    pub account: &'static str,
+    pub debit: u64,
+    pub credit: u64,
+}
+
+impl JournalLine {
+    pub fn debit(account: &'static str, amount: u64) -> Self {
+        Self { account, debit: amount, credit: 0 }
+    }
+    pub fn credit(account: &'static str, amount: u64) -> Self {
+        Self { account, debit: 0, credit: amount }
+    }
+}
+
+/// 仕訳（1 件の資金移動）
+#[derive(Debug, Clone)]
+pub struct JournalEntry {
+    pub date: NaiveDateTime,
+    /// 仕訳の元となったテーブル（payments / flushes / payouts / withdrawals）
+    /// 全銀ファイルで振り込んだ payouts（exported_at）の支払は exports とし、source_id は PayoutID とする
+    pub source: &'static str,
+    pub source_id: u32,
+    pub description: String,
+    pub lines: Vec<JournalLine>,
+}
+
+impl JournalEntry {
+    /// 金額が 0 の明細行を除いて仕訳を作成する
+    pub fn new(date: NaiveDateTime, source: &'static str, source_id: u32, description: String, lines: Vec<JournalLine>) -> Self {
+        let lines = lines.into_iter().filter(|l| l.debit > 0 || l.credit > 0).collect();
+        Self { date, source, source_id, description, lines }
+    }
+    pub fn debit_total(&self) -> u64 {
+        self.lines.iter().map(|l| l.debit).sum()
+    }
+    pub fn credit_total(&self) -> u64 {
+        self.lines.iter().map(|l| l.credit).sum()
+    }
+}
+
+/// 期間の仕訳が貸借一致しているか検証する
+/// 全ての仕訳で借方合計と貸方合計が一致し、かつ clearing に指定した仮勘定の期間残高が 0 であること
+pub fn check_balanced(entries: &[JournalEntry], clearing: &[&str]) -> Result<()> {
+    let mut balances: BTreeMap<&str, i128> = BTreeMap::new();
+    for e in entries {
+        if e.debit_total() != e.credit_total() {
+            return Err(anyhow!("Unbalanced entry: {}:{} (debit: {}, credit: {})", e.source, e.source_id, e.debit_total(), e.credit_total()));
+        }
+        for l in &e.lines {
+            *balances.entry(l.account).or_default() += l.debit as i128 - l.credit as i128;
+        }
+    }
+    for account in clearing {
+        let balance = balances.get(account).copied().unwrap_or(0);
+        if balance != 0 {
+            return Err(anyhow!("Clearing account {} does not net to zero: {}", account, balance));
+        }
+    }
+    Ok(())
+}
+
+/// 同じ元データに対する計上と精算の仕訳で、仮勘定が相殺されているか検証する
+/// source の仕訳と、同じ source_id を持つ settle の仕訳が共に期間内にある場合、その 2 件での account の残高が 0 であること
+/// 精算の仕訳のみが期間内にある場合（計上が前の期間）は検証しない
+pub fn check_settled(entries: &[JournalEntry], account: &str, source: &str, settle: &str) -> Result<()> {
+    let balance_of = |e: &JournalEntry| -> i128 {
+        e.lines.iter().filter(|l| l.account == account).map(|l| l.debit as i128 - l.credit as i128).sum()
+    };
+    let recorded: BTreeMap<u32, i128> = entries.iter().filter(|e| e.source == source).map(|e| (e.source_id, balance_of(e))).collect();
+    for e in entries.iter().filter(|e| e.source == settle) {
+        if let Some(balance) = recorded.get(&e.source_id) {
+            let balance = balance + balance_of(e);
+            if balance != 0 {
+                return Err(anyhow!("{} of {}:{} is not settled by {}: {}", account, source, e.source_id, settle, balance));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 仕訳を CSV（UTF-8、ヘッダー行付き、1 明細 1 行）へ変換する
+/// 列: entry_no, date, source, source_id, account, debit, credit, description
+pub fn to_csv(entries: &[JournalEntry]) -> String {
+    let mut out = String::from("entry_no,date,source,source_id,account,debit,credit,description\r\n");
+    for (i, e) in entries.iter().enumerate() {
+        let date = e.date.format("%Y-%m-%d %H:%M:%S").to_string();
+        for l in &e.lines {
+            out.push_str(&format!(
+                "{},{},{},{},{},{},{},{}\r\n",
+                i + 1,
+                date,
+                e.source,
+                e.source_id,
+                csv_field(l.account),
+                l.debit,
+                l.credit,
+                csv_field(&e.description),
+            ));
+        }
+    }
+    out
+}
+
+/// カンマ・ダブルクォート・改行を含む場合はダブルクォートで囲む
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
@@ -6,4 +6,11 @@ pub mod cors;
 pub use cors::cors_layer;
 pub mod bd;
 pub mod jwt;
-pub mod crypto;
\ No newline at end of file
+pub mod crypto;
+pub mod ranking;
+pub mod flush;
+pub mod zengin;
+pub mod withholding;
+pub mod journal;
+pub mod point_expiry;
+pub mod collusion;
\ No newline at end of file
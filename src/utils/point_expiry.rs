@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+use chrono::{Months, NaiveDateTime};
+
+/// 個人への付与（バッジ授与または加算の手動調整）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointCredit {
+    /// 付与日時
+    pub granted_at: NaiveDateTime,
+    pub points: u32,
+    /// 付与元のバッジ授与ID（手動調整は 0）
+    pub usr_badge_id: u32,
+}
+
+/// 保有ポイントのうち、1 件の付与の残り
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointLot {
+    /// 付与日時
+    pub granted_at: NaiveDateTime,
+    /// 失効日時（付与日時 + 失効までの月数）
+    pub expires_at: NaiveDateTime,
+    pub points: u32,
+    /// 付与元のバッジ授与ID（手動調整は 0）
+    pub usr_badge_id: u32,
+}
+
+/// 付与日時から失効日時を算出する（月末日は翌月以降の末日に丸める）
+pub fn expires_at(granted_at: NaiveDateTime, months: u32) -> NaiveDateTime {
+    granted_at.checked_add_months(Months::new(months)).unwrap_or(NaiveDateTime::MAX)
+}
+
+/// 保有ポイントを付与の履歴（credits）へ割り当て、付与ごとの残りを返す
+/// 分配・減算の調整・失効は古い付与から順に消費されたものとみなし（先入先出）、保有ポイントは新しい付与から順に割り当てる
+/// 付与の履歴で説明できない保有ポイント（履歴より保有ポイントが多い場合の差分）は失効の対象としない
+/// 戻り値は失効日時の昇順
+pub fn allocate_lots(balance: u32, credits: &[PointCredit], months: u32) -> Vec<PointLot> {
+    let mut sorted: Vec<&PointCredit> = credits.iter().filter(|c| c.points > 0).collect();
+    sorted.sort_by(|a, b| b.granted_at.cmp(&a.granted_at).then(b.usr_badge_id.cmp(&a.usr_badge_id)));
+    let mut remaining = balance;
+    let mut lots = Vec::new();
+    for c in sorted {
+        if remaining == 0 {
+            break;
+        }
+        let take = remaining.min(c.points);
+        remaining -= take;
+        lots.push(PointLot { granted_at: c.granted_at, expires_at: expires_at(c.granted_at, months), points: take, usr_badge_id: c.usr_badge_id });
+    }
+    lots.reverse();
+    lots
+}
+
+/// 指定日時までに失効日時を迎える残りの合計
+pub fn expiring_by(lots: &[PointLot], at: NaiveDateTime) -> u32 {
+    lots.iter().filter(|l| l.expires_at <= at).map(|l| l.points).sum()
+}
+
+/// 指定日時までに失効日時を迎える残りを、付与元のバッジ授与ごとに合計する（手動調整の分は 0 にまとめる）
+pub fn expiring_by_award(lots: &[PointLot], at: NaiveDateTime) -> BTreeMap<u32, u32> {
+    let mut expiring: BTreeMap<u32, u32> = BTreeMap::new();
+    for l in lots.iter().filter(|l| l.expires_at <= at) {
+        *expiring.entry(l.usr_badge_id).or_default() += l.points;
+    }
+    expiring
+}
+
+/// 授与の取り消しで戻すポイント数
+/// 授与で付与したポイントから、その授与について失効済みの分を除いた残りとし、保有ポイントを上限とする
+pub fn revocable_points(awarded: u32, expired: u32, balance: u32) -> u32 {
+    awarded.saturating_sub(expired).min(balance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(y: i32, m: u32, d: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(0, 0, 0).unwrap()
+    }
+
+    fn credit(granted_at: NaiveDateTime, points: u32, usr_badge_id: u32) -> PointCredit {
+        PointCredit { granted_at, points, usr_badge_id }
+    }
+
+    #[test]
+    fn expires_at_clamps_to_month_end() {
+        assert_eq!(expires_at(at(2026, 1, 31), 1), at(2026, 2, 28));
+        assert_eq!(expires_at(at(2026, 3, 15), 12), at(2027, 3, 15));
+    }
+
+    #[test]
+    fn balance_is_allocated_to_newest_credits_first() {
+        let credits = vec![credit(at(2026, 1, 1), 30, 1), credit(at(2026, 6, 1), 20, 2), credit(at(2026, 3, 1), 10, 0)];
+        let lots = allocate_lots(35, &credits, 12);
+        let got: Vec<(u32, u32)> = lots.iter().map(|l| (l.usr_badge_id, l.points)).collect();
+        assert_eq!(got, vec![(1, 5), (0, 10), (2, 20)]);
+        assert_eq!(lots[0].expires_at, at(2027, 1, 1));
+        assert_eq!(expiring_by(&lots, at(2027, 3, 1)), 15);
+    }
+
+    #[test]
+    fn expiring_points_are_grouped_by_award() {
+        let credits = vec![credit(at(2026, 1, 1), 30, 1), credit(at(2026, 1, 2), 5, 0), credit(at(2026, 8, 1), 20, 2)];
+        let lots = allocate_lots(55, &credits, 6);
+        let expiring = expiring_by_award(&lots, at(2026, 7, 2));
+        assert_eq!(expiring.into_iter().collect::<Vec<_>>(), vec![(0, 5), (1, 30)]);
+    }
+
+    #[test]
+    fn revoke_after_expiry_does_not_deduct_expired_points_again() {
+        // 授与 1（1 月、30 ポイント）と授与 2（8 月、20 ポイント）。失効までは 6 か月
+        let credits = vec![credit(at(2026, 1, 1), 30, 1), credit(at(2026, 8, 1), 20, 2)];
+        let mut balance = 50;
+        // 7 月に授与 1 の 30 ポイントが失効する
+        let lots = allocate_lots(balance, &credits, 6);
+        let expired = expiring_by_award(&lots, at(2026, 7, 1));
+        assert_eq!(expired.get(&1).copied(), Some(30));
+        balance -= expired.values().sum::<u32>();
+        assert_eq!(balance, 20);
+        // 授与 1 を取り消しても、失効済みの分は戻さない（授与 2 の 20 ポイントは残る）
+        let revoked = revocable_points(30, expired.get(&1).copied().unwrap_or(0), balance);
+        assert_eq!(revoked, 0);
+        // 失効していない授与 2 は全て戻す
+        assert_eq!(revocable_points(20, expired.get(&2).copied().unwrap_or(0), balance), 20);
+    }
+
+    #[test]
+    fn revoke_after_partial_expiry_returns_the_unexpired_remainder() {
+        // 保有ポイントが授与 1 の一部にしか割り当てられない場合、割り当てられた分だけが失効する
+        let credits = vec![credit(at(2026, 1, 1), 30, 1), credit(at(2026, 8, 1), 20, 2)];
+        let lots = allocate_lots(30, &credits, 6);
+        let expired = expiring_by_award(&lots, at(2026, 7, 1));
+        assert_eq!(expired.get(&1).copied(), Some(10));
+        assert_eq!(revocable_points(30, 10, 20), 20);
+        // 保有ポイントを上限とする
+        assert_eq!(revocable_points(30, 10, 5), 5);
+        assert_eq!(revocable_points(30, 40, 20), 0);
+    }
+}
@@ -0,0 +1,193 @@
+use rust_decimal::Decimal;
+use crate::enums::matchreason::MatchReason;
+
+/// ランキング対象となる個人
+#[derive(Debug, Clone)]
+pub struct Candidate {
+    pub usr_id: u32,
+    /// 個人が保有するバッジ数（usr_badges の件数）
+    pub badge_count: u32,
+}
+
+/// スコアリングの前提となる値
+#[derive(Debug, Clone, Copy)]
+pub struct RankingContext {
+    /// 求人の max_badges（0 の場合は上限なし）
+    pub max_badges: u32,
+    /// VDR の total_badges（VDR 内で授与されたバッジのうち、取り消されていないものの総数）
+    pub total_badges: u32,
+}
+
+/// ランキング結果
+#[derive(Debug, Clone)]
+pub struct Ranked {
+    pub usr_id: u32,
+    pub badge_count: u32,
+    pub score: Decimal,
+    pub reason: MatchReason,
+}
+
+/// 候補者に優先度スコアを付与する戦略
+/// スコアは matches.priority_score (Decimal 10,4) に収まる 0〜1 の値とする
+pub trait RankingStrategy {
+    fn reason(&self) -> MatchReason;
+    fn score(&self, c: &Candidate, ctx: &RankingContext) -> Decimal;
+}
+
+/// バッジ保有数を 0〜1 に正規化する
+/// max_badges が設定されていればそれを上限とし、未設定なら VDR の total_badges に対する割合とする
+fn badge_ratio(c: &Candidate, ctx: &RankingContext) -> Decimal {
+    let (num, den) = if ctx.max_badges > 0 {
+        (c.badge_count.min(ctx.max_badges), ctx.max_badges)
+    } else {
+        (c.badge_count.min(ctx.total_badges), ctx.total_badges)
+    };
+    if den == 0 {
+        return Decimal::ZERO;
+    }
+    (Decimal::from(num) / Decimal::from(den)).round_dp(4)
+}
+
+/// バッジを多く保有する個人を優先する
+pub struct HighBadgeStrategy;
+
+impl RankingStrategy for HighBadgeStrategy {
+    fn reason(&self) -> MatchReason {
+        MatchReason::HighBadge
+    }
+    fn score(&self, c: &Candidate, ctx: &RankingContext) -> Decimal {
+        badge_ratio(c, ctx)
+    }
+}
+
+/// バッジ数に依らず無作為に並べる
+pub struct RandomStrategy;
+
+impl RankingStrategy for RandomStrategy {
+    fn reason(&self) -> MatchReason {
+        MatchReason::Random
+    }
+    fn score(&self, _c: &Candidate, _ctx: &RankingContext) -> Decimal {
+        Decimal::new(rand::random_range(0..10000i64), 4)
+    }
+}
+
+/// バッジの少ない個人に機会を与えるため、保有数の少ない順に優先する
+pub struct ExploreStrategy;
+
+impl RankingStrategy for ExploreStrategy {
+    fn reason(&self) -> MatchReason {
+        MatchReason::Explore
+    }
+    fn score(&self, c: &Candidate, ctx: &RankingContext) -> Decimal {
+        Decimal::ONE - badge_ratio(c, ctx)
+    }
+}
+
+/// 理由コードに対応する戦略を返す（Manual は戦略を持たない）
+pub fn strategy_for(reason: MatchReason) -> Option<Box<dyn RankingStrategy + Send + Sync>> {
+    match reason {
+        MatchReason::Manual => None,
+        MatchReason::HighBadge => Some(Box::new(HighBadgeStrategy)),
+        MatchReason::Random => Some(Box::new(RandomStrategy)),
+        MatchReason::Explore => Some(Box::new(ExploreStrategy)),
+    }
+}
+
+/// 候補者をスコアの高い順に並べる
+/// 同点の場合はバッジ保有数の多い順、さらに UsrID の昇順とする
+pub fn rank(strategy: &dyn RankingStrategy, candidates: Vec<Candidate>, ctx: &RankingContext) -> Vec<Ranked> {
+    let mut ranked: Vec<Ranked> = candidates
+        .into_iter()
+        .map(|c| Ranked {
+            usr_id: c.usr_id,
+            badge_count: c.badge_count,
+            score: strategy.score(&c, ctx),
+            reason: strategy.reason(),
+        })
+        .collect();
+    ranked.sort_by(|a, b| {
+        b.score.cmp(&a.score)
+            .then(b.badge_count.cmp(&a.badge_count))
+            .then(a.usr_id.cmp(&b.usr_id))
+    });
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn candidate(usr_id: u32, badge_count: u32) -> Candidate {
+        Candidate { usr_id, badge_count }
+    }
+
+    fn ctx(max_badges: u32, total_badges: u32) -> RankingContext {
+        RankingContext { max_badges, total_badges }
+    }
+
+    #[test]
+    fn ratio_uses_max_badges_when_set() {
+        assert_eq!(badge_ratio(&candidate(1, 3), &ctx(4, 100)), dec("0.75"));
+        assert_eq!(badge_ratio(&candidate(1, 1), &ctx(3, 100)), dec("0.3333"));
+    }
+
+    #[test]
+    fn ratio_falls_back_to_total_badges_without_max_badges() {
+        assert_eq!(badge_ratio(&candidate(1, 5), &ctx(0, 20)), dec("0.25"));
+    }
+
+    #[test]
+    fn ratio_is_zero_when_denominator_is_zero() {
+        assert_eq!(badge_ratio(&candidate(1, 5), &ctx(0, 0)), Decimal::ZERO);
+        assert_eq!(ExploreStrategy.score(&candidate(1, 5), &ctx(0, 0)), Decimal::ONE);
+    }
+
+    #[test]
+    fn ratio_is_clamped_to_one() {
+        assert_eq!(badge_ratio(&candidate(1, 9), &ctx(4, 100)), Decimal::ONE);
+        assert_eq!(badge_ratio(&candidate(1, 30), &ctx(0, 20)), Decimal::ONE);
+        assert_eq!(ExploreStrategy.score(&candidate(1, 9), &ctx(4, 100)), Decimal::ZERO);
+    }
+
+    #[test]
+    fn random_score_is_within_range() {
+        for _ in 0..100 {
+            let score = RandomStrategy.score(&candidate(1, 0), &ctx(0, 0));
+            assert!(score >= Decimal::ZERO && score < Decimal::ONE);
+        }
+    }
+
+    #[test]
+    fn high_badge_ranks_by_score_then_badges_then_usr_id() {
+        // max_badges = 2 で 2 件以上は同点となり、保有数の多い順、さらに UsrID の昇順
+        let candidates = vec![candidate(5, 1), candidate(4, 2), candidate(3, 3), candidate(2, 2), candidate(1, 0)];
+        let ranked = rank(&HighBadgeStrategy, candidates, &ctx(2, 100));
+        let order: Vec<u32> = ranked.iter().map(|r| r.usr_id).collect();
+        assert_eq!(order, vec![3, 2, 4, 5, 1]);
+        assert_eq!(ranked[0].score, Decimal::ONE);
+        assert_eq!(ranked[3].score, dec("0.5"));
+        assert!(ranked.iter().all(|r| r.reason == MatchReason::HighBadge));
+    }
+
+    #[test]
+    fn explore_ranks_fewest_badges_first() {
+        let candidates = vec![candidate(1, 4), candidate(2, 0), candidate(3, 2)];
+        let ranked = rank(&ExploreStrategy, candidates, &ctx(0, 8));
+        let order: Vec<u32> = ranked.iter().map(|r| r.usr_id).collect();
+        assert_eq!(order, vec![2, 3, 1]);
+        assert_eq!(ranked[0].score, Decimal::ONE);
+        assert_eq!(ranked[2].score, dec("0.5"));
+    }
+
+    #[test]
+    fn manual_has_no_strategy() {
+        assert!(strategy_for(MatchReason::Manual).is_none());
+        assert_eq!(strategy_for(MatchReason::Explore).unwrap().reason(), MatchReason::Explore);
+    }
+}
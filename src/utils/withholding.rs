@@ -0,0 +1,42 @@
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+
+/// 源泉徴収ルール（VDR ごとの設定）
+/// 例: 報酬・料金の場合は reduced_rate = 0.1021, reduced_limit = 1,000,000, rate = 0.2042
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WithholdingRule {
+    /// 源泉徴収の対象となる 1 回の支払金額の下限（これ未満の支払は源泉徴収しない。0 は全ての支払が対象）
+    pub threshold: u32,
+    /// 源泉徴収税率（軽減税率の区分を超える部分に適用）
+    pub rate: Decimal,
+    /// 軽減税率（reduced_limit までの部分に適用）
+    pub reduced_rate: Decimal,
+    /// 軽減税率を適用する区分の上限額（0 は軽減税率の区分なし）
+    pub reduced_limit: u32,
+}
+
+impl WithholdingRule {
+    /// 税率が共に 0 以下の場合は源泉徴収しない
+    pub fn is_enabled(&self) -> bool {
+        self.rate > Decimal::ZERO || self.reduced_rate > Decimal::ZERO
+    }
+
+    /// 1 回の支払金額（gross）に対する源泉徴収額を算出する
+    /// 軽減税率の区分と超過部分のそれぞれで 1 円未満を切り捨てた上で合算し、gross を超えることはない
+    pub fn calc(&self, gross: u32) -> u32 {
+        if !self.is_enabled() || gross == 0 || gross < self.threshold {
+            return 0;
+        }
+        let reduced_part = gross.min(self.reduced_limit);
+        let excess_part = gross - reduced_part;
+        let withheld = floor_mul(reduced_part, self.reduced_rate) + floor_mul(excess_part, self.rate);
+        u32::try_from(withheld).unwrap_or(gross).min(gross)
+    }
+}
+
+fn floor_mul(amount: u32, rate: Decimal) -> u64 {
+    if amount == 0 || rate <= Decimal::ZERO {
+        return 0;
+    }
+    (Decimal::from(amount) * rate).floor().to_u64().unwrap_or(0)
+}
@@ -0,0 +1,367 @@
+use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
+use encoding_rs::SHIFT_JIS;
+
+/// 全銀フォーマットの 1 レコードのバイト長（改行を除く）
+pub const RECORD_LEN: usize = 120;
+
+/// 総合振込の種別コード
+const KIND_TRANSFER: &str = "21";
+
+/// 振込元・振込先の口座情報
+/// 名称は全て、全銀協規定の半角カナ文字へ変換して出力される
+#[derive(Debug, Clone)]
+pub struct ZenginAccount {
+    /// 金融機関コード（4 桁）
+    pub bank_code: String,
+    pub bank_name: String,
+    /// 支店コード（3 桁）
+    pub branch_code: String,
+    pub branch_name: String,
+    /// 預金種目（1:普通, 2:当座, 4:貯蓄, 9:その他）
+    pub account_type: u8,
+    /// 口座番号（7 桁以内）
+    pub account_number: String,
+    /// 口座名義（カナ）
+    pub holder: String,
+}
+
+/// ヘッダーレコードの内容
+#[derive(Debug, Clone)]
+pub struct ZenginHeader {
+    /// 振込依頼人コード（10 桁、金融機関との契約時に払い出される）
+    pub consignor_code: String,
+    /// 振込依頼人名（カナ）
+    pub consignor_name: String,
+    /// 取組日（振込指定日）
+    pub transfer_date: NaiveDate,
+    /// 仕向口座（振込元）
+    pub account: ZenginAccount,
+}
+
+/// データレコードの内容
+#[derive(Debug, Clone)]
+pub struct ZenginRecord {
+    /// 被仕向口座（振込先）
+    pub account: ZenginAccount,
+    pub amount: u32,
+    /// 顧客コード1（突合用。UsrID などを入れる）
+    pub customer_code: String,
+}
+
+/// 全銀フォーマット（総合振込）のファイルを作成する
+/// ヘッダー・データ・トレーラー・エンドの各レコードを 120 バイト固定長 + CRLF で出力し、Shift_JIS でエンコードする
+pub fn build_zengin(header: &ZenginHeader, records: &[ZenginRecord]) -> Result<Vec<u8>> {
+    if records.len() > 999_999 {
+        return Err(anyhow!("Too many records: {}", records.len()));
+    }
+    let mut lines: Vec<String> = Vec::with_capacity(records.len() + 3);
+    // --------------------------------
+    // 1. ヘッダーレコード
+    // --------------------------------
+    let src = &header.account;
+    lines.push([
+        "1".to_string(),
+        KIND_TRANSFER.to_string(),
+        "0".to_string(),
+        digits(&header.consignor_code, 10, "consignor_code")?,
+        kana(&header.consignor_name, 40)?,
+        header.transfer_date.format("%m%d").to_string(),
+        digits(&src.bank_code, 4, "bank_code")?,
+        kana(&src.bank_name, 15)?,
+        digits(&src.branch_code, 3, "branch_code")?,
+        kana(&src.branch_name, 15)?,
+        account_type(src.account_type)?,
+        account_number(&src.account_number)?,
+        " ".repeat(17),
+    ].concat());
+    // --------------------------------
+    // 2. データレコード
+    // --------------------------------
+    let mut total: u64 = 0;
+    for r in records {
+        let dst = &r.account;
+        total += r.amount as u64;
+        lines.push([
+            "2".to_string(),
+            digits(&dst.bank_code, 4, "bank_code")?,
+            kana(&dst.bank_name, 15)?,
+            digits(&dst.branch_code, 3, "branch_code")?,
+            kana(&dst.branch_name, 15)?,
+            " ".repeat(4),
+            account_type(dst.account_type)?,
+            account_number(&dst.account_number)?,
+            kana(&dst.holder, 30)?,
+            format!("{:010}", r.amount),
+            "0".to_string(),
+            kana(&r.customer_code, 10)?,
+            " ".repeat(10),
+            " ".to_string(),
+            " ".to_string(),
+            " ".repeat(7),
+        ].concat());
+    }
+    // --------------------------------
+    // 3. トレーラーレコードとエンドレコード
+    // --------------------------------
+    if total > 999_999_999_999 {
+        return Err(anyhow!("Total amount overflow: {}", total));
+    }
+    lines.push(format!("8{:06}{:012}{}", records.len(), total, " ".repeat(101)));
+    lines.push(format!("9{}", " ".repeat(119)));
+    // --------------------------------
+    // 4. Shift_JIS へのエンコード
+    // --------------------------------
+    let mut out = Vec::with_capacity(lines.len() * (RECORD_LEN + 2));
+    for line in lines {
+        let (bytes, _, had_errors) = SHIFT_JIS.encode(&line);
+        if had_errors || bytes.len() != RECORD_LEN {
+            return Err(anyhow!("Invalid record: {}", line));
+        }
+        out.extend_from_slice(&bytes);
+        out.extend_from_slice(b"\r\n");
+    }
+    Ok(out)
+}
+
+/// 文字列を全銀協規定の半角文字へ変換する
+/// ひらがな・全角カナは半角カナ（濁点・半濁点は分離）、全角英数は半角英数、英小文字は大文字、小書き文字は大書きへ変換する
+/// 変換後に使用できない文字が残る場合はエラーとする
+pub fn to_zengin_kana(s: &str) -> Result<String> {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        let c = match c {
+            // ひらがなはカタカナへ
+            '\u{3041}'..='\u{3096}' => char::from_u32(c as u32 + 0x60).unwrap_or(c),
+            // 全角英数記号は半角へ
+            '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+            '\u{3000}' => ' ',
+            _ => c,
+        };
+        match c {
+            'ー' | '‐' | '−' | '―' | 'ｰ' => out.push('-'),
+            'ヴ' => out.push_str("ｳﾞ"),
+            _ => {
+                if let Some(i) = FULL_KANA.chars().position(|f| f == c) {
+                    out.push(HALF_KANA.chars().nth(i).unwrap_or(c));
+                } else if let Some(i) = DAKU_KANA.chars().position(|f| f == c) {
+                    out.push(HALF_KANA.chars().nth(DAKU_BASE[i]).unwrap_or(c));
+                    out.push('ﾞ');
+                } else if let Some(i) = HANDAKU_KANA.chars().position(|f| f == c) {
+                    out.push(HALF_KANA.chars().nth(HANDAKU_BASE[i]).unwrap_or(c));
+                    out.push('ﾟ');
+                } else if let Some(i) = SMALL_KANA.chars().position(|f| f == c) {
+                    out.push(SMALL_KANA_TO.chars().nth(i).unwrap_or(c));
+                } else {
+                    out.push(c.to_ascii_uppercase());
+                }
+            }
+        }
+    }
+    if let Some(bad) = out.chars().find(|c| !is_zengin_char(*c)) {
+        return Err(anyhow!("Unsupported character for Zengin: '{}' in '{}'", bad, s));
+    }
+    Ok(out)
+}
+
+const FULL_KANA: &str = "アイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワヲンヰヱ";
+const HALF_KANA: &str = "ｱｲｳｴｵｶｷｸｹｺｻｼｽｾｿﾀﾁﾂﾃﾄﾅﾆﾇﾈﾉﾊﾋﾌﾍﾎﾏﾐﾑﾒﾓﾔﾕﾖﾗﾘﾙﾚﾛﾜｦﾝｲｴ";
+const DAKU_KANA: &str = "ガギグゲゴザジズゼゾダヂヅデドバビブベボ";
+const DAKU_BASE: [usize; 20] = [5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 25, 26, 27, 28, 29];
+const HANDAKU_KANA: &str = "パピプペポ";
+const HANDAKU_BASE: [usize; 5] = [25, 26, 27, 28, 29];
+const SMALL_KANA: &str = "ァィゥェォッャュョヮヵヶｧｨｩｪｫｯｬｭｮ";
+const SMALL_KANA_TO: &str = "ｱｲｳｴｵﾂﾔﾕﾖﾜｶｹｱｲｳｴｵﾂﾔﾕﾖ";
+
+/// 全銀協規定で使用できる文字か
+fn is_zengin_char(c: char) -> bool {
+    c.is_ascii_digit()
+        || c.is_ascii_uppercase()
+        || ('\u{FF71}'..='\u{FF9F}').contains(&c)
+        || c == 'ｦ'
+        || matches!(c, ' ' | '(' | ')' | '-' | '.' | '/' | ',' | '\\' | '｢' | '｣')
+}
+
+/// 半角カナへ変換し、指定桁数に左詰め・空白埋めする（超過分は切り捨て）
+fn kana(s: &str, len: usize) -> Result<String> {
+    let s: String = to_zengin_kana(s)?.chars().take(len).collect();
+    let pad = len - s.chars().count();
+    Ok(format!("{}{}", s, " ".repeat(pad)))
+}
+
+/// 数字のみで構成された指定桁数のコードか検証する
+fn digits(s: &str, len: usize, field: &str) -> Result<String> {
+    if s.len() != len || !s.chars().all(|c| c.is_ascii_digit()) {
+        return Err(anyhow!("Invalid {}: '{}' (must be {} digits)", field, s, len));
+    }
+    Ok(s.to_string())
+}
+
+/// 口座番号を 7 桁に右詰め・ゼロ埋めする
+fn account_number(s: &str) -> Result<String> {
+    if s.is_empty() || s.len() > 7 || !s.chars().all(|c| c.is_ascii_digit()) {
+        return Err(anyhow!("Invalid account_number: '{}' (must be up to 7 digits)", s));
+    }
+    Ok(format!("{:0>7}", s))
+}
+
+fn account_type(t: u8) -> Result<String> {
+    match t {
+        1 | 2 | 4 | 9 => Ok(t.to_string()),
+        _ => Err(anyhow!("Invalid account_type: {}", t)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(bank_code: &str, branch_code: &str, number: &str, holder: &str) -> ZenginAccount {
+        ZenginAccount {
+            bank_code: bank_code.to_string(),
+            bank_name: "ミズホ".to_string(),
+            branch_code: branch_code.to_string(),
+            branch_name: "ほんてん".to_string(),
+            account_type: 1,
+            account_number: number.to_string(),
+            holder: holder.to_string(),
+        }
+    }
+
+    fn header() -> ZenginHeader {
+        ZenginHeader {
+            consignor_code: "1234567890".to_string(),
+            consignor_name: "カ)バッジ".to_string(),
+            transfer_date: NaiveDate::from_ymd_opt(2026, 10, 25).unwrap(),
+            account: account("0001", "001", "1234567", ""),
+        }
+    }
+
+    fn records() -> Vec<ZenginRecord> {
+        vec![
+            ZenginRecord { account: account("0005", "123", "765", "やまだ　たろう"), amount: 12_345, customer_code: "101".to_string() },
+            ZenginRecord { account: account("0009", "456", "1", "ｻﾄｳ ﾊﾅｺ"), amount: 1_000_000, customer_code: "102".to_string() },
+        ]
+    }
+
+    fn split(out: &[u8]) -> Vec<&[u8]> {
+        assert_eq!(out.len() % (RECORD_LEN + 2), 0);
+        out.chunks(RECORD_LEN + 2)
+            .map(|c| {
+                assert_eq!(&c[RECORD_LEN..], b"\r\n");
+                &c[..RECORD_LEN]
+            })
+            .collect()
+    }
+
+    fn sjis(s: &str) -> Vec<u8> {
+        SHIFT_JIS.encode(s).0.into_owned()
+    }
+
+    #[test]
+    fn records_are_120_bytes_with_crlf() {
+        let out = build_zengin(&header(), &records()).unwrap();
+        let lines = split(&out);
+        assert_eq!(lines.len(), 5);
+        let kinds: Vec<u8> = lines.iter().map(|l| l[0]).collect();
+        assert_eq!(kinds, b"12289".to_vec());
+    }
+
+    #[test]
+    fn header_field_offsets() {
+        let out = build_zengin(&header(), &records()).unwrap();
+        let h = split(&out)[0];
+        assert_eq!(&h[1..3], b"21");
+        assert_eq!(&h[3..4], b"0");
+        assert_eq!(&h[4..14], b"1234567890");
+        assert_eq!(&h[14..54], sjis(&format!("{:<40}", "ｶ)ﾊﾞﾂｼﾞ")).as_slice());
+        assert_eq!(&h[54..58], b"1025");
+        assert_eq!(&h[58..62], b"0001");
+        assert_eq!(&h[62..77], sjis(&format!("{:<15}", "ﾐｽﾞﾎ")).as_slice());
+        assert_eq!(&h[77..80], b"001");
+        assert_eq!(&h[80..95], sjis(&format!("{:<15}", "ﾎﾝﾃﾝ")).as_slice());
+        assert_eq!(&h[95..96], b"1");
+        assert_eq!(&h[96..103], b"1234567");
+        assert_eq!(&h[103..120], " ".repeat(17).as_bytes());
+    }
+
+    #[test]
+    fn data_field_offsets() {
+        let out = build_zengin(&header(), &records()).unwrap();
+        let d = split(&out)[1];
+        assert_eq!(&d[1..5], b"0005");
+        assert_eq!(&d[20..23], b"123");
+        assert_eq!(&d[38..42], b"    ");
+        assert_eq!(&d[42..43], b"1");
+        assert_eq!(&d[43..50], b"0000765");
+        assert_eq!(&d[50..80], sjis(&format!("{:<30}", "ﾔﾏﾀﾞ ﾀﾛｳ")).as_slice());
+        assert_eq!(&d[80..90], b"0000012345");
+        assert_eq!(&d[90..91], b"0");
+        assert_eq!(&d[91..101], b"101       ");
+        assert_eq!(&d[101..120], " ".repeat(19).as_bytes());
+        let d = split(&out)[2];
+        assert_eq!(&d[43..50], b"0000001");
+        assert_eq!(&d[80..90], b"0001000000");
+    }
+
+    #[test]
+    fn trailer_and_end_records() {
+        let out = build_zengin(&header(), &records()).unwrap();
+        let lines = split(&out);
+        assert_eq!(&lines[3][1..7], b"000002");
+        assert_eq!(&lines[3][7..19], b"000001012345");
+        assert_eq!(&lines[3][19..120], " ".repeat(101).as_bytes());
+        assert_eq!(&lines[4][1..120], " ".repeat(119).as_bytes());
+    }
+
+    #[test]
+    fn half_width_kana_is_single_byte_shift_jis() {
+        let out = build_zengin(&header(), &records()).unwrap();
+        let d = split(&out)[1];
+        // ﾔ ﾏ ﾀ ﾞ
+        assert_eq!(&d[50..54], &[0xD4, 0xCF, 0xC0, 0xDE]);
+        // 半角カナ以外の Shift_JIS 2 バイト文字は含まれない
+        assert!(out.iter().all(|b| *b < 0x80 || (0xA1..=0xDF).contains(b)));
+    }
+
+    #[test]
+    fn converts_to_zengin_kana() {
+        assert_eq!(to_zengin_kana("やまだ　たろう").unwrap(), "ﾔﾏﾀﾞ ﾀﾛｳ");
+        assert_eq!(to_zengin_kana("ヤマダ タロウ").unwrap(), "ﾔﾏﾀﾞ ﾀﾛｳ");
+        assert_eq!(to_zengin_kana("パピプペポ").unwrap(), "ﾊﾟﾋﾟﾌﾟﾍﾟﾎﾟ");
+        assert_eq!(to_zengin_kana("ヴィッキー").unwrap(), "ｳﾞｲﾂｷ-");
+        assert_eq!(to_zengin_kana("ｶ)ｷｯﾄ").unwrap(), "ｶ)ｷﾂﾄ");
+        assert_eq!(to_zengin_kana("ａｂｃ１２３abc").unwrap(), "ABC123ABC");
+        assert_eq!(to_zengin_kana("ヰヱヲン").unwrap(), "ｲｴｦﾝ");
+        assert!(to_zengin_kana("山田").is_err());
+    }
+
+    #[test]
+    fn pads_and_truncates_fields() {
+        let mut r = records();
+        r[0].account.holder = "ア".repeat(40);
+        let out = build_zengin(&header(), &r).unwrap();
+        let d = split(&out)[1];
+        assert_eq!(&d[50..80], sjis(&"ｱ".repeat(30)).as_slice());
+        assert_eq!(&d[80..90], b"0000012345");
+    }
+
+    #[test]
+    fn rejects_invalid_fields() {
+        let mut h = header();
+        h.consignor_code = "123".to_string();
+        assert!(build_zengin(&h, &records()).is_err());
+        let mut r = records();
+        r[0].account.bank_code = "05".to_string();
+        assert!(build_zengin(&header(), &r).is_err());
+        let mut r = records();
+        r[0].account.account_type = 3;
+        assert!(build_zengin(&header(), &r).is_err());
+        let mut r = records();
+        r[0].account.account_number = "12345678".to_string();
+        assert!(build_zengin(&header(), &r).is_err());
+        let mut r = records();
+        r[0].account.holder = "山田".to_string();
+        assert!(build_zengin(&header(), &r).is_err());
+    }
+}